@@ -0,0 +1,256 @@
+//! Derive and attribute macros for kodegen-mcp-schema tool arguments
+//!
+//! `#[tool_metadata(description = "...")]` on a `ToolArgs` impl registers the
+//! tool's `ToolMetadata` and its output deserializer with inventory. It uses
+//! `crate::` paths and is meant for Args types defined in the schema crate.
+//!
+//! `#[derive(ValidateArgs)]` implements `kodegen_mcp_schema::tool::ValidateArgs`
+//! from field attributes and registers it with inventory, so the default
+//! `ToolArgs::validate` runs the checks without a manual override. The
+//! generated code uses `::kodegen_mcp_schema` paths and works in any crate
+//! depending on kodegen-mcp-schema.
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
+//! pub struct TerminalInput {
+//!     pub action: TerminalAction,
+//!
+//!     #[required_if(action = "Exec")]
+//!     pub command: Option<String>,
+//!
+//!     #[range(max = 2000)]
+//!     pub tail: u32,
+//! }
+//! ```
+//!
+//! Field attributes:
+//!
+//! - `#[required_if(field = "Variant" | "Other")]`: the field must be present
+//!   when enum `field` is one of the variants
+//! - `#[exclusive_with(a, b)]`: the field must not be present together with `a` or `b`
+//! - `#[range(min = 1, max = 100)]`: a numeric field (or a set `Option` of one)
+//!   must lie within the bounds; either bound may be omitted
+//!
+//! Errors name the field as it appears in JSON (honoring `#[serde(rename)]`).
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Expr, Field, Fields, GenericArgument, Ident, ItemImpl, LitStr, PathArguments, Token, Type, parse_macro_input};
+
+mod tool_metadata;
+
+/// Register a `ToolArgs` impl for tool discovery and output deserialization
+#[proc_macro_attribute]
+pub fn tool_metadata(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as tool_metadata::ToolMetadataArgs);
+    let impl_block = parse_macro_input!(item as ItemImpl);
+    tool_metadata::expand(args, impl_block).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Implement `ValidateArgs` from `required_if`, `exclusive_with` and `range` field attributes
+#[proc_macro_derive(ValidateArgs, attributes(required_if, exclusive_with, range))]
+pub fn derive_validate_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// `field = "Variant" | "Other"`
+struct RequiredIf {
+    field: Ident,
+    variants: Vec<LitStr>,
+}
+
+impl Parse for RequiredIf {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let field: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let variants = Punctuated::<LitStr, Token![|]>::parse_separated_nonempty(input)?;
+        Ok(Self {
+            field,
+            variants: variants.into_iter().collect(),
+        })
+    }
+}
+
+/// `min = expr, max = expr`
+#[derive(Default)]
+struct Range {
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl Parse for Range {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut range = Range::default();
+        let bounds = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+        for bound in bounds {
+            if bound.path.is_ident("min") {
+                range.min = Some(bound.value);
+            } else if bound.path.is_ident("max") {
+                range.max = Some(bound.value);
+            } else {
+                return Err(syn::Error::new(bound.path.span(), "expected `min` or `max`"));
+            }
+        }
+        if range.min.is_none() && range.max.is_none() {
+            return Err(input.error("expected `min = ...` and/or `max = ...`"));
+        }
+        Ok(range)
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.ident.span(), "ValidateArgs can only be derived for structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new(input.ident.span(), "ValidateArgs requires named fields"));
+    };
+    let fields: Vec<&Field> = fields.named.iter().collect();
+    let find = |name: &Ident| {
+        fields
+            .iter()
+            .copied()
+            .find(|field| field.ident.as_ref() == Some(name))
+            .ok_or_else(|| syn::Error::new(name.span(), format!("no field `{}` in this struct", name)))
+    };
+
+    let mut checks = Vec::new();
+    for field in &fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let name = json_name(field)?;
+
+        for attr in &field.attrs {
+            if attr.path().is_ident("required_if") {
+                let RequiredIf { field: condition, variants } = attr.parse_args()?;
+                let condition_field = find(&condition)?;
+                let condition_name = json_name(condition_field)?;
+                let pattern = variant_pattern(&condition_field.ty, &variants)?;
+                checks.push(quote_spanned! {attr.span()=>
+                    if matches!(self.#condition, #pattern) && !::kodegen_mcp_schema::tool::validate::Presence::is_present(&self.#ident) {
+                        return Err(::kodegen_mcp_schema::tool::McpError::invalid_argument(
+                            #name,
+                            format!("required when {} is {}", #condition_name, ::kodegen_mcp_schema::tool::validate::describe(&self.#condition)),
+                        ));
+                    }
+                });
+            } else if attr.path().is_ident("exclusive_with") {
+                let others = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                for other in others {
+                    let other_name = json_name(find(&other)?)?;
+                    let message = format!("cannot be combined with `{}`", other_name);
+                    checks.push(quote_spanned! {attr.span()=>
+                        if ::kodegen_mcp_schema::tool::validate::Presence::is_present(&self.#ident)
+                            && ::kodegen_mcp_schema::tool::validate::Presence::is_present(&self.#other)
+                        {
+                            return Err(::kodegen_mcp_schema::tool::McpError::invalid_argument(#name, #message));
+                        }
+                    });
+                }
+            } else if attr.path().is_ident("range") {
+                let Range { min, max } = attr.parse_args()?;
+                let bounds = |expr: &Expr| quote!(#expr).to_string().replace(' ', "");
+                let expected = match (&min, &max) {
+                    (Some(min), Some(max)) => format!("between {} and {}", bounds(min), bounds(max)),
+                    (Some(min), None) => format!("at least {}", bounds(min)),
+                    (None, Some(max)) => format!("at most {}", bounds(max)),
+                    (None, None) => unreachable!("Range::parse requires a bound"),
+                };
+                let above_min = min.map_or_else(|| quote!(true), |min| quote!(value >= (#min) as f64));
+                let below_max = max.map_or_else(|| quote!(true), |max| quote!(value <= (#max) as f64));
+                checks.push(quote_spanned! {attr.span()=>
+                    if let Some(value) = ::kodegen_mcp_schema::tool::validate::Bounded::bound_value(&self.#ident)
+                        && !(#above_min && #below_max)
+                    {
+                        return Err(::kodegen_mcp_schema::tool::McpError::invalid_argument(
+                            #name,
+                            format!("must be {}, found {}", #expected, value),
+                        ));
+                    }
+                });
+            }
+        }
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Generic types have no single `TypeId` to register; they forward `ToolArgs::validate` by hand
+    let registration = input.generics.params.is_empty().then(|| {
+        quote! {
+            ::kodegen_mcp_schema::inventory::submit! {
+                ::kodegen_mcp_schema::tool::ArgsValidator::new::<#ty>()
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics ::kodegen_mcp_schema::tool::ValidateArgs for #ty #ty_generics #where_clause {
+            fn validate_args(&self) -> ::std::result::Result<(), ::kodegen_mcp_schema::tool::McpError> {
+                #(#checks)*
+                Ok(())
+            }
+        }
+
+        #registration
+    })
+}
+
+/// Name of `field` in JSON: `#[serde(rename = "...")]`, else the identifier
+fn json_name(field: &Field) -> syn::Result<String> {
+    let mut name = field.ident.as_ref().expect("named field").unraw().to_string();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                name = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(name)
+}
+
+/// `Enum::A | Enum::B`, wrapped in `Some(..)` for an `Option<Enum>` field
+fn variant_pattern(ty: &Type, variants: &[LitStr]) -> syn::Result<TokenStream2> {
+    let (enum_ty, optional) = match option_inner(ty) {
+        Some(inner) => (inner, true),
+        None => (ty, false),
+    };
+    let Type::Path(path) = enum_ty else {
+        return Err(syn::Error::new(ty.span(), "required_if conditions must be enum fields"));
+    };
+    let path = &path.path;
+    let variants = variants
+        .iter()
+        .map(|variant| {
+            let variant = variant.parse::<Ident>()?;
+            Ok(if optional { quote!(Some(#path::#variant)) } else { quote!(#path::#variant) })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote!(#(#variants)|*))
+}
+
+/// `T` of an `Option<T>` type
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
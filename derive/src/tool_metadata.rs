@@ -0,0 +1,93 @@
+//! `#[tool_metadata]`: registers a `ToolArgs` impl for discovery and output deserialization
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, ImplItem, ItemImpl, Lit, LitStr, Meta, Token, Type};
+
+/// `description = "..."`
+pub struct ToolMetadataArgs {
+    description: LitStr,
+}
+
+impl Parse for ToolMetadataArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut description = None;
+
+        while !input.is_empty() {
+            let Meta::NameValue(nv) = input.parse::<Meta>()? else {
+                return Err(input.error("expected `name = value`"));
+            };
+            if !nv.path.is_ident("description") {
+                return Err(syn::Error::new_spanned(&nv.path, "unknown attribute (only `description` is supported)"));
+            }
+            let Expr::Lit(syn::ExprLit { lit: Lit::Str(lit), .. }) = nv.value else {
+                return Err(syn::Error::new_spanned(&nv.value, "expected string literal"));
+            };
+            description = Some(lit);
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self {
+            description: description.ok_or_else(|| input.error("missing `description` attribute"))?,
+        })
+    }
+}
+
+pub fn expand(args: ToolMetadataArgs, impl_block: ItemImpl) -> syn::Result<TokenStream2> {
+    let description = &args.description;
+    let self_ty = &impl_block.self_ty;
+    let output_ty = associated_type(&impl_block, "Output")?;
+    let prompts_ty = associated_type(&impl_block, "Prompts")?;
+
+    Ok(quote! {
+        #impl_block
+
+        inventory::submit! {
+            crate::ToolMetadata {
+                name: <#self_ty as crate::ToolArgs>::NAME,
+                category: <#self_ty as crate::ToolArgs>::CATEGORY,
+                description: #description,
+                args_schema: || {
+                    let schema = schemars::schema_for!(#self_ty);
+                    serde_json::to_value(&schema).expect("Failed to serialize schema")
+                },
+                output_schema: || {
+                    let schema = schemars::schema_for!(#output_ty);
+                    serde_json::to_value(&schema).expect("Failed to serialize schema")
+                },
+                behavior: <#self_ty as crate::ToolArgs>::BEHAVIOR,
+                prompt_arguments: || {
+                    <#prompts_ty as crate::tool::PromptProvider>::prompt_arguments()
+                },
+                generate_prompts: |args_json: &serde_json::Value| {
+                    // All PromptArgs fields use #[serde(default)], so an empty object always works
+                    let args: <#prompts_ty as crate::tool::PromptProvider>::PromptArgs =
+                        serde_json::from_value(args_json.clone())
+                            .or_else(|_| serde_json::from_value(serde_json::json!({})))
+                            .expect("PromptArgs should deserialize from empty object");
+                    <#prompts_ty as crate::tool::PromptProvider>::generate_prompts(&args)
+                },
+            }
+        }
+
+        inventory::submit! {
+            crate::deserialize::ToolOutputDeserializer::new::<#output_ty>(<#self_ty as crate::ToolArgs>::NAME)
+        }
+    })
+}
+
+/// `type <name> = ...;` of the impl block
+fn associated_type<'a>(impl_block: &'a ItemImpl, name: &str) -> syn::Result<&'a Type> {
+    impl_block
+        .items
+        .iter()
+        .find_map(|item| match item {
+            ImplItem::Type(ty) if ty.ident == name => Some(&ty.ty),
+            _ => None,
+        })
+        .ok_or_else(|| syn::Error::new_spanned(&impl_block.self_ty, format!("ToolArgs impl must define `type {}`", name)))
+}
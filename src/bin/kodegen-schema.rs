@@ -0,0 +1,147 @@
+//! Export the schemas of every registered tool as a static bundle
+//!
+//! ```text
+//! kodegen-schema [--out DIR] [--openapi] [--typescript] [--python] [--pydantic]
+//! kodegen-schema --check BASELINE
+//! kodegen-schema --diff OLD NEW
+//! ```
+//!
+//! Writes `DIR/{version}/index.json`, `DIR/{version}/tools/{name}.schema.json`
+//! and, with `--openapi`, `DIR/{version}/openapi.json` (see
+//! [`SchemaBundle`](kodegen_mcp_schema::tool::SchemaBundle)). The type flags add
+//! `kodegen.d.ts`, `kodegen_types.py` and `kodegen_models.py` (see
+//! [`type_gen`](kodegen_mcp_schema::tool::type_gen)).
+//!
+//! `--check` compares the registered tools against a bundle written earlier (its
+//! version directory), `--diff` compares two such bundles. Both print every
+//! change and exit with status 1 if any is breaking (see
+//! [`schema_compat`](kodegen_mcp_schema::tool::schema_compat)).
+
+use kodegen_mcp_schema::tool::type_gen::{self, PythonStyle};
+use kodegen_mcp_schema::tool::{SchemaBundle, schema_compat};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: kodegen-schema [--out DIR] [--openapi] [--typescript] [--python] [--pydantic]
+       kodegen-schema --check BASELINE
+       kodegen-schema --diff OLD NEW
+
+Write the JSON Schemas of every kodegen tool to DIR/<version>/ (default DIR: schemas),
+or report schema changes against a bundle written earlier (exit status 1 if breaking).
+
+Options:
+  -o, --out DIR     Output directory
+      --openapi     Also write an OpenAPI 3.1 document (openapi.json)
+      --typescript  Also write TypeScript declarations (kodegen.d.ts)
+      --python      Also write Python TypedDicts (kodegen_types.py)
+      --pydantic    Also write pydantic models (kodegen_models.py)
+      --check DIR   Compare the current tools against the bundle in DIR (e.g. schemas/0.10.14)
+      --diff A B    Compare the bundle in A against the bundle in B
+  -V, --version     Print the schema version
+  -h, --help        Print this help";
+
+fn main() -> ExitCode {
+    let mut out = PathBuf::from("schemas");
+    let mut openapi = false;
+    let mut typescript = false;
+    let mut python = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--out" => match args.next() {
+                Some(dir) => out = PathBuf::from(dir),
+                None => return usage_error("--out requires a directory"),
+            },
+            "--openapi" => openapi = true,
+            "--typescript" => typescript = true,
+            "--python" => python.push(PythonStyle::TypedDict),
+            "--pydantic" => python.push(PythonStyle::Pydantic),
+            "--check" => match args.next() {
+                Some(baseline) => return check(&PathBuf::from(baseline), None),
+                None => return usage_error("--check requires a bundle directory"),
+            },
+            "--diff" => match (args.next(), args.next()) {
+                (Some(old), Some(new)) => return check(&PathBuf::from(old), Some(&PathBuf::from(new))),
+                _ => return usage_error("--diff requires two bundle directories"),
+            },
+            "-V" | "--version" => {
+                println!("kodegen-schema {}", env!("CARGO_PKG_VERSION"));
+                return ExitCode::SUCCESS;
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            other => return usage_error(&format!("unexpected argument '{}'", other)),
+        }
+    }
+
+    let bundle = SchemaBundle::from_inventory();
+    let written = bundle
+        .write_to(&out, openapi)
+        .and_then(|files| write_types(&bundle, &out, typescript, &python).map(|types| files.len() + types));
+    match written {
+        Ok(files) => {
+            println!(
+                "Wrote {} tools ({} files) to {}",
+                bundle.index().tools.len(),
+                files,
+                out.join(&bundle.index().schema_version).display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("kodegen-schema: failed to write bundle: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print the changes from the bundle in `old` to the bundle in `new` (default:
+/// the registered tools); fails if any is breaking
+fn check(old: &Path, new: Option<&Path>) -> ExitCode {
+    let read = |dir: &Path| {
+        SchemaBundle::read_from(dir).map_err(|e| eprintln!("kodegen-schema: cannot read bundle {}: {}", dir.display(), e))
+    };
+    let Ok(old) = read(old) else {
+        return ExitCode::FAILURE;
+    };
+    let new = match new {
+        Some(dir) => match read(dir) {
+            Ok(bundle) => bundle,
+            Err(()) => return ExitCode::FAILURE,
+        },
+        None => SchemaBundle::from_inventory(),
+    };
+
+    let report = schema_compat::compare(&old, &new);
+    print!("{}", report);
+    if report.is_compatible() { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+/// Write the requested type declarations next to the schemas, returning how many
+fn write_types(bundle: &SchemaBundle, out: &Path, typescript: bool, python: &[PythonStyle]) -> io::Result<usize> {
+    let root = out.join(&bundle.index().schema_version);
+    let mut written = 0;
+    if typescript {
+        std::fs::write(root.join("kodegen.d.ts"), type_gen::typescript(bundle))?;
+        written += 1;
+    }
+    for &style in python {
+        let file = match style {
+            PythonStyle::TypedDict => "kodegen_types.py",
+            PythonStyle::Pydantic => "kodegen_models.py",
+        };
+        std::fs::write(root.join(file), type_gen::python(bundle, style))?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+fn usage_error(message: &str) -> ExitCode {
+    eprintln!("kodegen-schema: {}\n\n{}", message, USAGE);
+    ExitCode::from(2)
+}
@@ -3,15 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `browser_agent` prompts
+    pub enum BrowserAgentScenario {
+        /// Basic browser automation
+        Basic => "basic",
+        /// Autonomous multi-step navigation and complex workflows
+        Autonomous => "autonomous",
+        /// Monitoring agent progress - READ action
+        Monitoring => "monitoring",
+        /// Agent management
+        Management => "management",
+    }
+}
+
 /// Prompt arguments for browser_agent tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BrowserAgentPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic browser automation
-    /// - "navigation": Web navigation patterns
-    /// - "extraction": Data extraction tasks
-    /// - "management": Agent management
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<BrowserAgentScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for browser_agent tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::BrowserAgentPromptArgs;
+use super::prompt_args::{BrowserAgentPromptArgs, BrowserAgentScenario};
 
 /// Prompt provider for browser_agent tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for AgentPrompts {
     type PromptArgs = BrowserAgentPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("autonomous") => prompt_autonomous(),
-            Some("monitoring") => prompt_monitoring(),
-            Some("management") => prompt_management(),
+        match args.scenario {
+            Some(BrowserAgentScenario::Basic) => prompt_basic(),
+            Some(BrowserAgentScenario::Autonomous) => prompt_autonomous(),
+            Some(BrowserAgentScenario::Monitoring) => prompt_monitoring(),
+            Some(BrowserAgentScenario::Management) => prompt_management(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic (PROMPT action), autonomous (multi-step), monitoring (READ action), management (KILL action)".to_string()),
-                required: Some(false),
-            }
+            BrowserAgentScenario::prompt_argument()
         ]
     }
 }
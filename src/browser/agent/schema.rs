@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_AGENT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::AgentPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -159,6 +160,11 @@ pub struct BrowserAgentKillOutput {
     pub message: String,
 }
 
+// No ToolArgs impl yet, so register the output deserializer by hand
+inventory::submit! {
+    crate::deserialize::ToolOutputDeserializer::new::<BrowserAgentKillOutput>(crate::browser::BROWSER_AGENT_KILL)
+}
+
 // ============================================================================
 // TOOL ARGS TRAIT IMPL
 // ============================================================================
@@ -173,4 +179,5 @@ impl ToolArgs for BrowserAgentArgs {
     const NAME: &'static str = BROWSER_AGENT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Autonomous AI agent that accomplishes complex web tasks through multi-step reasoning and adaptive decision-making";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
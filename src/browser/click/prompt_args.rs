@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `browser_click` prompts
+    pub enum BrowserClickScenario {
+        /// Handling dynamic content and timing (default)
+        Waiting => "waiting",
+        /// CSS selector patterns for different elements
+        Selectors => "selectors",
+    }
+}
+
 /// Prompt arguments for browser_click tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BrowserClickPromptArgs {
-    /// Scenario to show examples for
-    /// - "selectors": CSS selector patterns for different elements
-    /// - "coordinates": Using x,y coordinates
-    /// - "waiting": Handling dynamic content and timing
-    /// - "troubleshooting": Debugging click failures
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<BrowserClickScenario>,
 }
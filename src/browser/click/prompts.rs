@@ -1,8 +1,8 @@
 //! Prompt messages for browser_click tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::BrowserClickPromptArgs;
+use super::prompt_args::{BrowserClickPromptArgs, BrowserClickScenario};
 
 /// Prompt provider for browser_click tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for ClickPrompts {
     type PromptArgs = BrowserClickPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("selectors") => prompt_selectors(),
-            _ => prompt_waiting(),
+        match args.scenario {
+            Some(BrowserClickScenario::Selectors) => prompt_selectors(),
+            Some(BrowserClickScenario::Waiting) | None => prompt_waiting(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (selectors, waiting)".to_string()),
-                required: Some(false),
-            }
+            BrowserClickScenario::prompt_argument()
         ]
     }
 }
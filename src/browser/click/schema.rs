@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_CLICK};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ClickPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // INPUT ARGS
@@ -51,4 +52,5 @@ impl ToolArgs for BrowserClickArgs {
     const NAME: &'static str = BROWSER_CLICK;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Click an element on the page using CSS selectors with automatic wait for clickability";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `browser_extract_text` prompts
+    pub enum BrowserExtractTextScenario {
+        /// Reading full page or sections
+        PageContent => "page_content",
+        /// Targeting specific data
+        SpecificElements => "specific_elements",
+    }
+}
+
 /// Prompt arguments for browser_extract_text tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BrowserExtractTextPromptArgs {
-    /// Scenario to show examples for
-    /// - "page_content": Reading full page or sections
-    /// - "specific_elements": Targeting specific data
-    /// - "structured_data": Extracting tables, lists, forms
-    /// - "verification": Checking expected content exists
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<BrowserExtractTextScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for browser_extract_text tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::BrowserExtractTextPromptArgs;
+use super::prompt_args::{BrowserExtractTextPromptArgs, BrowserExtractTextScenario};
 
 /// Prompt provider for browser_extract_text tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ExtractTextPrompts {
     type PromptArgs = BrowserExtractTextPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("page_content") => prompt_page_content(),
-            Some("specific_elements") => prompt_specific_elements(),
+        match args.scenario {
+            Some(BrowserExtractTextScenario::PageContent) => prompt_page_content(),
+            Some(BrowserExtractTextScenario::SpecificElements) => prompt_specific_elements(),
             _ => prompt_page_content(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Extraction scenario: page_content (basic text extraction), specific_elements (targeted by CSS selector)".to_string()),
-                required: Some(false),
-            }
+            BrowserExtractTextScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_EXTRACT_TEXT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ExtractTextPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // INPUT ARGS
@@ -43,4 +44,5 @@ impl ToolArgs for BrowserExtractTextArgs {
     const NAME: &'static str = BROWSER_EXTRACT_TEXT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Extract visible text content from the page or specific elements using CSS selectors";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -15,6 +15,7 @@ pub mod type_text;
 pub mod scroll;
 pub mod screenshot;
 pub mod extract_text;
+pub mod resources;
 
 // Re-export all tool types for convenient access
 pub use shared::*;
@@ -87,3 +88,6 @@ pub use extract_text::{
     BrowserExtractTextPromptArgs,
     ExtractTextPrompts,
 };
+
+// Re-export resource URIs
+pub use resources::{ScreenshotResources, ScreenshotUri};
@@ -3,15 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `browser_navigate` prompts
+    pub enum BrowserNavigateScenario {
+        /// Simple URL navigation
+        Basic => "basic",
+    }
+}
+
 /// Prompt arguments for browser_navigate tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BrowserNavigatePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple URL navigation
-    /// - "query_params": Building URLs with parameters
-    /// - "authentication": Handling login redirects
-    /// - "spa_pages": Single-page app navigation
-    /// - "wait_strategies": Different page load scenarios
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<BrowserNavigateScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for browser_navigate tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::BrowserNavigatePromptArgs;
+use super::prompt_args::{BrowserNavigatePromptArgs, BrowserNavigateScenario};
 
 /// Prompt provider for browser_navigate tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for NavigatePrompts {
     type PromptArgs = BrowserNavigatePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
+        match args.scenario {
+            Some(BrowserNavigateScenario::Basic) => prompt_basic(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic)".to_string()),
-                required: Some(false),
-            }
+            BrowserNavigateScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_NAVIGATE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::NavigatePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // INPUT ARGS
@@ -51,4 +52,5 @@ impl ToolArgs for BrowserNavigateArgs {
     const NAME: &'static str = BROWSER_NAVIGATE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Navigate browser to specified URL with configurable load timeout and error handling";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent().open_world();
 }
@@ -3,15 +3,28 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `browser_research` prompts
+    pub enum BrowserResearchScenario {
+        /// Simple research queries (default)
+        Basic => "basic",
+        /// Multi-page in-depth research
+        DeepResearch => "deep_research",
+        /// Researching technical documentation
+        TechnicalDocs => "technical_docs",
+        /// Comparing multiple solutions/products
+        Comparison => "comparison",
+        /// Long-running research management
+        Monitoring => "monitoring",
+    }
+}
+
 /// Prompt arguments for browser_research tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BrowserResearchPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple research queries
-    /// - "deep_research": Multi-page in-depth research
-    /// - "technical_docs": Researching technical documentation
-    /// - "comparison": Comparing multiple solutions/products
-    /// - "monitoring": Long-running research management
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<BrowserResearchScenario>,
 }
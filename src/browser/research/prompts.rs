@@ -1,8 +1,8 @@
 //! Prompt messages for browser_research tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::BrowserResearchPromptArgs;
+use super::prompt_args::{BrowserResearchPromptArgs, BrowserResearchScenario};
 
 /// Prompt provider for browser_research tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for ResearchPrompts {
     type PromptArgs = BrowserResearchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("deep_research") => prompt_deep_research(),
-            Some("technical_docs") => prompt_technical_docs(),
-            Some("comparison") => prompt_comparison(),
-            Some("monitoring") => prompt_monitoring(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(BrowserResearchScenario::DeepResearch) => prompt_deep_research(),
+            Some(BrowserResearchScenario::TechnicalDocs) => prompt_technical_docs(),
+            Some(BrowserResearchScenario::Comparison) => prompt_comparison(),
+            Some(BrowserResearchScenario::Monitoring) => prompt_monitoring(),
+            Some(BrowserResearchScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, deep_research, technical_docs, comparison, monitoring)".to_string()),
-                required: Some(false),
-            }
+            BrowserResearchScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_RESEARCH};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ResearchPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -173,4 +174,5 @@ impl ToolArgs for BrowserResearchArgs {
     const NAME: &'static str = BROWSER_RESEARCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Conduct comprehensive web research with multi-page crawling, content analysis, and AI-powered synthesis";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
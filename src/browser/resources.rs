@@ -0,0 +1,68 @@
+//! Resources for browser screenshots
+
+use rmcp::model::RawResourceTemplate;
+use std::path::Path;
+
+use crate::tool::{ResourceProvider, ResourceUri, match_template, resource_template};
+use super::BrowserScreenshotOutput;
+
+/// A saved screenshot, addressed by its file name
+pub const SCREENSHOT_URI_TEMPLATE: &str = "kodegen://screenshot/{file}";
+
+/// URI of a saved screenshot
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScreenshotUri {
+    /// File name (e.g. `screenshot_1700000000.png`)
+    pub file: String,
+}
+
+impl ResourceUri for ScreenshotUri {
+    fn parse(uri: &str) -> Option<Self> {
+        let mut vars = match_template(SCREENSHOT_URI_TEMPLATE, uri)?;
+        Some(Self {
+            file: vars.remove("file")?,
+        })
+    }
+
+    fn to_uri(&self) -> String {
+        format!("kodegen://screenshot/{}", self.file)
+    }
+
+    fn name(&self) -> String {
+        self.file.clone()
+    }
+
+    fn mime_type(&self) -> Option<&'static str> {
+        let extension = Path::new(&self.file).extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some("image/png"),
+            "jpg" | "jpeg" => Some("image/jpeg"),
+            _ => None,
+        }
+    }
+}
+
+/// Screenshots saved by `browser_screenshot`
+pub struct ScreenshotResources;
+
+impl ResourceProvider for ScreenshotResources {
+    type Uri = ScreenshotUri;
+
+    fn templates() -> Vec<RawResourceTemplate> {
+        vec![resource_template(
+            SCREENSHOT_URI_TEMPLATE,
+            "screenshot",
+            "Screenshot saved by browser_screenshot",
+            None,
+        )]
+    }
+}
+
+impl BrowserScreenshotOutput {
+    /// Resource for the saved screenshot (None if it was not saved to disk)
+    #[must_use]
+    pub fn resource_uri(&self) -> Option<ScreenshotUri> {
+        let file = Path::new(self.path.as_ref()?).file_name()?.to_str()?;
+        Some(ScreenshotUri { file: file.to_string() })
+    }
+}
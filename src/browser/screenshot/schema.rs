@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_SCREENSHOT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ScreenshotPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // INPUT ARGS
@@ -37,6 +38,19 @@ pub struct BrowserScreenshotOutput {
     pub base64: Option<String>,
 }
 
+impl BrowserScreenshotOutput {
+    /// The screenshot as an image content part for vision-capable clients
+    /// (None unless it was returned as base64)
+    #[must_use]
+    pub fn image_part(&self) -> Option<crate::tool::ContentPart> {
+        let mime_type = match self.format.to_ascii_lowercase().as_str() {
+            "jpg" | "jpeg" => "image/jpeg",
+            _ => "image/png",
+        };
+        Some(crate::tool::ContentPart::image(self.base64.clone()?, mime_type))
+    }
+}
+
 // ============================================================================
 // TOOL ARGS TRAIT IMPL
 // ============================================================================
@@ -51,4 +65,5 @@ impl ToolArgs for BrowserScreenshotArgs {
     const NAME: &'static str = BROWSER_SCREENSHOT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Capture a screenshot of the page or specific element in PNG format with base64 encoding";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
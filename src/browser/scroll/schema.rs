@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_SCROLL};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ScrollPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // INPUT ARGS
@@ -52,4 +53,5 @@ impl ToolArgs for BrowserScrollArgs {
     const NAME: &'static str = BROWSER_SCROLL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Scroll the page in specified direction or to a specific element, useful for lazy-loaded content";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -150,3 +150,8 @@ pub struct BrowserEvalOutput {
     pub success: bool,
     pub result: JsValue,
 }
+
+// No ToolArgs impl yet, so register the output deserializer by hand
+inventory::submit! {
+    crate::deserialize::ToolOutputDeserializer::new::<BrowserEvalOutput>(BROWSER_EVAL)
+}
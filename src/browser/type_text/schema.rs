@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_BROWSER, BROWSER_TYPE_TEXT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::TypeTextPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // HELPER FUNCTIONS
@@ -62,4 +63,5 @@ impl ToolArgs for BrowserTypeTextArgs {
     const NAME: &'static str = BROWSER_TYPE_TEXT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_BROWSER;
     const DESCRIPTION: &'static str = "Type text into input fields, textareas, and other editable elements with optional clear-first behavior";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -40,6 +40,7 @@ pub struct FetchOutput {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::FetchPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Fetch a single web page and display as ANSI-highlighted markdown. Simplified wrapper around scrape_url for quick page retrieval."
@@ -51,4 +52,5 @@ impl ToolArgs for FetchArgs {
     const NAME: &'static str = FETCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CITESCRAPE;
     const DESCRIPTION: &'static str = "Fetch a single web page and display as ANSI-highlighted markdown. Simplified wrapper around scrape_url for quick page retrieval.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
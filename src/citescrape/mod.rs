@@ -6,6 +6,7 @@ pub use kodegen_config::{FETCH, SCRAPE_URL, WEB_SEARCH};
 pub mod fetch;
 pub mod scrape_url;
 pub mod web_search;
+pub mod resources;
 
 // Re-export scrape_url tool
 pub use scrape_url::{
@@ -34,3 +35,6 @@ pub use fetch::{
     FetchPromptArgs,
     FetchPrompts,
 };
+
+// Re-export resource URIs
+pub use resources::{CitescrapeResources, CitescrapeUri};
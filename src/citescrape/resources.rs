@@ -0,0 +1,100 @@
+//! Resources for crawl output and fetched pages
+
+use rmcp::model::RawResourceTemplate;
+use std::path::Path;
+
+use crate::tool::{ResourceProvider, ResourceUri, match_template, resource_template};
+use super::{FetchOutput, ScrapeUrlOutput};
+
+/// A crawl's output directory (index of its pages)
+pub const CRAWL_URI_TEMPLATE: &str = "kodegen://crawl/{crawl_id}";
+
+/// One saved page of a crawl, numbered from 1 in crawl order
+pub const CRAWL_PAGE_URI_TEMPLATE: &str = "kodegen://crawl/{crawl_id}/page/{n}";
+
+/// A page saved by `fetch`, addressed by its file name
+pub const FETCH_URI_TEMPLATE: &str = "kodegen://fetch/{file}";
+
+/// URI of a citescrape resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CitescrapeUri {
+    /// Output directory of crawl `crawl_id`
+    Crawl { crawl_id: u32 },
+    /// Page `page` (1-based) of crawl `crawl_id`
+    Page { crawl_id: u32, page: u32 },
+    /// Markdown file saved by `fetch`
+    Fetched { file: String },
+}
+
+impl ResourceUri for CitescrapeUri {
+    fn parse(uri: &str) -> Option<Self> {
+        if let Some(vars) = match_template(CRAWL_PAGE_URI_TEMPLATE, uri) {
+            let page = vars["n"].parse().ok().filter(|page| *page > 0)?;
+            return Some(Self::Page {
+                crawl_id: vars["crawl_id"].parse().ok()?,
+                page,
+            });
+        }
+        if let Some(vars) = match_template(CRAWL_URI_TEMPLATE, uri) {
+            return Some(Self::Crawl {
+                crawl_id: vars["crawl_id"].parse().ok()?,
+            });
+        }
+        match_template(FETCH_URI_TEMPLATE, uri).map(|mut vars| Self::Fetched {
+            file: vars.remove("file").unwrap_or_default(),
+        })
+    }
+
+    fn to_uri(&self) -> String {
+        match self {
+            Self::Crawl { crawl_id } => format!("kodegen://crawl/{}", crawl_id),
+            Self::Page { crawl_id, page } => format!("kodegen://crawl/{}/page/{}", crawl_id, page),
+            Self::Fetched { file } => format!("kodegen://fetch/{}", file),
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Self::Crawl { crawl_id } => format!("crawl {}", crawl_id),
+            Self::Page { crawl_id, page } => format!("crawl {} page {}", crawl_id, page),
+            Self::Fetched { file } => file.clone(),
+        }
+    }
+
+    fn mime_type(&self) -> Option<&'static str> {
+        Some("text/markdown")
+    }
+}
+
+/// Crawl output and fetched pages
+pub struct CitescrapeResources;
+
+impl ResourceProvider for CitescrapeResources {
+    type Uri = CitescrapeUri;
+
+    fn templates() -> Vec<RawResourceTemplate> {
+        vec![
+            resource_template(CRAWL_URI_TEMPLATE, "crawl", "Index of the pages saved by a scrape_url crawl", Some("text/markdown")),
+            resource_template(CRAWL_PAGE_URI_TEMPLATE, "crawl_page", "One page saved by a scrape_url crawl (1-based)", Some("text/markdown")),
+            resource_template(FETCH_URI_TEMPLATE, "fetched_page", "Markdown saved by fetch", Some("text/markdown")),
+        ]
+    }
+}
+
+impl ScrapeUrlOutput {
+    /// Resource for this crawl's saved output (None if nothing was saved)
+    #[must_use]
+    pub fn resource_uri(&self) -> Option<CitescrapeUri> {
+        self.output_dir.as_ref()?;
+        Some(CitescrapeUri::Crawl { crawl_id: self.crawl_id })
+    }
+}
+
+impl FetchOutput {
+    /// Resource for the saved markdown file
+    #[must_use]
+    pub fn resource_uri(&self) -> Option<CitescrapeUri> {
+        let file = Path::new(&self.path).file_name()?.to_str()?;
+        Some(CitescrapeUri::Fetched { file: file.to_string() })
+    }
+}
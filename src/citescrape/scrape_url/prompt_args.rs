@@ -3,16 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `scrape_url` prompts
+    pub enum ScrapeUrlScenario {
+        /// Multi-page crawling (default)
+        Crawling => "crawling",
+        /// Searching crawled content
+        Search => "search",
+        /// Background crawl management
+        Background => "background",
+    }
+}
+
 /// Prompt arguments for scrape_url tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScrapeUrlPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic URL scraping
-    /// - "crawling": Multi-page crawling
-    /// - "search": Searching crawled content
-    /// - "background": Background crawl management
-    /// - "extraction": Content extraction options
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ScrapeUrlScenario>,
 }
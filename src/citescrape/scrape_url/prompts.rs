@@ -1,8 +1,8 @@
 //! Prompt messages for scrape_url tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ScrapeUrlPromptArgs;
+use super::prompt_args::{ScrapeUrlPromptArgs, ScrapeUrlScenario};
 
 /// Prompt provider for scrape_url tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ScrapeUrlPrompts {
     type PromptArgs = ScrapeUrlPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("search") => prompt_search(),
-            Some("background") => prompt_background(),
-            _ => prompt_crawling(),
+        match args.scenario {
+            Some(ScrapeUrlScenario::Search) => prompt_search(),
+            Some(ScrapeUrlScenario::Background) => prompt_background(),
+            Some(ScrapeUrlScenario::Crawling) | None => prompt_crawling(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (crawling, search, background)".to_string()),
-                required: Some(false),
-            }
+            ScrapeUrlScenario::prompt_argument()
         ]
     }
 }
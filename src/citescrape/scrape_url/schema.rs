@@ -195,6 +195,7 @@ pub struct ScrapeSearchResult {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ScrapeUrlPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Unified website crawler with action-based dispatch (CRAWL/READ/LIST/KILL/SEARCH). Crawl entire sites, save to disk, build Tantivy search index"
@@ -206,6 +207,7 @@ impl ToolArgs for ScrapeUrlArgs {
     const NAME: &'static str = SCRAPE_URL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CITESCRAPE;
     const DESCRIPTION: &'static str = "Unified website crawler with action-based dispatch (CRAWL/READ/LIST/KILL/SEARCH). Crawl entire sites, save to disk, build Tantivy search index";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
 
     fn icon() -> char {
         '☄'  // COMET - tool-specific icon (overrides citescrape default)
@@ -3,9 +3,23 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `web_search` prompts
+    pub enum WebSearchScenario {
+        /// Basic DuckDuckGo web search
+        Basic => "basic",
+        /// Multi-query research pattern
+        Research => "research",
+        /// Effective query patterns
+        Queries => "queries",
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct WebSearchPromptArgs {
-    /// Scenario: basic, research
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<WebSearchScenario>,
 }
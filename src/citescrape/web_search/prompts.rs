@@ -1,8 +1,8 @@
 //! Prompt messages for web_search tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::WebSearchPromptArgs;
+use super::prompt_args::{WebSearchPromptArgs, WebSearchScenario};
 
 /// Prompt provider for web_search tool
 ///
@@ -14,21 +14,17 @@ impl PromptProvider for WebSearchPrompts {
     type PromptArgs = WebSearchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic_search(),
-            Some("research") => prompt_research_search(),
+        match args.scenario {
+            Some(WebSearchScenario::Basic) => prompt_basic_search(),
+            Some(WebSearchScenario::Research) => prompt_research_search(),
+            Some(WebSearchScenario::Queries) => prompt_query_patterns(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Search scenario: basic, research".to_string()),
-                required: Some(false),
-            }
+            WebSearchScenario::prompt_argument()
         ]
     }
 }
@@ -240,6 +236,54 @@ fn prompt_research_search() -> Vec<PromptMessage> {
     ]
 }
 
+/// Effective query patterns
+fn prompt_query_patterns() -> Vec<PromptMessage> {
+    vec![
+        PromptMessage {
+            role: PromptMessageRole::User,
+            content: PromptMessageContent::text(
+                "What are the most effective query patterns for web searching?",
+            ),
+        },
+        PromptMessage {
+            role: PromptMessageRole::Assistant,
+            content: PromptMessageContent::text(
+                "EFFECTIVE QUERY PATTERNS:\n\n\
+                 1. Specific technology:\n\
+                    web_search({\n\
+                        \"query\": \"actix-web middleware authentication example\"\n\
+                    })\n\n\
+                 2. Error messages:\n\
+                    web_search({\n\
+                        \"query\": \"rust borrow checker cannot borrow as mutable\"\n\
+                    })\n\n\
+                 3. Version-specific:\n\
+                    web_search({\n\
+                        \"query\": \"tokio 1.0 migration guide\"\n\
+                    })\n\n\
+                 4. Comparisons:\n\
+                    web_search({\n\
+                        \"query\": \"rust diesel vs sqlx comparison 2024\"\n\
+                    })\n\n\
+                 5. Best practices:\n\
+                    web_search({\n\
+                        \"query\": \"rust error handling best practices anyhow thiserror\"\n\
+                    })\n\n\
+                 6. Official docs:\n\
+                    web_search({\n\
+                        \"query\": \"site:docs.rs serde json\"\n\
+                    })\n\n\
+                 QUERY TIPS:\n\
+                 - Be specific with library names\n\
+                 - Include version numbers\n\
+                 - Add \"example\" or \"tutorial\"\n\
+                 - Use \"site:\" for specific domains\n\
+                 - Include error text for debugging",
+            ),
+        },
+    ]
+}
+
 /// Comprehensive guide to web search
 fn prompt_comprehensive() -> Vec<PromptMessage> {
     vec![
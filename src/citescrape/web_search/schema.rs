@@ -43,6 +43,7 @@ pub struct WebSearchResultItem {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WebSearchPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Perform web search using DuckDuckGo and return structured results with titles, URLs, and snippets"
@@ -54,6 +55,7 @@ impl ToolArgs for WebSearchArgs {
     const NAME: &'static str = WEB_SEARCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CITESCRAPE;
     const DESCRIPTION: &'static str = "Perform web search using DuckDuckGo and return structured results with titles, URLs, and snippets";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 
     fn icon() -> char {
         '⚶'  // VESTA - tool-specific icon (overrides citescrape default)
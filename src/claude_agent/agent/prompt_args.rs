@@ -3,15 +3,28 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `claude_agent` prompts
+    pub enum ClaudeAgentScenario {
+        /// Simple delegation (default)
+        Basic => "basic",
+        /// Tool-constrained agents
+        Specialized => "specialized",
+        /// Multi-agent coordination
+        Parallel => "parallel",
+        /// Research-focused with add_dirs
+        Research => "research",
+        /// Progress checking patterns
+        Monitoring => "monitoring",
+    }
+}
+
 /// Prompt arguments for claude_agent tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ClaudeAgentPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple delegation
-    /// - "specialized": Tool-constrained agents
-    /// - "parallel": Multi-agent coordination
-    /// - "research": Research-focused with add_dirs
-    /// - "monitoring": Progress checking patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ClaudeAgentScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for claude_agent tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ClaudeAgentPromptArgs;
+use super::prompt_args::{ClaudeAgentPromptArgs, ClaudeAgentScenario};
 
 /// Prompt provider for claude_agent tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for ClaudeAgentPrompts {
     type PromptArgs = ClaudeAgentPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("specialized") => prompt_specialized_agents(),
-            Some("parallel") => prompt_parallel_coordination(),
-            Some("research") => prompt_research_agents(),
-            Some("monitoring") => prompt_progress_monitoring(),
-            _ => prompt_basic_delegation(),
+        match args.scenario {
+            Some(ClaudeAgentScenario::Specialized) => prompt_specialized_agents(),
+            Some(ClaudeAgentScenario::Parallel) => prompt_parallel_coordination(),
+            Some(ClaudeAgentScenario::Research) => prompt_research_agents(),
+            Some(ClaudeAgentScenario::Monitoring) => prompt_progress_monitoring(),
+            Some(ClaudeAgentScenario::Basic) | None => prompt_basic_delegation(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, specialized, parallel, research, monitoring".to_string()),
-                required: Some(false),
-            }
+            ClaudeAgentScenario::prompt_argument()
         ]
     }
 }
@@ -3,7 +3,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_CLAUDE_AGENT, CLAUDE_AGENT};
-use crate::ToolArgs;
+use crate::{ToolArgs, ValidateArgs};
 
 // ============================================================================
 // ACTION ENUM
@@ -43,7 +43,7 @@ const fn default_timeout_ms() -> u64 {
 // ============================================================================
 
 /// Arguments for unified claude_agent tool
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
 pub struct ClaudeAgentArgs {
     /// Action to perform
     #[serde(default)]
@@ -61,6 +61,7 @@ pub struct ClaudeAgentArgs {
 
     // SPAWN/SEND-specific fields
     /// Prompt for agent (required for SPAWN/SEND)
+    #[required_if(action = "Spawn" | "Send")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<String>,
 
@@ -69,6 +70,7 @@ pub struct ClaudeAgentArgs {
     pub system_prompt: Option<String>,
 
     /// Maximum conversation turns (SPAWN only, default: 10)
+    #[range(min = 1)]
     #[serde(default)]
     pub max_turns: Option<u32>,
 
@@ -166,6 +168,7 @@ pub struct ClaudeAgentSummary {
 
 use crate::tool_metadata;
 use super::prompts::ClaudeAgentPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Unified Claude agent interface with action-based dispatch (SPAWN/SEND/READ/LIST/KILL). Spawn autonomous sub-agents for task delegation and parallel processing"
@@ -177,4 +180,5 @@ impl ToolArgs for ClaudeAgentArgs {
     const NAME: &'static str = CLAUDE_AGENT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CLAUDE_AGENT;
     const DESCRIPTION: &'static str = "Unified Claude agent interface with action-based dispatch (SPAWN/SEND/READ/LIST/KILL). Spawn autonomous sub-agents for task delegation and parallel processing";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
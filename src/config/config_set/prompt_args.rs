@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `config_set` prompts
+    pub enum SetConfigValueScenario {
+        /// Simple value changes
+        Basic => "basic",
+        /// Different value formats
+        ValueTypes => "value_types",
+    }
+}
+
 /// Prompt arguments for config_set tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SetConfigValuePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple value changes
-    /// - "security_aware": Understanding implications
-    /// - "value_types": Different value formats
-    /// - "workflow": Proper inspect-modify-verify
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<SetConfigValueScenario>,
 }
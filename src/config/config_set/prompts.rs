@@ -1,8 +1,8 @@
 //! Prompt messages for config_set tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::SetConfigValuePromptArgs;
+use super::prompt_args::{SetConfigValuePromptArgs, SetConfigValueScenario};
 
 /// Prompt provider for config_set tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for SetConfigValuePrompts {
     type PromptArgs = SetConfigValuePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("value_types") => prompt_value_types(),
+        match args.scenario {
+            Some(SetConfigValueScenario::Basic) => prompt_basic(),
+            Some(SetConfigValueScenario::ValueTypes) => prompt_value_types(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, value_types)".to_string()),
-                required: Some(false),
-            }
+            SetConfigValueScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_CONFIG, CONFIG_SET};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SetConfigValuePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // CONFIG_SET TOOL
@@ -55,4 +56,5 @@ impl ToolArgs for SetConfigValueArgs {
     const NAME: &'static str = CONFIG_SET;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CONFIG;
     const DESCRIPTION: &'static str = "Set a specific configuration value by key. WARNING: Should be used in a separate chat from file operations and command execution to prevent security violations. Always read config_get first before making changes";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `db_execute_sql` prompts
+    pub enum DbExecuteSqlScenario {
+        /// SQL injection prevention
+        Safety => "safety",
+        /// Common patterns and troubleshooting
+        Patterns => "patterns",
+    }
+}
+
 /// Prompt arguments for db_execute_sql tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DbExecuteSqlPromptArgs {
-    /// Scenario to show examples for
-    /// - "select": Safe read queries
-    /// - "modification": INSERT/UPDATE/DELETE (dangerous)
-    /// - "safety": SQL injection prevention
-    /// - "transactions": Transaction handling
-    /// - "troubleshooting": Query debugging
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<DbExecuteSqlScenario>,
 }
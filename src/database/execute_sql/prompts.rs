@@ -1,8 +1,8 @@
 //! Prompt messages for db_execute_sql tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::DbExecuteSqlPromptArgs;
+use super::prompt_args::{DbExecuteSqlPromptArgs, DbExecuteSqlScenario};
 
 /// Prompt provider for db_execute_sql tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for DbExecuteSqlPrompts {
     type PromptArgs = DbExecuteSqlPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("safety") => prompt_safety(),
-            Some("patterns") => prompt_patterns(),
+        match args.scenario {
+            Some(DbExecuteSqlScenario::Safety) => prompt_safety(),
+            Some(DbExecuteSqlScenario::Patterns) => prompt_patterns(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (default: basic SQL queries, safety: parameterized queries and transactions, patterns: common patterns and troubleshooting)".to_string()),
-                required: Some(false),
-            }
+            DbExecuteSqlScenario::prompt_argument()
         ]
     }
 }
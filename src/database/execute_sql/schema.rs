@@ -4,8 +4,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_DATABASE, DB_EXECUTE_SQL};
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::super::types::{SqlRow, SqlStatementError};
 use super::prompts::DbExecuteSqlPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -19,6 +21,58 @@ pub struct ExecuteSQLArgs {
     pub sql: String,
 }
 
+impl ExecuteSQLArgs {
+    /// Statements that may modify data or schema (anything but plain queries)
+    #[must_use]
+    pub fn write_statements(&self) -> Vec<&str> {
+        self.sql
+            .split(';')
+            .map(str::trim)
+            .filter(|statement| !statement.is_empty() && !is_read_only_statement(statement))
+            .collect()
+    }
+}
+
+/// Whether `statement` only reads (SELECT, SHOW, EXPLAIN, ... or a WITH query without DML)
+fn is_read_only_statement(statement: &str) -> bool {
+    let words: Vec<String> = statement
+        .lines()
+        .map(|line| line.split("--").next().unwrap_or(""))
+        .flat_map(|line| line.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_')))
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_uppercase)
+        .collect();
+    is_read_only_words(&words)
+}
+
+fn is_read_only_words(words: &[String]) -> bool {
+    match words.first().map(String::as_str) {
+        Some("SELECT" | "SHOW" | "DESCRIBE" | "DESC" | "VALUES") => true,
+        // EXPLAIN only plans; EXPLAIN ANALYZE also runs the statement it wraps
+        Some("EXPLAIN") => {
+            let rest = &words[1..];
+            let Some(start) = rest.iter().position(|word| is_statement_keyword(word)) else {
+                return !rest.iter().any(|word| matches!(word.as_str(), "ANALYZE" | "ANALYSE"));
+            };
+            let options = &rest[..start];
+            !options.iter().any(|word| matches!(word.as_str(), "ANALYZE" | "ANALYSE")) || is_read_only_words(&rest[start..])
+        }
+        Some("WITH") => !words
+            .iter()
+            .any(|word| matches!(word.as_str(), "INSERT" | "UPDATE" | "DELETE" | "MERGE")),
+        _ => false,
+    }
+}
+
+/// Words that start the statement wrapped by `EXPLAIN` (after its options)
+fn is_statement_keyword(word: &str) -> bool {
+    matches!(
+        word,
+        "SELECT" | "VALUES" | "TABLE" | "WITH" | "INSERT" | "UPDATE" | "DELETE" | "MERGE" | "REPLACE" | "CREATE"
+            | "EXECUTE" | "DECLARE"
+    )
+}
+
 // ============================================================================
 // TOOL OUTPUT
 // ============================================================================
@@ -54,4 +108,22 @@ impl ToolArgs for ExecuteSQLArgs {
     const NAME: &'static str = DB_EXECUTE_SQL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "Execute SQL query with connection pooling and timeout support. Prefer read-only SELECT queries over modifications.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
+
+    /// Read-only queries run unconfirmed; any write statement needs confirmation
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        let writes = self.write_statements();
+        if writes.is_empty() {
+            return None;
+        }
+
+        let mut action = DestructiveAction::new(
+            format!("Execute {} data-modifying SQL statement(s)", writes.len()),
+            "connected database",
+        );
+        for statement in writes {
+            action = action.effect(statement);
+        }
+        Some(action)
+    }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_DATABASE, DB_LIST_SCHEMAS};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ListSchemasPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -41,4 +42,5 @@ impl ToolArgs for ListSchemasArgs {
     const NAME: &'static str = DB_LIST_SCHEMAS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "List all schemas (namespaces) in the database. Use this to discover database structure before querying.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `db_list_tables` prompts
+    pub enum ListTablesScenario {
+        /// Simple table listing (default)
+        Basic => "basic",
+        /// Finding specific tables
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for db_list_tables tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListTablesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple table listing
-    /// - "filtering": Finding specific tables
-    /// - "exploration": Table discovery workflow
-    /// - "views": Tables vs views
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ListTablesScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for db_list_tables tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ListTablesPromptArgs;
+use super::prompt_args::{ListTablesPromptArgs, ListTablesScenario};
 
 /// Prompt provider for db_list_tables tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for ListTablesPrompts {
     type PromptArgs = ListTablesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("filtering") => prompt_filtering(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(ListTablesScenario::Filtering) => prompt_filtering(),
+            Some(ListTablesScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering)".to_string()),
-                required: Some(false),
-            }
+            ListTablesScenario::prompt_argument()
         ]
     }
 }
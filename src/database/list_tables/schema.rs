@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_DATABASE, DB_LIST_TABLES};
 use crate::{ToolArgs, tool_metadata};
 use super::super::types::TableInfo;
 use super::prompts::ListTablesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -49,4 +50,5 @@ impl ToolArgs for ListTablesArgs {
     const NAME: &'static str = DB_LIST_TABLES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "List all tables and views in a schema. Use this to discover available tables before querying.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
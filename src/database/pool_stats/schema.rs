@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_DATABASE, DB_POOL_STATS};
 use crate::{ToolArgs, tool_metadata};
 use super::super::types::{ConnectionStats, PoolConfiguration, PoolHealth};
 use super::prompts::PoolStatsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -42,4 +43,5 @@ impl ToolArgs for GetPoolStatsArgs {
     const NAME: &'static str = DB_POOL_STATS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "Get connection pool statistics including active/idle connections, utilization, and health metrics.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,13 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `db_stored_procedures` prompts
+    pub enum GetStoredProceduresScenario {
+        /// Listing procedures (default)
+        Basic => "basic",
+        /// Understanding parameters
+        Signatures => "signatures",
+    }
+}
+
 /// Prompt arguments for db_stored_procedures tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetStoredProceduresPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Listing procedures
-    /// - "signatures": Understanding parameters
-    /// - "usage": Calling stored procedures
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetStoredProceduresScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for db_stored_procedures tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetStoredProceduresPromptArgs;
+use super::prompt_args::{GetStoredProceduresPromptArgs, GetStoredProceduresScenario};
 
 /// Prompt provider for db_stored_procedures tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for StoredProceduresPrompts {
     type PromptArgs = GetStoredProceduresPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("signatures") => prompt_signatures(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GetStoredProceduresScenario::Signatures) => prompt_signatures(),
+            Some(GetStoredProceduresScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, signatures)".to_string()),
-                required: Some(false),
-            }
+            GetStoredProceduresScenario::prompt_argument()
         ]
     }
 }
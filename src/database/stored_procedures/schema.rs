@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_DATABASE, DB_STORED_PROCEDURES};
 use crate::{ToolArgs, tool_metadata};
 use super::super::types::ProcedureInfo;
 use super::prompts::StoredProceduresPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -50,4 +51,5 @@ impl ToolArgs for GetStoredProceduresArgs {
     const NAME: &'static str = DB_STORED_PROCEDURES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "List stored procedures (functions) in a schema with parameters and return types.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
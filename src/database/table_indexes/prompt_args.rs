@@ -3,10 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `db_table_indexes` prompts
+    pub enum GetTableIndexesScenario {
+        /// Using index information to optimize queries
+        Usage => "usage",
+    }
+}
+
 /// Prompt arguments for db_table_indexes tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetTableIndexesPromptArgs {
-    /// Scenario: types, composite, performance, usage
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetTableIndexesScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for db_table_indexes tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetTableIndexesPromptArgs;
+use super::prompt_args::{GetTableIndexesPromptArgs, GetTableIndexesScenario};
 
 /// Prompt provider for db_table_indexes tool
 pub struct TableIndexesPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for TableIndexesPrompts {
     type PromptArgs = GetTableIndexesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("usage") => prompt_usage_scenarios(),
+        match args.scenario {
+            Some(GetTableIndexesScenario::Usage) => prompt_usage_scenarios(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Index inspection scenario: usage (optional) for practical patterns".to_string()),
-                required: Some(false),
-            }
+            GetTableIndexesScenario::prompt_argument()
         ]
     }
 }
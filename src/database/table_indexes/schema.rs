@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_DATABASE, DB_TABLE_INDEXES};
 use crate::{ToolArgs, tool_metadata};
 use super::super::types::IndexInfo;
 use super::prompts::TableIndexesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -49,4 +50,5 @@ impl ToolArgs for GetTableIndexesArgs {
     const NAME: &'static str = DB_TABLE_INDEXES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "View indexes on a table including types (btree, hash, gin), columns, and uniqueness constraints for query optimization.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
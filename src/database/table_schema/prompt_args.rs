@@ -3,14 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `db_table_schema` prompts
+    pub enum GetTableSchemaScenario {
+        /// Getting table structure
+        Basic => "basic",
+    }
+}
+
 /// Prompt arguments for db_table_schema tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetTableSchemaPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Getting table structure
-    /// - "relationships": Understanding foreign keys
-    /// - "query_building": Using schema for queries
-    /// - "data_types": Understanding column types
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetTableSchemaScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for db_table_schema tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetTableSchemaPromptArgs;
+use super::prompt_args::{GetTableSchemaPromptArgs, GetTableSchemaScenario};
 
 /// Prompt provider for db_table_schema tool
 ///
@@ -14,20 +14,14 @@ impl PromptProvider for TableSchemaPrompts {
     type PromptArgs = GetTableSchemaPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") | None => prompt_basic(),
-            _ => prompt_basic(), // Default to basic for any unknown scenario
+        match args.scenario {
+            Some(GetTableSchemaScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic)".to_string()),
-                required: Some(false),
-            }
+            GetTableSchemaScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_DATABASE, DB_TABLE_SCHEMA};
 use crate::{ToolArgs, tool_metadata};
 use super::super::types::ColumnInfo;
 use super::prompts::TableSchemaPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // TOOL ARGUMENTS
@@ -53,4 +54,5 @@ impl ToolArgs for GetTableSchemaArgs {
     const NAME: &'static str = DB_TABLE_SCHEMA;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_DATABASE;
     const DESCRIPTION: &'static str = "Get complete table structure including columns, data types, constraints, primary keys, and foreign key relationships.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -1,49 +1,219 @@
 //! MCP content extraction utilities
 
 use super::error::DeserializeError;
+use super::types::Branding;
+use crate::tool::{ContentPart, ToolStatus};
+
+/// Brand symbol that starts every branded line
+const BRAND_SYMBOL: char = 'ⓚ';
+
+/// ANSI 256-color code used for the duration of successful calls
+const SUCCESS_TIMING_COLOR: &str = "35";
+
+/// Positions of the display and metadata blocks within a content array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentLayout {
+    /// Index of the branded line, if present
+    pub branding: Option<usize>,
+
+    /// Index of the display text
+    pub display: usize,
+
+    /// Index of the JSON metadata block
+    pub metadata: usize,
+}
+
+/// Detect where display and metadata live in a content array.
+///
+/// - A branded line (`ⓚ icon tool_name Ns`) at `content[0]` shifts everything by one.
+/// - The metadata block is the last text block after the display that parses as
+///   a JSON object; if none does, it is assumed to directly follow the display.
+pub fn locate_layout(content: &[rmcp::model::Content]) -> ContentLayout {
+    let branding = content
+        .first()
+        .and_then(text_of)
+        .filter(|text| parse_branding(text).is_some())
+        .map(|_| 0);
+
+    let display = branding.map_or(0, |i| i + 1);
+    let metadata = content
+        .iter()
+        .enumerate()
+        .skip(display + 1)
+        .rev()
+        .find(|(_, c)| {
+            text_of(c).is_some_and(|text| {
+                serde_json::from_str::<serde_json::Value>(text).is_ok_and(|v| v.is_object())
+            })
+        })
+        .map_or(display + 1, |(i, _)| i);
+
+    ContentLayout {
+        branding,
+        display,
+        metadata,
+    }
+}
+
+/// Parse a branded line into its parts.
+///
+/// Returns `None` if `text` is not a branded line.
+pub fn parse_branding(text: &str) -> Option<Branding> {
+    let stripped = strip_ansi(text);
+    let rest = stripped.trim().strip_prefix(BRAND_SYMBOL)?.trim_start();
+
+    // "<icon> <tool_name>   <N>s"
+    let mut chars = rest.chars();
+    let icon = chars.next()?;
+    let mut parts = chars.as_str().split_whitespace();
+    let tool_name = parts.next()?.to_string();
+    let duration_s = parts.next()?.strip_suffix('s')?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let status = if timing_color(text) == Some(SUCCESS_TIMING_COLOR) {
+        ToolStatus::Success
+    } else {
+        ToolStatus::Error
+    };
+
+    Some(Branding {
+        tool_name,
+        icon,
+        duration_s,
+        status,
+    })
+}
+
+/// Extract the parsed branded line, if the layout has one.
+pub fn extract_branding(
+    content: &[rmcp::model::Content],
+    layout: &ContentLayout,
+) -> Option<Branding> {
+    content
+        .get(layout.branding?)
+        .and_then(text_of)
+        .and_then(parse_branding)
+}
 
 /// Extract display text from MCP content array.
 ///
-/// Reads from `content[0]` which contains human-readable text.
-pub fn extract_display(content: &[rmcp::model::Content]) -> Result<String, DeserializeError> {
+/// Reads from the display block of `layout` (`content[0]`, or `content[1]` when branded).
+pub fn extract_display(
+    content: &[rmcp::model::Content],
+    layout: &ContentLayout,
+) -> Result<String, DeserializeError> {
+    let index = layout.display;
     content
-        .first()
+        .get(index)
         .ok_or_else(|| DeserializeError::InvalidMcpResponse {
-            reason: "Missing content[0] (display text)".to_string(),
-        })
-        .and_then(|c| {
-            if let rmcp::model::RawContent::Text(text_content) = &**c {
-                Ok(text_content.text.clone())
-            } else {
-                Err(DeserializeError::InvalidContentType { index: 0 })
-            }
+            reason: format!("Missing content[{}] (display text)", index),
         })
+        .and_then(|c| text_of(c).map(str::to_string).ok_or(DeserializeError::InvalidContentType { index }))
 }
 
 /// Extract typed JSON string from MCP content array.
 ///
-/// Reads from `content[1]` which contains serialized Output struct.
-pub fn extract_typed_json(content: &[rmcp::model::Content]) -> Result<String, DeserializeError> {
+/// Reads from the metadata block of `layout` which contains the serialized Output struct.
+pub fn extract_typed_json(
+    content: &[rmcp::model::Content],
+    layout: &ContentLayout,
+) -> Result<String, DeserializeError> {
+    let index = layout.metadata;
     content
-        .get(1)
+        .get(index)
         .ok_or_else(|| DeserializeError::InvalidMcpResponse {
-            reason: "Missing content[1] (typed output)".to_string(),
+            reason: format!("Missing content[{}] (typed output)", index),
         })
-        .and_then(|c| {
-            if let rmcp::model::RawContent::Text(text_content) = &**c {
-                Ok(text_content.text.clone())
-            } else {
-                Err(DeserializeError::InvalidContentType { index: 1 })
+        .and_then(|c| text_of(c).map(str::to_string).ok_or(DeserializeError::InvalidContentType { index }))
+}
+
+/// Extract the non-text content parts, in order.
+///
+/// Text blocks are skipped: they are the branded line, display or metadata.
+pub fn extract_parts(content: &[rmcp::model::Content]) -> Vec<ContentPart> {
+    content.iter().filter_map(ContentPart::from_content).collect()
+}
+
+/// Text of a content block, if it is text content
+fn text_of(content: &rmcp::model::Content) -> Option<&str> {
+    match &**content {
+        rmcp::model::RawContent::Text(text_content) => Some(&text_content.text),
+        _ => None,
+    }
+}
+
+/// Remove ANSI SGR escape sequences (`ESC [ ... m`)
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
             }
-        })
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Color code of the last `ESC[38;5;<n>m` sequence (the duration color)
+fn timing_color(text: &str) -> Option<&str> {
+    const PREFIX: &str = "\x1b[38;5;";
+    let start = text.rfind(PREFIX)? + PREFIX.len();
+    text[start..].split('m').next()
+}
+
+/// Parsed MCP tool response: content blocks plus optional structured content.
+pub struct McpResponse {
+    /// `content` array
+    pub content: Vec<rmcp::model::Content>,
+
+    /// `structuredContent`, when the server emitted the structured layout
+    pub structured_content: Option<serde_json::Value>,
+}
+
+/// Full `CallToolResult` object layout (only the fields the deserializer needs)
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CallToolResultLayout {
+    content: Vec<rmcp::model::Content>,
+    #[serde(default)]
+    structured_content: Option<serde_json::Value>,
 }
 
-/// Parse MCP JSON response string into content array.
+/// Parse MCP JSON response string into content array and structured content.
 ///
-/// Accepts the full MCP response JSON as a string and parses it into
-/// the expected Vec<rmcp::model::Content> structure.
-pub fn parse_mcp_response(json_str: &str) -> Result<Vec<rmcp::model::Content>, DeserializeError> {
-    serde_json::from_str(json_str).map_err(|e| DeserializeError::InvalidMcpResponse {
+/// Accepts either layout:
+/// - the bare content array (`[{"type":"text",...}, ...]`)
+/// - a full `CallToolResult` object (`{"content":[...], "structuredContent":{...}}`)
+pub fn parse_mcp_response(json_str: &str) -> Result<McpResponse, DeserializeError> {
+    let value: serde_json::Value =
+        serde_json::from_str(json_str).map_err(|e| DeserializeError::InvalidMcpResponse {
+            reason: format!("Failed to parse MCP response JSON: {}", e),
+        })?;
+
+    if value.is_object() {
+        let layout: CallToolResultLayout =
+            serde_json::from_value(value).map_err(|e| DeserializeError::InvalidMcpResponse {
+                reason: format!("Failed to parse MCP CallToolResult: {}", e),
+            })?;
+        return Ok(McpResponse {
+            content: layout.content,
+            structured_content: layout.structured_content.filter(|v| !v.is_null()),
+        });
+    }
+
+    let content = serde_json::from_value(value).map_err(|e| DeserializeError::InvalidMcpResponse {
         reason: format!("Failed to parse MCP response JSON: {}", e),
+    })?;
+    Ok(McpResponse {
+        content,
+        structured_content: None,
     })
 }
@@ -9,16 +9,29 @@ mod registry;
 mod types;
 
 pub use error::DeserializeError;
-pub use types::{AnyToolOutput, ToolOutputResult};
+pub use registry::{ToolOutputDeserializer, is_registered, registered_tool_names};
+pub use types::{AnyToolOutput, Branding, ToolOutputResult};
 
 /// Deserialize a full MCP tool response JSON.
 ///
-/// Takes the complete MCP response JSON string (entire Vec<Content>) and
-/// the tool name, returning both display text and typed output together.
+/// Takes the complete MCP response JSON string and the tool name, returning
+/// both display text and typed output together.
+///
+/// Branded responses (a `ⓚ icon tool_name Ns` line at `content[0]`, as produced
+/// by `into_call_tool_result_with_branding`) are detected automatically; the
+/// parsed line is returned in `ToolOutputResult::branding`.
+///
+/// Images, audio and resources following the metadata are returned in
+/// `ToolOutputResult::parts`.
+///
+/// Both response layouts are accepted:
+/// - the content array, with typed metadata as JSON text in `content[1]`
+/// - a full `CallToolResult` object; when it carries `structuredContent`, the
+///   typed output is read from there and `content[1]` is not required
 ///
 /// # Arguments
 /// * `tool_name` - The canonical tool name (e.g., "fs_read_file", "git_add")
-/// * `mcp_response_json` - Full MCP response JSON string containing content array
+/// * `mcp_response_json` - Content array or full `CallToolResult` JSON string
 ///
 /// # Returns
 /// * `Ok(ToolOutputResult)` - Successfully deserialized with display + typed output
@@ -27,6 +40,7 @@ pub use types::{AnyToolOutput, ToolOutputResult};
 /// # Example
 /// ```rust
 /// use kodegen_mcp_schema::deserialize::deserialize_tool_output;
+/// use kodegen_mcp_schema::filesystem::FsReadFileOutput;
 ///
 /// let mcp_json = r#"[
 ///     {"type":"text","text":"📄 Read file: src/main.rs\n 📊 Content: 500 lines"},
@@ -36,31 +50,38 @@ pub use types::{AnyToolOutput, ToolOutputResult};
 /// let result = deserialize_tool_output("fs_read_file", mcp_json)?;
 ///
 /// println!("Display: {}", result.display);
-/// match result.typed {
-///     AnyToolOutput::FsReadFile(output) => {
-///         println!("Path: {}", output.path);
-///         println!("Lines: {:?}", output.total_lines);
-///     }
-///     _ => unreachable!(),
-/// }
+/// let output = result.typed.downcast_ref::<FsReadFileOutput>().unwrap();
+/// println!("Path: {}", output.path);
+/// println!("Lines: {:?}", output.total_lines);
 /// ```
 pub fn deserialize_tool_output(
     tool_name: &str,
     mcp_response_json: &str,
 ) -> Result<ToolOutputResult, DeserializeError> {
-    // Parse full MCP response JSON into content array
-    let content = extract::parse_mcp_response(mcp_response_json)?;
+    // Parse full MCP response JSON into content array (+ structuredContent)
+    let response = extract::parse_mcp_response(mcp_response_json)?;
+
+    // Locate branded line, display and metadata blocks
+    let layout = extract::locate_layout(&response.content);
+    let branding = extract::extract_branding(&response.content, &layout);
 
-    // Extract display text from content[0]
-    let display = extract::extract_display(&content)?;
+    // Extract display text from content[0] (content[1] when branded)
+    let display = extract::extract_display(&response.content, &layout)?;
 
-    // Extract typed JSON from content[1]
-    let typed_json = extract::extract_typed_json(&content)?;
+    // Collect images, audio and resources
+    let parts = extract::extract_parts(&response.content);
 
-    // Deserialize typed output based on tool name
-    let typed = registry::deserialize_by_tool_name(tool_name, &typed_json)?;
+    // Deserialize typed output based on tool name, preferring structuredContent
+    let typed = match response.structured_content {
+        Some(structured) => registry::deserialize_value_by_tool_name(tool_name, structured)?,
+        None => {
+            // Extract typed JSON from the metadata block
+            let typed_json = extract::extract_typed_json(&response.content, &layout)?;
+            registry::deserialize_by_tool_name(tool_name, &typed_json)?
+        }
+    };
 
-    Ok(ToolOutputResult { display, typed })
+    Ok(ToolOutputResult { display, typed, branding, parts })
 }
 
 /// Deserialize typed output from raw JSON (content[1] only).
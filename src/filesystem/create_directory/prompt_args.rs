@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_create_directory` prompts
+    pub enum FsCreateDirectoryScenario {
+        /// Simple directory creation (default)
+        Basic => "basic",
+        /// Creating nested paths
+        Nested => "nested",
+    }
+}
+
 /// Prompt arguments for fs_create_directory tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsCreateDirectoryPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple directory creation
-    /// - "nested": Creating nested paths
-    /// - "project_setup": Creating project structures
-    /// - "idempotent": Safe repeated calls
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsCreateDirectoryScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for fs_create_directory tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsCreateDirectoryPromptArgs;
+use super::prompt_args::{FsCreateDirectoryPromptArgs, FsCreateDirectoryScenario};
 
 /// Prompt provider for fs_create_directory tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for CreateDirectoryPrompts {
     type PromptArgs = FsCreateDirectoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("nested") => prompt_nested(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(FsCreateDirectoryScenario::Nested) => prompt_nested(),
+            Some(FsCreateDirectoryScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (basic, nested)".to_string()),
-                required: Some(false),
-            }
+            FsCreateDirectoryScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_FILESYSTEM, FS_CREATE_DIRECTORY};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreateDirectoryPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // CREATE DIRECTORY ARGS
@@ -45,4 +46,5 @@ impl ToolArgs for FsCreateDirectoryArgs {
     const NAME: &'static str = FS_CREATE_DIRECTORY;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Create a new directory or ensure a directory exists. Automatically creates parent directories (like mkdir -p)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_delete_directory` prompts
+    pub enum FsDeleteDirectoryScenario {
+        /// Simple deletion with safety
+        Basic => "basic",
+        /// Understanding recursive confirmation
+        Safety => "safety",
+    }
+}
+
 /// Prompt arguments for fs_delete_directory tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsDeleteDirectoryPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple deletion with safety
-    /// - "safety": Understanding recursive confirmation
-    /// - "verification": Verify before delete workflow
-    /// - "alternatives": When NOT to delete
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsDeleteDirectoryScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for fs_delete_directory tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsDeleteDirectoryPromptArgs;
+use super::prompt_args::{FsDeleteDirectoryPromptArgs, FsDeleteDirectoryScenario};
 
 /// Prompt provider for fs_delete_directory tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for DeleteDirectoryPrompts {
     type PromptArgs = FsDeleteDirectoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("safety") => prompt_safety(),
+        match args.scenario {
+            Some(FsDeleteDirectoryScenario::Basic) => prompt_basic(),
+            Some(FsDeleteDirectoryScenario::Safety) => prompt_safety(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, safety)".to_string()),
-                required: Some(false),
-            }
+            FsDeleteDirectoryScenario::prompt_argument()
         ]
     }
 }
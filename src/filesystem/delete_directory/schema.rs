@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_FILESYSTEM, FS_DELETE_DIRECTORY};
 
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::prompts::DeleteDirectoryPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // DELETE DIRECTORY ARGS
@@ -48,4 +50,12 @@ impl ToolArgs for FsDeleteDirectoryArgs {
     const NAME: &'static str = FS_DELETE_DIRECTORY;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Delete a directory and all its contents recursively. This operation is permanent and cannot be undone";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
+
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        Some(
+            DestructiveAction::new("Delete a directory and all its contents", &self.path)
+                .effect("Every file and subdirectory inside it is permanently removed"),
+        )
+    }
 }
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_FILESYSTEM, FS_DELETE_FILE};
 
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::prompts::DeleteFilePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // DELETE FILE ARGS
@@ -44,4 +46,9 @@ impl ToolArgs for FsDeleteFileArgs {
     const NAME: &'static str = FS_DELETE_FILE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Delete a file from the filesystem. This operation is permanent and cannot be undone";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
+
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        Some(DestructiveAction::new("Delete a file", &self.path))
+    }
 }
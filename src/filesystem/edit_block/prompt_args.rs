@@ -3,16 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_edit_block` prompts
+    pub enum FsEditBlockScenario {
+        /// Basic string replacement
+        Basic => "basic",
+        /// Precise editing patterns
+        Precision => "precision",
+        /// Common editing workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for fs_edit_block tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsEditBlockPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic string replacement
-    /// - "precision": Precise editing patterns
-    /// - "multiline": Multi-line edits
-    /// - "safety": Safe editing practices
-    /// - "workflows": Common editing workflows
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsEditBlockScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for fs_edit_block tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsEditBlockPromptArgs;
+use super::prompt_args::{FsEditBlockPromptArgs, FsEditBlockScenario};
 
 /// Prompt provider for fs_edit_block tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for EditBlockPrompts {
     type PromptArgs = FsEditBlockPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("precision") => prompt_precision(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(FsEditBlockScenario::Basic) => prompt_basic(),
+            Some(FsEditBlockScenario::Precision) => prompt_precision(),
+            Some(FsEditBlockScenario::Workflows) => prompt_workflows(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, precision, workflows)".to_string()),
-                required: Some(false),
-            }
+            FsEditBlockScenario::prompt_argument()
         ]
     }
 }
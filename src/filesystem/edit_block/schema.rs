@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_FILESYSTEM, FS_EDIT_BLOCK};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::EditBlockPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // EDIT BLOCK ARGS
@@ -59,4 +60,5 @@ impl ToolArgs for FsEditBlockArgs {
     const NAME: &'static str = FS_EDIT_BLOCK;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Apply surgical text replacements to files. Takes old_string and new_string, performs exact string replacement";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_list_directory` prompts
+    pub enum FsListDirectoryScenario {
+        /// Simple directory listing
+        Basic => "basic",
+        /// Including hidden files
+        Hidden => "hidden",
+    }
+}
+
 /// Prompt arguments for fs_list_directory tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsListDirectoryPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple directory listing
-    /// - "hidden": Including hidden files
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsListDirectoryScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for fs_list_directory tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsListDirectoryPromptArgs;
+use super::prompt_args::{FsListDirectoryPromptArgs, FsListDirectoryScenario};
 
 /// Prompt provider for fs_list_directory tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ListDirectoryPrompts {
     type PromptArgs = FsListDirectoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("hidden") => prompt_hidden(),
+        match args.scenario {
+            Some(FsListDirectoryScenario::Basic) => prompt_basic(),
+            Some(FsListDirectoryScenario::Hidden) => prompt_hidden(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, hidden)".to_string()),
-                required: Some(false),
-            }
+            FsListDirectoryScenario::prompt_argument()
         ]
     }
 }
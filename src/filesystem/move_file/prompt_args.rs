@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_move_file` prompts
+    pub enum FsMoveFileScenario {
+        /// Renaming files/directories
+        Rename => "rename",
+        /// Moving to different location
+        Relocate => "relocate",
+    }
+}
+
 /// Prompt arguments for fs_move_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsMoveFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "rename": Renaming files/directories
-    /// - "relocate": Moving to different location
-    /// - "organize": Organizing file structures
-    /// - "backup": Creating backups via move
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsMoveFileScenario>,
 }
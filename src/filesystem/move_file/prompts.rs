@@ -1,8 +1,8 @@
 //! Prompt messages for fs_move_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsMoveFilePromptArgs;
+use super::prompt_args::{FsMoveFilePromptArgs, FsMoveFileScenario};
 
 /// Prompt provider for fs_move_file tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for MoveFilePrompts {
     type PromptArgs = FsMoveFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("rename") => prompt_rename(),
-            Some("relocate") => prompt_relocate(),
+        match args.scenario {
+            Some(FsMoveFileScenario::Rename) => prompt_rename(),
+            Some(FsMoveFileScenario::Relocate) => prompt_relocate(),
             _ => prompt_rename(),  // Default to rename scenario
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (rename, relocate)".to_string()),
-                required: Some(false),
-            }
+            FsMoveFileScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_FILESYSTEM, FS_MOVE_FILE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::MoveFilePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // MOVE FILE ARGS
@@ -48,4 +49,5 @@ impl ToolArgs for FsMoveFileArgs {
     const NAME: &'static str = FS_MOVE_FILE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Move or rename files and directories. Can move files between directories and rename them in a single operation";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_read_file` prompts
+    pub enum FsReadFileScenario {
+        /// Simple file reading (default)
+        Basic => "basic",
+        /// Handling big files with offset/length
+        LargeFiles => "large_files",
+    }
+}
+
 /// Prompt arguments for fs_read_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsReadFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple file reading
-    /// - "large_files": Handling big files with offset/length
-    /// - "code_files": Reading source code
-    /// - "config_files": Reading configuration
-    /// - "urls": Reading from URLs
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsReadFileScenario>,
 }
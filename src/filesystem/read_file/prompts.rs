@@ -1,8 +1,8 @@
 //! Prompt messages for fs_read_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsReadFilePromptArgs;
+use super::prompt_args::{FsReadFilePromptArgs, FsReadFileScenario};
 
 /// Prompt provider for fs_read_file tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for ReadFilePrompts {
     type PromptArgs = FsReadFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("large_files") => prompt_large_files(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(FsReadFileScenario::LargeFiles) => prompt_large_files(),
+            Some(FsReadFileScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, large_files)".to_string()),
-                required: Some(false),
-            }
+            FsReadFileScenario::prompt_argument()
         ]
     }
 }
@@ -53,6 +53,16 @@ pub struct FsReadFileOutput {
     pub content: String,
 }
 
+impl FsReadFileOutput {
+    /// The image as a content part for vision-capable clients
+    /// (None unless `is_image`, in which case `content` holds the base64 data)
+    #[must_use]
+    pub fn image_part(&self) -> Option<crate::tool::ContentPart> {
+        self.is_image
+            .then(|| crate::tool::ContentPart::image(self.content.clone(), self.mime_type.clone()))
+    }
+}
+
 // ============================================================================
 // TOOL ARGS TRAIT IMPLEMENTATION
 // ============================================================================
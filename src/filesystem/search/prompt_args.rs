@@ -3,16 +3,30 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_search` prompts
+    pub enum FsSearchScenario {
+        /// Basic content and filename search
+        Basic => "basic",
+        /// Regex patterns and filtering
+        Patterns => "patterns",
+        /// Pattern modes for filename searches (regex, glob, substring)
+        PatternModes => "pattern_modes",
+        /// Search options and modes
+        Options => "options",
+        /// Background search management
+        Background => "background",
+        /// Search workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for fs_search tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsSearchPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic content and filename search
-    /// - "patterns": Regex patterns and filtering
-    /// - "options": Search options and modes
-    /// - "background": Background search management
-    /// - "workflows": Search workflows
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsSearchScenario>,
 }
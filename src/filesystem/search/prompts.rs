@@ -1,8 +1,8 @@
 //! Prompt messages for fs_search tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
-use super::prompt_args::FsSearchPromptArgs;
+use super::prompt_args::{FsSearchPromptArgs, FsSearchScenario};
 
 /// Prompt provider for fs_search tool
 ///
@@ -14,26 +14,19 @@ impl PromptProvider for SearchPrompts {
     type PromptArgs = FsSearchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("patterns") => prompt_patterns(),
-            Some("pattern_modes") => prompt_pattern_modes(),
-            Some("options") => prompt_options(),
-            Some("background") => prompt_background(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(FsSearchScenario::Basic) => prompt_basic(),
+            Some(FsSearchScenario::Patterns) => prompt_patterns(),
+            Some(FsSearchScenario::PatternModes) => prompt_pattern_modes(),
+            Some(FsSearchScenario::Options) => prompt_options(),
+            Some(FsSearchScenario::Background) => prompt_background(),
+            Some(FsSearchScenario::Workflows) => prompt_workflows(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![PromptArgument {
-            name: "scenario".to_string(),
-            title: None,
-            description: Some(
-                "Scenario to show (basic, patterns, pattern_modes, options, background, workflows)".to_string(),
-            ),
-            required: Some(false),
-        }]
+        vec![FsSearchScenario::prompt_argument()]
     }
 }
 
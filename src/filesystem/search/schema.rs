@@ -3,7 +3,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_FILESYSTEM, FS_SEARCH};
-use crate::{ToolArgs, tool_metadata};
+use crate::{ToolArgs, ValidateArgs, tool_metadata};
 use crate::filesystem::shared::*;
 use super::prompts::SearchPrompts;
 
@@ -12,7 +12,7 @@ use super::prompts::SearchPrompts;
 // ============================================================================
 
 /// Arguments for `fs_search` tool
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
 pub struct FsSearchArgs {
     /// Action to perform - defaults to SEARCH for backward compatibility
     #[serde(default)]
@@ -36,10 +36,12 @@ pub struct FsSearchArgs {
     // ========================================================================
 
     /// Root directory to search (required for SEARCH, ignored for READ/LIST/KILL)
+    #[required_if(action = "Search")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
 
     /// Pattern to search for (required for SEARCH, ignored for READ/LIST/KILL)
+    #[required_if(action = "Search")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
 
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `fs_write_file` prompts
+    pub enum FsWriteFileScenario {
+        /// Simple file writing (default)
+        Basic => "basic",
+        /// Adding to existing files
+        Append => "append",
+    }
+}
+
 /// Prompt arguments for fs_write_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FsWriteFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple file writing
-    /// - "append": Adding to existing files
-    /// - "code_files": Writing source code
-    /// - "config_files": Writing configuration
-    /// - "workflows": Complete write workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<FsWriteFileScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for fs_write_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::FsWriteFilePromptArgs;
+use super::prompt_args::{FsWriteFilePromptArgs, FsWriteFileScenario};
 
 /// Prompt provider for fs_write_file tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for WriteFilePrompts {
     type PromptArgs = FsWriteFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("append") => prompt_append(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(FsWriteFileScenario::Append) => prompt_append(),
+            Some(FsWriteFileScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, append)".to_string()),
-                required: Some(false),
-            }
+            FsWriteFileScenario::prompt_argument()
         ]
     }
 }
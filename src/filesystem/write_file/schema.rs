@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_FILESYSTEM, FS_WRITE_FILE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WriteFilePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // WRITE FILE ARGS
@@ -56,4 +57,5 @@ impl ToolArgs for FsWriteFileArgs {
     const NAME: &'static str = FS_WRITE_FILE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_FILESYSTEM;
     const DESCRIPTION: &'static str = "Write or append to file contents. Supports two modes: 'rewrite' (overwrite entire file) and 'append' (add to end of file)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_add` prompts
+    pub enum GitAddScenario {
+        /// Adding individual files (default)
+        SpecificFiles => "specific_files",
+        /// Adding by glob patterns
+        Patterns => "patterns",
+    }
+}
+
 /// Prompt arguments for git_add tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitAddPromptArgs {
-    /// Scenario to show examples for
-    /// - "specific_files": Adding individual files
-    /// - "patterns": Adding by glob patterns
-    /// - "all_changes": Staging everything
-    /// - "partial": Selective staging strategies
-    /// - "workflows": Complete staging workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitAddScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_add tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitAddPromptArgs;
+use super::prompt_args::{GitAddPromptArgs, GitAddScenario};
 
 /// Prompt provider for git_add tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for AddPrompts {
     type PromptArgs = GitAddPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("patterns") => prompt_patterns(),
-            _ => prompt_specific_files(),
+        match args.scenario {
+            Some(GitAddScenario::Patterns) => prompt_patterns(),
+            Some(GitAddScenario::SpecificFiles) | None => prompt_specific_files(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (specific_files, patterns)".to_string()),
-                required: Some(false),
-            }
+            GitAddScenario::prompt_argument()
         ]
     }
 }
@@ -49,12 +44,12 @@ fn prompt_specific_files() -> Vec<PromptMessage> {
                  1. Add single file:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/main.rs\"]\n\
+                        \"paths\": [\"src/main.rs\"]\n\
                     })\n\n\
                  2. Add multiple files:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\n\
+                        \"paths\": [\n\
                             \"src/lib.rs\",\n\
                             \"src/utils.rs\",\n\
                             \"Cargo.toml\"\n\
@@ -63,7 +58,7 @@ fn prompt_specific_files() -> Vec<PromptMessage> {
                  3. Add new directory:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/handlers/\"]\n\
+                        \"paths\": [\"src/handlers/\"]\n\
                     })\n\n\
                  RESPONSE:\n\
                  {\n\
@@ -108,22 +103,22 @@ fn prompt_patterns() -> Vec<PromptMessage> {
                  1. All Rust files:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"*.rs\"]\n\
+                        \"paths\": [\"*.rs\"]\n\
                     })\n\n\
                  2. All files in directory:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/handlers/*\"]\n\
+                        \"paths\": [\"src/handlers/*\"]\n\
                     })\n\n\
                  3. Multiple patterns:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"*.rs\", \"*.toml\"]\n\
+                        \"paths\": [\"*.rs\", \"*.toml\"]\n\
                     })\n\n\
                  4. Recursive pattern:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/**/*.rs\"]\n\
+                        \"paths\": [\"src/**/*.rs\"]\n\
                     })\n\n\
                  PATTERN SYNTAX:\n\
                  - *: Match any characters in filename\n\
@@ -144,17 +139,17 @@ fn prompt_patterns() -> Vec<PromptMessage> {
                  Stage all source code:\n\
                  git_add({\n\
                      \"path\": \"/project\",\n\
-                     \"files\": [\"src/**/*.rs\", \"tests/**/*.rs\"]\n\
+                     \"paths\": [\"src/**/*.rs\", \"tests/**/*.rs\"]\n\
                  })\n\n\
                  Stage all configuration:\n\
                  git_add({\n\
                      \"path\": \"/project\",\n\
-                     \"files\": [\"*.toml\", \"*.yaml\", \".config/*\"]\n\
+                     \"paths\": [\"*.toml\", \"*.yaml\", \".config/*\"]\n\
                  })\n\n\
                  Stage all documentation:\n\
                  git_add({\n\
                      \"path\": \"/project\",\n\
-                     \"files\": [\"*.md\", \"docs/**/*\"]\n\
+                     \"paths\": [\"*.md\", \"docs/**/*\"]\n\
                  })\n\n\
                  PATTERN SAFETY:\n\
                  - Always review with git_status before using broad patterns\n\
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_ADD};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::AddPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_ADD TOOL
@@ -54,4 +55,5 @@ impl ToolArgs for GitAddArgs {
     const NAME: &'static str = GIT_ADD;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Stage file changes for the next commit";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent();
 }
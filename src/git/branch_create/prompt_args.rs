@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_branch_create` prompts
+    pub enum GitBranchCreateScenario {
+        /// Creating feature branches (default)
+        Feature => "feature",
+        /// Branches from specific points
+        FromCommit => "from_commit",
+        /// Remote tracking branches
+        Tracking => "tracking",
+    }
+}
+
 /// Prompt arguments for git_branch_create tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitBranchCreatePromptArgs {
-    /// Scenario to show examples for
-    /// - "feature": Creating feature branches
-    /// - "from_commit": Branches from specific points
-    /// - "tracking": Remote tracking branches
-    /// - "naming": Branch naming conventions
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitBranchCreateScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_branch_create tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitBranchCreatePromptArgs;
+use super::prompt_args::{GitBranchCreatePromptArgs, GitBranchCreateScenario};
 
 /// Prompt provider for git_branch_create tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for BranchCreatePrompts {
     type PromptArgs = GitBranchCreatePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("from_commit") => prompt_from_commit(),
-            Some("tracking") => prompt_tracking(),
-            _ => prompt_feature(),
+        match args.scenario {
+            Some(GitBranchCreateScenario::FromCommit) => prompt_from_commit(),
+            Some(GitBranchCreateScenario::Tracking) => prompt_tracking(),
+            Some(GitBranchCreateScenario::Feature) | None => prompt_feature(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (feature, from_commit, tracking)".to_string()),
-                required: Some(false),
-            }
+            GitBranchCreateScenario::prompt_argument()
         ]
     }
 }
@@ -110,7 +105,7 @@ fn prompt_feature() -> Vec<PromptMessage> {
                  1. Create and checkout branch:\n\
                     git_branch_create({\"path\": \"/project\", \"name\": \"feature/new-feature\", \"checkout\": true})\n\
                  2. Make changes and commit:\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"src/file.rs\"]})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"src/file.rs\"]})\n\
                     git_commit({\"path\": \"/project\", \"message\": \"Add new feature\"})\n\
                  3. Push to remote:\n\
                     git_push({\"path\": \"/project\", \"set_upstream\": true})\n\n\
@@ -300,7 +295,7 @@ fn prompt_tracking() -> Vec<PromptMessage> {
                      \"checkout\": true\n\
                  })\n\n\
                  Step 2: Make initial commit\n\
-                 git_add({\"path\": \"/project\", \"files\": [\".\"]})\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\".\"]})\n\
                  git_commit({\n\
                      \"path\": \"/project\",\n\
                      \"message\": \"Initial payment system structure\"\n\
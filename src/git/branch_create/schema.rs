@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_BRANCH_CREATE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::BranchCreatePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_BRANCH_CREATE TOOL
@@ -56,4 +57,5 @@ impl ToolArgs for GitBranchCreateArgs {
     const NAME: &'static str = GIT_BRANCH_CREATE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create a new branch from a starting point";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_branch_delete` prompts
+    pub enum GitBranchDeleteScenario {
+        /// Deleting local branches
+        Local => "local",
+        /// Deleting remote branches
+        Remote => "remote",
+    }
+}
+
 /// Prompt arguments for git_branch_delete tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitBranchDeletePromptArgs {
-    /// Scenario to show examples for
-    /// - "local": Deleting local branches
-    /// - "remote": Deleting remote branches
-    /// - "cleanup": Branch cleanup strategies
-    /// - "safety": Safe deletion practices
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitBranchDeleteScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_branch_delete tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitBranchDeletePromptArgs;
+use super::prompt_args::{GitBranchDeletePromptArgs, GitBranchDeleteScenario};
 
 /// Prompt provider for git_branch_delete tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for BranchDeletePrompts {
     type PromptArgs = GitBranchDeletePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("local") => prompt_local(),
-            Some("remote") => prompt_remote(),
+        match args.scenario {
+            Some(GitBranchDeleteScenario::Local) => prompt_local(),
+            Some(GitBranchDeleteScenario::Remote) => prompt_remote(),
             _ => prompt_local(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (local, remote)".to_string()),
-                required: Some(false),
-            }
+            GitBranchDeleteScenario::prompt_argument()
         ]
     }
 }
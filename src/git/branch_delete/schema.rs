@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_BRANCH_DELETE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::BranchDeletePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_BRANCH_DELETE TOOL
@@ -46,4 +47,5 @@ impl ToolArgs for GitBranchDeleteArgs {
     const NAME: &'static str = GIT_BRANCH_DELETE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Delete a branch from the repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
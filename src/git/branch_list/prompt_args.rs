@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_branch_list` prompts
+    pub enum GitBranchListScenario {
+        /// Simple branch listing
+        Basic => "basic",
+        /// Remote branches
+        Remote => "remote",
+    }
+}
+
 /// Prompt arguments for git_branch_list tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitBranchListPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple branch listing
-    /// - "remote": Remote branches
-    /// - "filtering": Filtered branch lists
-    /// - "analysis": Branch analysis workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitBranchListScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_branch_list tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitBranchListPromptArgs;
+use super::prompt_args::{GitBranchListPromptArgs, GitBranchListScenario};
 
 /// Prompt provider for git_branch_list tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for BranchListPrompts {
     type PromptArgs = GitBranchListPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("remote") => prompt_remote(),
+        match args.scenario {
+            Some(GitBranchListScenario::Basic) => prompt_basic(),
+            Some(GitBranchListScenario::Remote) => prompt_remote(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, remote)".to_string()),
-                required: Some(false),
-            }
+            GitBranchListScenario::prompt_argument()
         ]
     }
 }
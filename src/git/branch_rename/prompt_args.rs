@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_branch_rename` prompts
+    pub enum GitBranchRenameScenario {
+        /// Renaming a branch (default)
+        Basic => "basic",
+        /// Renaming the current branch
+        Current => "current",
+    }
+}
+
 /// Prompt arguments for `git_branch_rename` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitBranchRenamePromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Renaming a branch
-    /// - "current": Renaming the current branch
-    ///
-    /// Default if omitted: basic scenario
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitBranchRenameScenario>,
 }
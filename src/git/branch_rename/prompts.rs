@@ -1,8 +1,8 @@
 //! Prompt messages for git_branch_rename tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitBranchRenamePromptArgs;
+use super::prompt_args::{GitBranchRenamePromptArgs, GitBranchRenameScenario};
 
 /// Prompt provider for git_branch_rename tool
 pub struct BranchRenamePrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for BranchRenamePrompts {
     type PromptArgs = GitBranchRenamePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("current") => prompt_current(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitBranchRenameScenario::Current) => prompt_current(),
+            Some(GitBranchRenameScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, current".to_string()),
-                required: Some(false),
-            }
+            GitBranchRenameScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_BRANCH_RENAME};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::BranchRenamePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_BRANCH_RENAME TOOL
@@ -50,4 +51,5 @@ impl ToolArgs for GitBranchRenameArgs {
     const NAME: &'static str = GIT_BRANCH_RENAME;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Rename an existing branch";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
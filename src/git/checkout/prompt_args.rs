@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_checkout` prompts
+    pub enum GitCheckoutScenario {
+        /// Switching between branches
+        SwitchBranch => "switch_branch",
+        /// Detached HEAD operations
+        Detached => "detached",
+    }
+}
+
 /// Prompt arguments for git_checkout tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitCheckoutPromptArgs {
-    /// Scenario to show examples for
-    /// - "switch_branch": Switching between branches
-    /// - "create_branch": Create and checkout new branch
-    /// - "restore_files": Restore specific files
-    /// - "detached": Detached HEAD operations
-    /// - "workflows": Complete checkout workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitCheckoutScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_checkout tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitCheckoutPromptArgs;
+use super::prompt_args::{GitCheckoutPromptArgs, GitCheckoutScenario};
 
 /// Prompt provider for git_checkout tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for GitCheckoutPrompts {
     type PromptArgs = GitCheckoutPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("switch_branch") => prompt_switch_branch(),
-            Some("detached") => prompt_detached(),
+        match args.scenario {
+            Some(GitCheckoutScenario::SwitchBranch) => prompt_switch_branch(),
+            Some(GitCheckoutScenario::Detached) => prompt_detached(),
             _ => prompt_switch_branch(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (switch_branch, detached)".to_string()),
-                required: Some(false),
-            }
+            GitCheckoutScenario::prompt_argument()
         ]
     }
 }
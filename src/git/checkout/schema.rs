@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_CHECKOUT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GitCheckoutPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_CHECKOUT TOOL
@@ -57,4 +58,5 @@ impl ToolArgs for GitCheckoutArgs {
     const NAME: &'static str = GIT_CHECKOUT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Switch branches or restore files from a specific revision";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
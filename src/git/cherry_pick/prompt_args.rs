@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_cherry_pick` prompts
+    pub enum GitCherryPickScenario {
+        /// Cherry-pick one commit
+        Single => "single",
+        /// Cherry-pick several commits
+        Multiple => "multiple",
+        /// Handling conflicts
+        Conflicts => "conflicts",
+        /// Advanced cherry-pick options
+        Options => "options",
+    }
+}
+
 /// Prompt arguments for git_cherry_pick tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitCherryPickPromptArgs {
-    /// Scenario to show examples for
-    /// - "single": Cherry-pick one commit
-    /// - "multiple": Cherry-pick several commits
-    /// - "conflicts": Handling conflicts
-    /// - "options": Advanced cherry-pick options
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitCherryPickScenario>,
 }
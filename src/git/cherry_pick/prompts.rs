@@ -1,8 +1,8 @@
 //! Prompt messages for git_cherry_pick tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitCherryPickPromptArgs;
+use super::prompt_args::{GitCherryPickPromptArgs, GitCherryPickScenario};
 
 /// Prompt provider for git_cherry_pick tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for CherryPickPrompts {
     type PromptArgs = GitCherryPickPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("single") => prompt_single(),
-            Some("multiple") => prompt_multiple(),
-            Some("conflicts") => prompt_conflicts(),
-            Some("options") => prompt_options(),
+        match args.scenario {
+            Some(GitCherryPickScenario::Single) => prompt_single(),
+            Some(GitCherryPickScenario::Multiple) => prompt_multiple(),
+            Some(GitCherryPickScenario::Conflicts) => prompt_conflicts(),
+            Some(GitCherryPickScenario::Options) => prompt_options(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (single, multiple, conflicts, options)".to_string()),
-                required: Some(false),
-            }
+            GitCherryPickScenario::prompt_argument()
         ]
     }
 }
@@ -328,7 +323,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  4. Stage resolved files:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/main.rs\", \"src/lib.rs\"]\n\
+                        \"paths\": [\"src/main.rs\", \"src/lib.rs\"]\n\
                     })\n\n\
                  5. Continue cherry-pick:\n\
                     git_cherry_pick({\n\
@@ -371,7 +366,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  Step 4: Edit to resolve\n\
                  fs_edit_block({...remove markers, keep correct code...})\n\n\
                  Step 5: Stage resolution\n\
-                 git_add({\"files\": [\"file.rs\"]})\n\n\
+                 git_add({\"paths\": [\"file.rs\"]})\n\n\
                  Step 6: Complete cherry-pick\n\
                  git_cherry_pick({\"continue\": true})\n\n\
                  CONFLICT MARKERS:\n\n\
@@ -410,13 +405,13 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  → both modified: src/c.rs\n\n\
                  // Resolve a.rs\n\
                  fs_edit_block({\"path\": \"src/a.rs\", ...})\n\
-                 git_add({\"files\": [\"src/a.rs\"]})\n\n\
+                 git_add({\"paths\": [\"src/a.rs\"]})\n\n\
                  // Resolve b.rs\n\
                  fs_edit_block({\"path\": \"src/b.rs\", ...})\n\
-                 git_add({\"files\": [\"src/b.rs\"]})\n\n\
+                 git_add({\"paths\": [\"src/b.rs\"]})\n\n\
                  // Resolve c.rs\n\
                  fs_edit_block({\"path\": \"src/c.rs\", ...})\n\
-                 git_add({\"files\": [\"src/c.rs\"]})\n\n\
+                 git_add({\"paths\": [\"src/c.rs\"]})\n\n\
                  // All resolved, continue\n\
                  git_cherry_pick({\"continue\": true})\n\n\
                  CHECKING RESOLUTION:\n\n\
@@ -517,7 +512,7 @@ fn prompt_options() -> Vec<PromptMessage> {
                  git_cherry_pick({\"commit\": \"abc1234\", \"no_commit\": true})\n\
                  // Make additional changes\n\
                  fs_edit_block({...modify files...})\n\
-                 git_add({\"files\": [...]})\n\
+                 git_add({\"paths\": [...]})\n\
                  git_commit({\"message\": \"Cherry-picked with modifications\"})\n\n\
                  EDIT MESSAGE OPTION:\n\n\
                  Add context to cherry-picked commit:\n\
@@ -578,7 +573,7 @@ fn prompt_options() -> Vec<PromptMessage> {
                  git_cherry_pick({\"commit\": \"abc1234\", \"no_commit\": true})\n\
                  // Modify for compatibility\n\
                  fs_edit_block({...update API calls...})\n\
-                 git_add({\"files\": [\"src/api.rs\"]})\n\
+                 git_add({\"paths\": [\"src/api.rs\"]})\n\
                  git_commit({\"message\": \"Backport feature (adapted for v1.0 API)\"})\n\n\
                  Cherry-pick merge commit:\n\
                  git_cherry_pick({\n\
@@ -745,7 +740,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                  3. Resolve conflicts:\n\
                     fs_edit_block({...remove markers, fix code...})\n\n\
                  4. Stage resolved files:\n\
-                    git_add({\"files\": [\"file.rs\"]})\n\n\
+                    git_add({\"paths\": [\"file.rs\"]})\n\n\
                  5. Continue cherry-pick:\n\
                     git_cherry_pick({\"continue\": true})\n\n\
                  Or abort if needed:\n\
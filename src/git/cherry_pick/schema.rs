@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_CHERRY_PICK};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CherryPickPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_CHERRY_PICK TOOL
@@ -127,4 +128,5 @@ impl ToolArgs for GitCherryPickArgs {
     const NAME: &'static str = GIT_CHERRY_PICK;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Apply changes from specific commits to current branch";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_clone` prompts
+    pub enum GitCloneScenario {
+        /// Simple repository cloning (default)
+        Basic => "basic",
+        /// Shallow clones for speed
+        Shallow => "shallow",
+        /// Cloning specific branches
+        Branch => "branch",
+    }
+}
+
 /// Prompt arguments for git_clone tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitClonePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple repository cloning
-    /// - "shallow": Shallow clones for speed
-    /// - "branch": Cloning specific branches
-    /// - "options": Advanced clone options
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitCloneScenario>,
 }
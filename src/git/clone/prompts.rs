@@ -1,8 +1,8 @@
 //! Prompt messages for git_clone tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitClonePromptArgs;
+use super::prompt_args::{GitClonePromptArgs, GitCloneScenario};
 
 /// Prompt provider for git_clone tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ClonePrompts {
     type PromptArgs = GitClonePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("shallow") => prompt_shallow(),
-            Some("branch") => prompt_branch(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitCloneScenario::Shallow) => prompt_shallow(),
+            Some(GitCloneScenario::Branch) => prompt_branch(),
+            Some(GitCloneScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show: basic (default), shallow, branch".to_string()),
-                required: Some(false),
-            }
+            GitCloneScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_CLONE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ClonePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_CLONE TOOL
@@ -55,4 +56,5 @@ impl ToolArgs for GitCloneArgs {
     const NAME: &'static str = GIT_CLONE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Clone a remote Git repository to a local path";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_commit` prompts
+    pub enum GitCommitScenario {
+        /// Simple commits with messages (default)
+        Basic => "basic",
+        /// Modifying previous commits
+        Amend => "amend",
+    }
+}
+
 /// Prompt arguments for git_commit tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitCommitPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple commits with messages
-    /// - "messages": Writing good commit messages
-    /// - "amend": Modifying previous commits
-    /// - "workflows": Complete commit workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitCommitScenario>,
 }
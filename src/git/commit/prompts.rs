@@ -1,8 +1,8 @@
 //! Prompt messages for git_commit tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitCommitPromptArgs;
+use super::prompt_args::{GitCommitPromptArgs, GitCommitScenario};
 
 /// Prompt provider for git_commit tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for CommitPrompts {
     type PromptArgs = GitCommitPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("amend") => prompt_amend(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitCommitScenario::Amend) => prompt_amend(),
+            Some(GitCommitScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, amend)".to_string()),
-                required: Some(false),
-            }
+            GitCommitScenario::prompt_argument()
         ]
     }
 }
@@ -109,7 +104,7 @@ fn prompt_amend() -> Vec<PromptMessage> {
                  1. Fix commit message only:\n\
                     git_commit({\"path\": \"/project\", \"amend\": true, \"message\": \"Corrected message\"})\n\n\
                  2. Add forgotten file (keep same message):\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"forgotten.rs\"]})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"forgotten.rs\"]})\n\
                     git_commit({\"path\": \"/project\", \"amend\": true, \"no_edit\": true})\n\n\
                  3. Fix last commit entirely (new files + new message):\n\
                     git_add({\"path\": \"/project\", \"all\": true})\n\
@@ -130,7 +125,7 @@ fn prompt_amend() -> Vec<PromptMessage> {
                  - Requires force push if already pushed\n\n\
                  RECOMMENDED WORKFLOW:\n\
                  1. Verify last commit: git_log({\"path\": \"/project\", \"max_count\": 1})\n\
-                 2. Stage new files (if needed): git_add({\"files\": [\"file.rs\"]})\n\
+                 2. Stage new files (if needed): git_add({\"paths\": [\"file.rs\"]})\n\
                  3. Amend: git_commit({\"amend\": true, \"message\": \"...\"})\n\
                  4. Force push only if necessary: git_push({\"force\": true})\n\n\
                  ALTERNATIVES:\n\
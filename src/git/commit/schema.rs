@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_COMMIT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CommitPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_COMMIT TOOL
@@ -55,4 +56,5 @@ impl ToolArgs for GitCommitArgs {
     const NAME: &'static str = GIT_COMMIT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create a commit with staged changes";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,16 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_config_get` prompts
+    pub enum GitConfigGetScenario {
+        /// User identity settings
+        User => "user",
+        /// Repository-specific settings
+        Repo => "repo",
+        /// Global vs local config
+        Scopes => "scopes",
+        /// Listing all config
+        List => "list",
+    }
+}
+
 /// Prompt arguments for git_config_get tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitConfigGetPromptArgs {
-    /// Scenario to show examples for
-    /// - "user": User identity settings
-    /// - "repo": Repository-specific settings
-    /// - "scopes": Global vs local config
-    /// - "list": Listing all config
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitConfigGetScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_config_get tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitConfigGetPromptArgs;
+use super::prompt_args::{GitConfigGetPromptArgs, GitConfigGetScenario};
 
 /// Prompt provider for git_config_get tool
 pub struct ConfigGetPrompts;
@@ -11,23 +11,18 @@ impl PromptProvider for ConfigGetPrompts {
     type PromptArgs = GitConfigGetPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("user") => prompt_user(),
-            Some("repo") => prompt_repo(),
-            Some("scopes") => prompt_scopes(),
-            Some("list") => prompt_list(),
+        match args.scenario {
+            Some(GitConfigGetScenario::User) => prompt_user(),
+            Some(GitConfigGetScenario::Repo) => prompt_repo(),
+            Some(GitConfigGetScenario::Scopes) => prompt_scopes(),
+            Some(GitConfigGetScenario::List) => prompt_list(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: user, repo, scopes, list".to_string()),
-                required: Some(false),
-            }
+            GitConfigGetScenario::prompt_argument()
         ]
     }
 }
@@ -3,7 +3,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_CONFIG_GET};
-use crate::{ToolArgs, tool_metadata};
+use crate::{ToolArgs, ValidateArgs, tool_metadata};
 use super::prompts::ConfigGetPrompts;
 
 // ============================================================================
@@ -11,13 +11,14 @@ use super::prompts::ConfigGetPrompts;
 // ============================================================================
 
 /// Arguments for `git_config_get` tool
-#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, ValidateArgs)]
 pub struct GitConfigGetArgs {
     /// Path to repository (or any path within the repository)
     pub path: String,
 
     /// Config key to read (e.g., "user.name", "remote.origin.url")
     /// Either provide 'key' for single value OR 'list: true' for all values
+    #[exclusive_with(list)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
 
@@ -3,16 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_config_set` prompts
+    pub enum GitConfigSetScenario {
+        /// Setting user identity
+        Identity => "identity",
+        /// Configuring git behavior
+        Behavior => "behavior",
+        /// Creating command aliases
+        Aliases => "aliases",
+        /// Per-repository settings
+        RepoSpecific => "repo_specific",
+    }
+}
+
 /// Prompt arguments for `git_config_set` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitConfigSetPromptArgs {
-    /// Optional: Scenario to show examples for
-    /// - "identity": Setting user identity
-    /// - "behavior": Configuring git behavior
-    /// - "aliases": Creating command aliases
-    /// - "repo_specific": Per-repository settings
-    ///
-    /// Default if omitted: comprehensive overview covering all scenarios
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitConfigSetScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_config_set tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitConfigSetPromptArgs;
+use super::prompt_args::{GitConfigSetPromptArgs, GitConfigSetScenario};
 
 /// Prompt provider for git_config_set tool
 pub struct ConfigSetPrompts;
@@ -11,23 +11,18 @@ impl PromptProvider for ConfigSetPrompts {
     type PromptArgs = GitConfigSetPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("identity") => prompt_identity(),
-            Some("behavior") => prompt_behavior(),
-            Some("aliases") => prompt_aliases(),
-            Some("repo_specific") => prompt_repo_specific(),
+        match args.scenario {
+            Some(GitConfigSetScenario::Identity) => prompt_identity(),
+            Some(GitConfigSetScenario::Behavior) => prompt_behavior(),
+            Some(GitConfigSetScenario::Aliases) => prompt_aliases(),
+            Some(GitConfigSetScenario::RepoSpecific) => prompt_repo_specific(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: identity, behavior, aliases, repo_specific".to_string()),
-                required: Some(false),
-            }
+            GitConfigSetScenario::prompt_argument()
         ]
     }
 }
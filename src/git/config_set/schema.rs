@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_CONFIG_SET};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ConfigSetPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_CONFIG_SET TOOL
@@ -66,4 +67,5 @@ impl ToolArgs for GitConfigSetArgs {
     const NAME: &'static str = GIT_CONFIG_SET;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Set Git configuration values";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_diff` prompts
+    pub enum GitDiffScenario {
+        /// Working directory changes (default)
+        Working => "working",
+        /// Comparing commits
+        Commits => "commits",
+    }
+}
+
 /// Prompt arguments for git_diff tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitDiffPromptArgs {
-    /// Scenario to show examples for
-    /// - "working": Working directory changes
-    /// - "staged": Staged changes review
-    /// - "commits": Comparing commits
-    /// - "branches": Comparing branches
-    /// - "files": Specific file diffs
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitDiffScenario>,
 }
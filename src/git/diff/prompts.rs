@@ -1,8 +1,8 @@
 //! Prompt messages for git_diff tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitDiffPromptArgs;
+use super::prompt_args::{GitDiffPromptArgs, GitDiffScenario};
 
 /// Prompt provider for git_diff tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for DiffPrompts {
     type PromptArgs = GitDiffPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("commits") => prompt_commits(),
-            _ => prompt_working(),
+        match args.scenario {
+            Some(GitDiffScenario::Commits) => prompt_commits(),
+            Some(GitDiffScenario::Working) | None => prompt_working(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (working, commits). Default: working directory changes".to_string()),
-                required: Some(false),
-            }
+            GitDiffScenario::prompt_argument()
         ]
     }
 }
@@ -50,40 +45,29 @@ fn prompt_working() -> Vec<PromptMessage> {
             content: PromptMessageContent::text(
                 "Use git_diff to view working directory changes - what you've modified but haven't committed yet.\n\n\
                  BASIC EXAMPLES:\n\
-                 1. All changes:\n\
-                    git_diff({\"path\": \"/project\"})\n\n\
-                 2. Specific file:\n\
-                    git_diff({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\n\
-                 3. Directory:\n\
-                    git_diff({\"path\": \"/project\", \"files\": [\"src/\"]})\n\n\
-                 4. Staged changes only:\n\
-                    git_diff({\"path\": \"/project\", \"staged\": true})\n\n\
-                 5. Statistics only:\n\
-                    git_diff({\"path\": \"/project\", \"stat_only\": true})\n\n\
+                 1. All changes since the last commit:\n\
+                    git_diff({\"path\": \"/project\", \"from\": \"HEAD\"})\n\n\
+                 2. All changes since an older commit:\n\
+                    git_diff({\"path\": \"/project\", \"from\": \"HEAD~3\"})\n\n\
+                 Leaving out `to` compares against the working directory.\n\n\
                  RESPONSE STRUCTURE:\n\
                  {\n\
+                   \"from\": \"HEAD\",\n\
                    \"files_changed\": 3,\n\
                    \"insertions\": 25,\n\
                    \"deletions\": 10,\n\
-                   \"diff\": \"...\"\n\
+                   \"files\": [\n\
+                     {\"path\": \"src/main.rs\", \"change_type\": \"modified\", \"additions\": 20, \"deletions\": 8}\n\
+                   ]\n\
                  }\n\
                  - files_changed: Number of modified files\n\
                  - insertions: Total lines added\n\
                  - deletions: Total lines removed\n\
-                 - diff: Line-by-line changes in unified diff format\n\n\
-                 READING DIFF OUTPUT:\n\
-                 Line prefixes:\n\
-                 - '+' = ADDITIONS (new lines)\n\
-                 - '-' = DELETIONS (removed lines)\n\
-                 - ' ' = CONTEXT (unchanged reference lines)\n\
-                 - '@@' = Line number ranges\n\n\
-                 Example:\n\
-                 @@ -42,6 +42,7 @@\n\
-                  fn main() {\n\
-                 -    println!(\"Hello\");\n\
-                 +    println!(\"Hello, world!\");\n\
-                 +    println!(\"Welcome\");\n\
-                  }\n\n\
+                 - files: Per-file change type and line counts\n\n\
+                 FOCUSING ON SPECIFIC FILES:\n\
+                 git_diff has no file filter; it always reports every changed file.\n\
+                 Pick the entries you care about from `files`, then read those\n\
+                 files for the actual content.\n\n\
                  WHEN TO CHECK:\n\
                  - Before staging (git_add) - Review what you're about to stage\n\
                  - Before committing - Verify all changes are present\n\
@@ -91,27 +75,16 @@ fn prompt_working() -> Vec<PromptMessage> {
                  - After modifying files - Confirm changes are expected\n\n\
                  WORKFLOW EXAMPLE:\n\
                  1. Make changes to files\n\
-                 2. git_diff({\"path\": \"/project\"}) - Review all changes\n\
-                 3. git_add({\"path\": \"/project\", \"files\": [...]}) - Stage changes\n\
-                 4. git_diff({\"path\": \"/project\", \"staged\": true}) - Verify staged\n\
-                 5. git_commit({\"path\": \"/project\", \"message\": \"...\"}) - Commit\n\n\
-                 FILE FILTERING:\n\
-                 Single file:\n\
-                 git_diff({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\n\
-                 Multiple files:\n\
-                 git_diff({\"path\": \"/project\", \"files\": [\"src/a.rs\", \"src/b.rs\"]})\n\n\
-                 Directory:\n\
-                 git_diff({\"path\": \"/project\", \"files\": [\"src/\"]})\n\n\
+                 2. git_diff({\"path\": \"/project\", \"from\": \"HEAD\"}) - Review all changes\n\
+                 3. Stage the files you want with git_add\n\
+                 4. git_commit({\"path\": \"/project\", \"message\": \"...\"}) - Commit\n\n\
                  PARAMETERS:\n\
                  - path: Repository path (required)\n\
-                 - files: Array of file paths (optional)\n\
-                 - staged: true/false (optional) - Show staged vs unstaged\n\
-                 - stat_only: Show statistics only (faster)\n\
-                 - name_only: Show file names only (fastest)\n\n\
+                 - from: Revision to compare from (required; \"HEAD\" for uncommitted changes)\n\
+                 - to: Revision to compare to (optional, defaults to the working directory)\n\n\
                  BEST PRACTICES:\n\
                  - Review diffs before staging to catch unintended changes\n\
-                 - Look for debugging code (console.log, println!) before committing\n\
-                 - Verify no sensitive data (passwords, keys) in changes\n\
+                 - Look for unexpected files (build output, secrets) in `files`\n\
                  - Check that tests were updated if code changed\n\
                  - Ensure documentation matches code changes",
             ),
@@ -163,8 +136,6 @@ fn prompt_commits() -> Vec<PromptMessage> {
                  git_diff({\"path\": \"/project\", \"from\": \"HEAD^\", \"to\": \"HEAD\"})\n\n\
                  Branch comparison:\n\
                  git_diff({\"path\": \"/project\", \"from\": \"main\", \"to\": \"feature/x\"})\n\n\
-                 Specific file across commits:\n\
-                 git_diff({\"path\": \"/project\", \"from\": \"v1.0.0\", \"to\": \"HEAD\", \"files\": [\"src/api.rs\"]})\n\n\
                  USE CASES:\n\
                  Release comparison:\n\
                  git_diff({\"path\": \"/project\", \"from\": \"v1.0.0\", \"to\": \"v2.0.0\"})\n\
@@ -181,8 +152,7 @@ fn prompt_commits() -> Vec<PromptMessage> {
                     git_log({\"path\": \"/project\"})\n\n\
                  2. Compare good to current:\n\
                     git_diff({\"path\": \"/project\", \"from\": \"last_good_commit\"})\n\n\
-                 3. Narrow to specific file:\n\
-                    git_diff({\"path\": \"/project\", \"from\": \"last_good_commit\", \"files\": [\"src/buggy.rs\"]})\n\n\
+                 3. Look for the suspect file in `files`\n\n\
                  4. Binary search commits:\n\
                     git_diff({\"path\": \"/project\", \"from\": \"commit1\", \"to\": \"commit2\"})\n\n\
                  5. Identify problematic change\n\n\
@@ -193,12 +163,12 @@ fn prompt_commits() -> Vec<PromptMessage> {
                    \"files_changed\": 15,\n\
                    \"insertions\": 234,\n\
                    \"deletions\": 89,\n\
-                   \"diff\": \"...\"\n\
+                   \"files\": [{\"path\": \"src/api.rs\", \"change_type\": \"modified\", \"additions\": 40, \"deletions\": 12}, ...]\n\
                  }\n\
                  - from/to: Commits compared\n\
                  - files_changed: Number of modified files\n\
                  - insertions/deletions: Code growth or reduction\n\
-                 - diff: Line-by-line changes\n\n\
+                 - files: Per-file change type and line counts\n\n\
                  FINDING COMMIT HASHES:\n\
                  Use git_log to see history:\n\
                  git_log({\"path\": \"/project\", \"max_count\": 10})\n\
@@ -206,15 +176,12 @@ fn prompt_commits() -> Vec<PromptMessage> {
                  PARAMETERS:\n\
                  - path: Repository path (required)\n\
                  - from: Starting revision (required for comparison)\n\
-                 - to: Ending revision (optional, defaults to working directory)\n\
-                 - files: Optional file filtering\n\
-                 - stat_only: Show statistics only\n\
-                 - name_only: Show file names only\n\n\
+                 - to: Ending revision (optional, defaults to working directory)\n\n\
                  BEST PRACTICES:\n\
                  - Use abbreviated hashes (7 chars) for readability\n\
                  - Compare tags for release documentation\n\
                  - Use HEAD~N for recent history\n\
-                 - Focus on specific files for large diffs\n\
+                 - For large diffs, pick the relevant entries from `files`\n\
                  - Combine with git_log for context",
             ),
         },
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_fetch` prompts
+    pub enum GitFetchScenario {
+        /// Simple fetching
+        Basic => "basic",
+        /// Cleaning stale references
+        Prune => "prune",
+    }
+}
+
 /// Prompt arguments for git_fetch tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitFetchPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple fetching
-    /// - "remotes": Fetching from specific remotes
-    /// - "prune": Cleaning stale references
-    /// - "workflows": Fetch workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitFetchScenario>,
 }
@@ -1,7 +1,7 @@
 //! Prompt messages for git_fetch tool
 
-use super::prompt_args::GitFetchPromptArgs;
-use crate::tool::PromptProvider;
+use super::prompt_args::{GitFetchPromptArgs, GitFetchScenario};
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
 
 /// Prompt provider for git_fetch tool
@@ -14,20 +14,15 @@ impl PromptProvider for FetchPrompts {
     type PromptArgs = GitFetchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("prune") => prompt_prune(),
+        match args.scenario {
+            Some(GitFetchScenario::Basic) => prompt_basic(),
+            Some(GitFetchScenario::Prune) => prompt_prune(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![PromptArgument {
-            name: "scenario".to_string(),
-            title: None,
-            description: Some("Scenario to show (basic, prune)".to_string()),
-            required: Some(false),
-        }]
+        vec![GitFetchScenario::prompt_argument()]
     }
 }
 
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_FETCH};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::FetchPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -63,4 +64,5 @@ impl ToolArgs for GitFetchArgs {
     const NAME: &'static str = GIT_FETCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Download changes from a remote repository without merging";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent().open_world();
 }
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_history` prompts
+    pub enum GitHistoryScenario {
+        /// Viewing commit history
+        Basic => "basic",
+        /// Filtering history by author, date, etc
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for `git_history` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitHistoryPromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Viewing commit history
-    /// - "filtering": Filtering history by author, date, etc.
-    /// - "searching": Searching commit messages
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitHistoryScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_history tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitHistoryPromptArgs;
+use super::prompt_args::{GitHistoryPromptArgs, GitHistoryScenario};
 
 /// Prompt provider for git_history tool
 pub struct HistoryPrompts;
@@ -11,21 +11,16 @@ impl PromptProvider for HistoryPrompts {
     type PromptArgs = GitHistoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
+        match args.scenario {
+            Some(GitHistoryScenario::Basic) => prompt_basic(),
+            Some(GitHistoryScenario::Filtering) => prompt_filtering(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, filtering".to_string()),
-                required: Some(false),
-            }
+            GitHistoryScenario::prompt_argument()
         ]
     }
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_init` prompts
+    pub enum GitInitScenario {
+        /// Simple repository creation
+        Basic => "basic",
+        /// Bare repository creation
+        Bare => "bare",
+    }
+}
+
 /// Prompt arguments for git_init tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitInitPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple repository creation
-    /// - "options": Init with options
-    /// - "bare": Bare repository creation
-    /// - "workflows": Complete init workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitInitScenario>,
 }
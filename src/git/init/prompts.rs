@@ -1,8 +1,8 @@
 //! Prompt messages for git_init tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitInitPromptArgs;
+use super::prompt_args::{GitInitPromptArgs, GitInitScenario};
 
 /// Prompt provider for git_init tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for InitPrompts {
     type PromptArgs = GitInitPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("bare") => prompt_bare(),
+        match args.scenario {
+            Some(GitInitScenario::Basic) => prompt_basic(),
+            Some(GitInitScenario::Bare) => prompt_bare(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, bare)".to_string()),
-                required: Some(false),
-            }
+            GitInitScenario::prompt_argument()
         ]
     }
 }
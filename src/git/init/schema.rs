@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_INIT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::InitPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_INIT TOOL
@@ -48,4 +49,5 @@ impl ToolArgs for GitInitArgs {
     const NAME: &'static str = GIT_INIT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Initialize a new Git repository at the specified path";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_log` prompts
+    pub enum GitLogScenario {
+        /// Filter by file and directory
+        Filtering => "filtering",
+        /// Code archaeology and file history tracking
+        CodeArchaeology => "code_archaeology",
+    }
+}
+
 /// Prompt arguments for git_log tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitLogPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple log viewing
-    /// - "filtering": Filter by file and directory
-    /// - "pagination": Paginate through history
-    /// - "file_history": Track file changes over time
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitLogScenario>,
 }
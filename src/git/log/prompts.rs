@@ -1,8 +1,8 @@
 //! Prompt messages for git_log tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitLogPromptArgs;
+use super::prompt_args::{GitLogPromptArgs, GitLogScenario};
 
 /// Prompt provider for git_log tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for LogPrompts {
     type PromptArgs = GitLogPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("filtering") => prompt_filtering(),
-            Some("code_archaeology") => prompt_code_archaeology(),
+        match args.scenario {
+            Some(GitLogScenario::Filtering) => prompt_filtering(),
+            Some(GitLogScenario::CodeArchaeology) => prompt_code_archaeology(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (filtering, code_archaeology)".to_string()),
-                required: Some(false),
-            }
+            GitLogScenario::prompt_argument()
         ]
     }
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_merge` prompts
+    pub enum GitMergeScenario {
+        /// Simple branch merging (default)
+        Basic => "basic",
+        /// Merge strategies and options
+        Strategies => "strategies",
+        /// Handling merge conflicts
+        Conflicts => "conflicts",
+    }
+}
+
 /// Prompt arguments for git_merge tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitMergePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple branch merging
-    /// - "strategies": Merge strategies and options
-    /// - "conflicts": Handling merge conflicts
-    /// - "workflows": Complete merge workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitMergeScenario>,
 }
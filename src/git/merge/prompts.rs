@@ -1,8 +1,8 @@
 //! Prompt messages for git_merge tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitMergePromptArgs;
+use super::prompt_args::{GitMergePromptArgs, GitMergeScenario};
 
 /// Prompt provider for git_merge tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for MergePrompts {
     type PromptArgs = GitMergePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("strategies") => prompt_strategies(),
-            Some("conflicts") => prompt_conflicts(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitMergeScenario::Strategies) => prompt_strategies(),
+            Some(GitMergeScenario::Conflicts) => prompt_conflicts(),
+            Some(GitMergeScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (strategies, conflicts, basic)".to_string()),
-                required: Some(false),
-            }
+            GitMergeScenario::prompt_argument()
         ]
     }
 }
@@ -236,7 +231,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                      \"new_string\": \"fn merged_code() {}\"\n\
                  })\n\n\
                  4. Mark resolved:\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"src/main.rs\"]})\n\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"src/main.rs\"]})\n\n\
                  5. Continue or abort:\n\
                  git_merge({\"path\": \"/repo\", \"continue\": true})\n\
                  // OR\n\
@@ -270,7 +265,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  Step 3: For EACH file - read, resolve, stage\n\
                  fs_read_file({\"path\": \"/repo/src/api.rs\"})\n\
                  fs_edit_block({\"path\": \"/repo/src/api.rs\", \"old_string\": \"...\", \"new_string\": \"...\"})\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"src/api.rs\"]})\n\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"src/api.rs\"]})\n\n\
                  Step 4: After ALL files resolved\n\
                  git_merge({\"path\": \"/repo\", \"continue\": true})\n\n\
                  Step 5: Verify final state\n\
@@ -278,9 +273,9 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  MULTIPLE CONFLICTS:\n\n\
                  Resolve each file independently:\n\
                  fs_edit_block({\"path\": \"/repo/file1.rs\", ...})\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"file1.rs\"]})\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"file1.rs\"]})\n\
                  fs_edit_block({\"path\": \"/repo/file2.rs\", ...})\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"file2.rs\"]})\n\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"file2.rs\"]})\n\n\
                  Stage each resolved file, only continue after ALL files resolved.\n\n\
                  PREVENTING CONFLICTS:\n\
                  - Merge main into feature frequently\n\
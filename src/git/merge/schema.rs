@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_MERGE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::MergePrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -63,4 +64,5 @@ impl ToolArgs for GitMergeArgs {
     const NAME: &'static str = GIT_MERGE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Merge changes from one branch into another";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
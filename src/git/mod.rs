@@ -489,6 +489,8 @@ pub use stash_list::{
 
 // Re-export stash_pop tool
 pub use stash_pop::{
+    GitStashPopArgs,
+    GitStashPopOutput,
     GitStashPopPromptArgs,
     GitStashPopPrompts,
 };
@@ -511,12 +513,24 @@ pub use tag_create::{
 
 // Re-export tag_list tool
 pub use tag_list::{
+    GitTagListArgs,
+    GitTagListOutput,
+    TagSort,
     GitTagListPromptArgs,
     GitTagListPrompts,
 };
 
 // Re-export show tool
 pub use show::{
+    GitShowArgs,
+    GitShowOutput,
+    GitShowResult,
+    GitShowCommit,
+    GitShowTag,
+    GitShowFile,
+    GitShowStats,
+    GitShowFileStat,
+    GitShowChange,
     GitShowPromptArgs,
     ShowPrompts,
 };
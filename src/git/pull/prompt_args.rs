@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_pull` prompts
+    pub enum GitPullScenario {
+        /// Simple pulling (default)
+        Basic => "basic",
+        /// Pull with rebase
+        Rebase => "rebase",
+        /// Handling pull conflicts
+        Conflicts => "conflicts",
+    }
+}
+
 /// Prompt arguments for git_pull tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitPullPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple pulling
-    /// - "rebase": Pull with rebase
-    /// - "remotes": Pulling from different remotes
-    /// - "conflicts": Handling pull conflicts
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitPullScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_pull tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitPullPromptArgs;
+use super::prompt_args::{GitPullPromptArgs, GitPullScenario};
 
 /// Prompt provider for git_pull tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for PullPrompts {
     type PromptArgs = GitPullPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("rebase") => prompt_rebase(),
-            Some("conflicts") => prompt_conflicts(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitPullScenario::Rebase) => prompt_rebase(),
+            Some(GitPullScenario::Conflicts) => prompt_conflicts(),
+            Some(GitPullScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, rebase, conflicts)".to_string()),
-                required: Some(false),
-            }
+            GitPullScenario::prompt_argument()
         ]
     }
 }
@@ -241,7 +236,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                         \"new_string\": \"resolved code\"\n\
                     })\n\n\
                  5. Mark as resolved:\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"src/config.rs\"]})\n\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"src/config.rs\"]})\n\n\
                  6. Complete the merge:\n\
                     git_commit({\n\
                         \"path\": \"/project\",\n\
@@ -284,7 +279,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  - Build/compile the code\n\
                  - Run tests\n\n\
                  Step 4: Mark as resolved\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"file1.rs\", \"file2.rs\"]})\n\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"file1.rs\", \"file2.rs\"]})\n\n\
                  Step 5: Complete the merge\n\
                  git_commit({\"path\": \"/project\", \"message\": \"Merge and resolve conflicts\"})\n\n\
                  COMMON CONFLICT SCENARIOS:\n\n\
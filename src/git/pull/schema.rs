@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_PULL};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PullPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -64,4 +65,5 @@ impl ToolArgs for GitPullArgs {
     const NAME: &'static str = GIT_PULL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Fetch and integrate changes from a remote repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,15 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_push` prompts
+    pub enum GitPushScenario {
+        /// Simple pushing
+        Basic => "basic",
+        /// Setting up tracking
+        Upstream => "upstream",
+        /// Force push scenarios
+        Force => "force",
+    }
+}
+
 /// Prompt arguments for git_push tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitPushPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple pushing
-    /// - "upstream": Setting up tracking
-    /// - "tags": Pushing tags
-    /// - "force": Force push scenarios
-    /// - "workflows": Complete push workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitPushScenario>,
 }
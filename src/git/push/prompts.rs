@@ -1,8 +1,8 @@
 //! Prompt messages for git_push tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitPushPromptArgs;
+use super::prompt_args::{GitPushPromptArgs, GitPushScenario};
 
 /// Prompt provider for git_push tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for PushPrompts {
     type PromptArgs = GitPushPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("upstream") => prompt_upstream(),
-            Some("force") => prompt_force(),
+        match args.scenario {
+            Some(GitPushScenario::Basic) => prompt_basic(),
+            Some(GitPushScenario::Upstream) => prompt_upstream(),
+            Some(GitPushScenario::Force) => prompt_force(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, upstream, force)".to_string()),
-                required: Some(false),
-            }
+            GitPushScenario::prompt_argument()
         ]
     }
 }
@@ -278,12 +273,12 @@ fn prompt_force() -> Vec<PromptMessage> {
                  - Last commit changed\n\
                  - Remote still has old version\n\n\
                  After history rewrite:\n\
-                 git_reset({\"path\": \"/project\", \"mode\": \"hard\", \"commit\": \"HEAD~3\"})\n\
+                 git_reset({\"path\": \"/project\", \"mode\": \"hard\", \"target\": \"HEAD~3\"})\n\
                  git_push({\"path\": \"/project\", \"force\": true})\n\
                  - Removed commits from history\n\
                  - Remote needs to match\n\n\
                  Resetting branch:\n\
-                 git_reset({\"path\": \"/project\", \"mode\": \"hard\", \"commit\": \"abc123\"})\n\
+                 git_reset({\"path\": \"/project\", \"mode\": \"hard\", \"target\": \"abc123\"})\n\
                  git_push({\"path\": \"/project\", \"force\": true})\n\
                  - Moving branch pointer backward\n\
                  - Discarding commits\n\n\
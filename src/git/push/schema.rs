@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_PUSH};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PushPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -74,4 +75,5 @@ impl ToolArgs for GitPushArgs {
     const NAME: &'static str = GIT_PUSH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Push commits to a remote repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
 }
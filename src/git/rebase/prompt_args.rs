@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_rebase` prompts
+    pub enum GitRebaseScenario {
+        /// Simple rebasing
+        Basic => "basic",
+        /// Rebasing onto specific commits
+        Onto => "onto",
+        /// Handling rebase conflicts
+        Conflicts => "conflicts",
+        /// Complete rebase workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for git_rebase tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitRebasePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple rebasing
-    /// - "onto": Rebasing onto specific commits
-    /// - "conflicts": Handling rebase conflicts
-    /// - "workflows": Complete rebase workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitRebaseScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_rebase tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitRebasePromptArgs;
+use super::prompt_args::{GitRebasePromptArgs, GitRebaseScenario};
 
 /// Prompt provider for git_rebase tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for RebasePrompts {
     type PromptArgs = GitRebasePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("onto") => prompt_onto(),
-            Some("conflicts") => prompt_conflicts(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GitRebaseScenario::Basic) => prompt_basic(),
+            Some(GitRebaseScenario::Onto) => prompt_onto(),
+            Some(GitRebaseScenario::Conflicts) => prompt_conflicts(),
+            Some(GitRebaseScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, onto, conflicts, workflows)".to_string()),
-                required: Some(false),
-            }
+            GitRebaseScenario::prompt_argument()
         ]
     }
 }
@@ -397,7 +392,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                         \"new_string\": \"resolved code\"\n\
                     })\n\n\
                  4. Stage and continue:\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"src/main.rs\"]})\n\
                     git_rebase({\n\
                         \"path\": \"/project\",\n\
                         \"continue\": true\n\
@@ -472,7 +467,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  })\n\
                  \n\
                  Step 5: Stage resolution\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"src/api.rs\"]})\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"src/api.rs\"]})\n\
                  \n\
                  Step 6: Continue rebase\n\
                  git_rebase({\"path\": \"/repo\", \"continue\": true})\n\
@@ -500,7 +495,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  fs_edit_block({\"path\": \"/repo/file2.rs\", ...})\n\
                  fs_edit_block({\"path\": \"/repo/file3.rs\", ...})\n\
                  \n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"file1.rs\", \"file2.rs\", \"file3.rs\"]})\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"file1.rs\", \"file2.rs\", \"file3.rs\"]})\n\
                  git_rebase({\"path\": \"/repo\", \"continue\": true})\n\n\
                  HANDLING MULTIPLE COMMITS:\n\
                  \n\
@@ -669,7 +664,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                     \n\
                     fs_read_file({\"path\": \"/project/src/file.rs\"})\n\
                     fs_edit_block({...})  // Fix conflicts\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"src/file.rs\"]})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"src/file.rs\"]})\n\
                     \n\
                     git_rebase({\"path\": \"/project\", \"continue\": true})\n\
                     // Repeat until done\n\
@@ -869,7 +864,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                  fs_edit_block({...})  // Fix conflicts\n\
                  \n\
                  Step 4: Stage resolved files\n\
-                 git_add({\"path\": \"/repo\", \"files\": [\"file.rs\"]})\n\
+                 git_add({\"path\": \"/repo\", \"paths\": [\"file.rs\"]})\n\
                  \n\
                  Step 5: Continue rebase\n\
                  git_rebase({\"path\": \"/repo\", \"continue\": true})\n\
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_REBASE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::RebasePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_REBASE TOOL
@@ -68,4 +69,5 @@ impl ToolArgs for GitRebaseArgs {
     const NAME: &'static str = GIT_REBASE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Reapply commits on top of another base";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
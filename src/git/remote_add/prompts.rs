@@ -93,7 +93,7 @@ fn prompt_basic() -> Vec<PromptMessage> {
                  1. Initialize repository:\n\
                     git_init({\"path\": \"/project\"})\n\n\
                  2. Add files and commit:\n\
-                    git_add({\"path\": \"/project\", \"files\": [\".\"],})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\".\"],})\n\
                     git_commit({\"path\": \"/project\", \"message\": \"Initial commit\"})\n\n\
                  3. Add remote:\n\
                     git_remote_add({\n\
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_REMOTE_ADD};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::RemoteAddPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -59,4 +60,5 @@ impl ToolArgs for GitRemoteAddArgs {
     const NAME: &'static str = GIT_REMOTE_ADD;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Add a new remote repository connection";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
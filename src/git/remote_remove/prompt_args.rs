@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_remote_remove` prompts
+    pub enum GitRemoteRemoveScenario {
+        /// Simple remote removal
+        Basic => "basic",
+        /// Cleaning up old remotes
+        Cleanup => "cleanup",
+    }
+}
+
 /// Prompt arguments for `git_remote_remove` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitRemoteRemovePromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Simple remote removal
-    /// - "cleanup": Cleaning up old remotes
-    /// - "replacement": Remove and replace workflow
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitRemoteRemoveScenario>,
 }
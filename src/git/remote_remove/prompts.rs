@@ -1,8 +1,8 @@
 //! Prompt messages for git_remote_remove tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitRemoteRemovePromptArgs;
+use super::prompt_args::{GitRemoteRemovePromptArgs, GitRemoteRemoveScenario};
 
 /// Prompt provider for git_remote_remove tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for RemoteRemovePrompts {
     type PromptArgs = GitRemoteRemovePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("cleanup") => prompt_cleanup(),
+        match args.scenario {
+            Some(GitRemoteRemoveScenario::Basic) => prompt_basic(),
+            Some(GitRemoteRemoveScenario::Cleanup) => prompt_cleanup(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (basic, cleanup)".to_string()),
-                required: Some(false),
-            }
+            GitRemoteRemoveScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_REMOTE_REMOVE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::RemoteRemovePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_REMOTE_REMOVE TOOL
@@ -42,4 +43,5 @@ impl ToolArgs for GitRemoteRemoveArgs {
     const NAME: &'static str = GIT_REMOTE_REMOVE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Remove a remote repository connection";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
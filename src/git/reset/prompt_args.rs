@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_reset` prompts
+    pub enum GitResetScenario {
+        /// Soft reset (keep changes staged)
+        Soft => "soft",
+        /// Mixed reset (keep changes unstaged)
+        Mixed => "mixed",
+    }
+}
+
 /// Prompt arguments for git_reset tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitResetPromptArgs {
-    /// Scenario to show examples for
-    /// - "unstage": Unstaging files
-    /// - "soft": Soft reset (keep changes staged)
-    /// - "mixed": Mixed reset (keep changes unstaged)
-    /// - "hard": Hard reset (discard changes)
-    /// - "safety": Safe reset practices
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitResetScenario>,
 }
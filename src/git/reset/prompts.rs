@@ -1,8 +1,8 @@
 //! Prompt messages for git_reset tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitResetPromptArgs;
+use super::prompt_args::{GitResetPromptArgs, GitResetScenario};
 
 /// Prompt provider for git_reset tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ResetPrompts {
     type PromptArgs = GitResetPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("soft") => prompt_soft(),
-            Some("mixed") => prompt_mixed(),
+        match args.scenario {
+            Some(GitResetScenario::Soft) => prompt_soft(),
+            Some(GitResetScenario::Mixed) => prompt_mixed(),
             _ => prompt_mixed(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (soft, mixed)".to_string()),
-                required: Some(false),
-            }
+            GitResetScenario::prompt_argument()
         ]
     }
 }
@@ -140,9 +135,9 @@ fn prompt_mixed() -> Vec<PromptMessage> {
                      \"target\": \"HEAD~2\"\n\
                  })\n\
                  // Now selectively stage and commit\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"important.rs\"]})\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"important.rs\"]})\n\
                  git_commit({\"path\": \"/project\", \"message\": \"First commit\"})\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"other.rs\"]})\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"other.rs\"]})\n\
                  git_commit({\"path\": \"/project\", \"message\": \"Second commit\"})\n\n\
                  MIXED RESET EFFECT:\n\
                  - HEAD moves to target\n\
@@ -4,8 +4,10 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_RESET};
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::super::ResetMode;
 use super::prompts::ResetPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -56,4 +58,14 @@ impl ToolArgs for GitResetArgs {
     const NAME: &'static str = GIT_RESET;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Reset current HEAD to a specified state (soft/mixed/hard)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
+
+    /// Only hard resets discard work; soft and mixed keep the working tree
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        (self.mode == ResetMode::Hard).then(|| {
+            DestructiveAction::new(format!("Hard reset the current branch to {}", self.target), &self.path)
+                .effect("Uncommitted changes in the index and working tree are discarded")
+                .effect(format!("Commits after {} are removed from the current branch", self.target))
+        })
+    }
 }
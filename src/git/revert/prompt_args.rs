@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_revert` prompts
+    pub enum GitRevertScenario {
+        /// Revert single commit
+        Single => "single",
+        /// Revert multiple commits
+        Multiple => "multiple",
+        /// Revert merge commits
+        Merge => "merge",
+        /// Handling revert conflicts
+        Conflicts => "conflicts",
+    }
+}
+
 /// Prompt arguments for git_revert tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitRevertPromptArgs {
-    /// Scenario to show examples for
-    /// - "single": Revert single commit
-    /// - "multiple": Revert multiple commits
-    /// - "merge": Revert merge commits
-    /// - "conflicts": Handling revert conflicts
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitRevertScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_revert tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitRevertPromptArgs;
+use super::prompt_args::{GitRevertPromptArgs, GitRevertScenario};
 
 /// Prompt provider for git_revert tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for RevertPrompts {
     type PromptArgs = GitRevertPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("single") => prompt_single(),
-            Some("multiple") => prompt_multiple(),
-            Some("merge") => prompt_merge(),
-            Some("conflicts") => prompt_conflicts(),
+        match args.scenario {
+            Some(GitRevertScenario::Single) => prompt_single(),
+            Some(GitRevertScenario::Multiple) => prompt_multiple(),
+            Some(GitRevertScenario::Merge) => prompt_merge(),
+            Some(GitRevertScenario::Conflicts) => prompt_conflicts(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (single, multiple, merge, conflicts)".to_string()),
-                required: Some(false),
-            }
+            GitRevertScenario::prompt_argument()
         ]
     }
 }
@@ -341,7 +336,7 @@ fn prompt_multiple() -> Vec<PromptMessage> {
                     git_status({\"path\": \"/project\"})\n\n\
                  3. Optionally modify:\n\
                     fs_edit_block({...additional changes...})\n\
-                    git_add({\"files\": [...]})\n\n\
+                    git_add({\"paths\": [...]})\n\n\
                  4. Commit manually:\n\
                     git_commit({\"message\": \"Combined revert\"})\n\n\
                  FINDING COMMITS TO REVERT:\n\n\
@@ -585,7 +580,7 @@ fn prompt_merge() -> Vec<PromptMessage> {
                  - Safe for pushed commits\n\
                  - Safe for shared branches\n\n\
                  Reset (DANGEROUS):\n\
-                 git_reset({\"commit\": \"before_merge\"})\n\
+                 git_reset({\"target\": \"before_merge\"})\n\
                  - Removes merge from history\n\
                  - Rewrites history\n\
                  - Breaks others' repos\n\
@@ -641,7 +636,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  5. Stage resolved files:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/main.rs\", \"src/lib.rs\"]\n\
+                        \"paths\": [\"src/main.rs\", \"src/lib.rs\"]\n\
                     })\n\n\
                  6. Continue revert:\n\
                     git_revert({\n\
@@ -708,7 +703,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                      \"new_string\": \"[resolved code]\"\n\
                  })\n\n\
                  Step 6: Stage\n\
-                 git_add({\"files\": [\"src/file.rs\"]})\n\n\
+                 git_add({\"paths\": [\"src/file.rs\"]})\n\n\
                  Step 7: Continue\n\
                  git_revert({\"continue\": true})\n\n\
                  CONFLICT MARKERS:\n\n\
@@ -749,10 +744,10 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  2. Resolve each file:\n\
                     fs_read_file({\"path\": \"file1.rs\"})\n\
                     fs_edit_block({\"path\": \"file1.rs\", ...})\n\
-                    git_add({\"files\": [\"file1.rs\"]})\n\n\
+                    git_add({\"paths\": [\"file1.rs\"]})\n\n\
                     fs_read_file({\"path\": \"file2.rs\"})\n\
                     fs_edit_block({\"path\": \"file2.rs\", ...})\n\
-                    git_add({\"files\": [\"file2.rs\"]})\n\n\
+                    git_add({\"paths\": [\"file2.rs\"]})\n\n\
                  3. Verify all resolved:\n\
                     git_status()\n\
                     // Should show all files staged\n\n\
@@ -949,7 +944,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                  3. Resolve conflicts:\n\
                     fs_edit_block({...remove markers...})\n\n\
                  4. Stage resolved files:\n\
-                    git_add({\"files\": [\"file.rs\"]})\n\n\
+                    git_add({\"paths\": [\"file.rs\"]})\n\n\
                  5. Continue revert:\n\
                     git_revert({\"continue\": true})\n\n\
                  Or abort:\n\
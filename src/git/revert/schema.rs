@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_REVERT};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::RevertPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_REVERT TOOL
@@ -135,4 +136,5 @@ impl ToolArgs for GitRevertArgs {
     const NAME: &'static str = GIT_REVERT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create new commits that undo changes from previous commits";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
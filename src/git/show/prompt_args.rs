@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_show` prompts
+    pub enum GitShowScenario {
+        /// Show commit details
+        Commits => "commits",
+        /// Show tag information
+        Tags => "tags",
+        /// Show file at commit
+        Files => "files",
+        /// Output format options
+        Formatting => "formatting",
+    }
+}
+
 /// Prompt arguments for git_show tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitShowPromptArgs {
-    /// Scenario to show examples for
-    /// - "commits": Show commit details
-    /// - "tags": Show tag information
-    /// - "files": Show file at commit
-    /// - "formatting": Output format options
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitShowScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_show tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitShowPromptArgs;
+use super::prompt_args::{GitShowPromptArgs, GitShowScenario};
 
 /// Prompt provider for git_show tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for ShowPrompts {
     type PromptArgs = GitShowPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("commits") => prompt_commits(),
-            Some("tags") => prompt_tags(),
-            Some("files") => prompt_files(),
-            Some("formatting") => prompt_formatting(),
+        match args.scenario {
+            Some(GitShowScenario::Commits) => prompt_commits(),
+            Some(GitShowScenario::Tags) => prompt_tags(),
+            Some(GitShowScenario::Files) => prompt_files(),
+            Some(GitShowScenario::Formatting) => prompt_formatting(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (commits, tags, files, formatting)".to_string()),
-                required: Some(false),
-            }
+            GitShowScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,181 @@
+//! Schema types for git_show tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GIT, GIT_SHOW};
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::ShowPrompts;
+
+// ============================================================================
+// GIT_SHOW TOOL
+// ============================================================================
+
+/// Arguments for `git_show` tool
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GitShowArgs {
+    /// Path to repository
+    pub path: String,
+
+    /// Object to show: commit ("HEAD~1", "abc1234"), tag ("v1.0.0"),
+    /// or file at a commit ("HEAD:src/main.rs")
+    #[serde(default = "default_object")]
+    pub object: String,
+
+    /// Show file statistics instead of the full diff
+    #[serde(default)]
+    pub stat: bool,
+
+    /// List only the names of changed files
+    #[serde(default)]
+    pub name_only: bool,
+
+    /// List changed files with their status (A/M/D/R/C/T)
+    #[serde(default)]
+    pub name_status: bool,
+
+    /// Custom pretty-format string (e.g., "%h %s (%an)")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Restrict the diff to these paths
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+}
+
+fn default_object() -> String {
+    "HEAD".to_string()
+}
+
+// ============================================================================
+// OUTPUT TYPE
+// ============================================================================
+
+/// Per-file line statistics
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowFileStat {
+    pub path: String,
+    pub insertions: u32,
+    pub deletions: u32,
+}
+
+/// Diff statistics (returned with `stat: true`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowStats {
+    pub files_changed: usize,
+    pub insertions: u32,
+    pub deletions: u32,
+    pub files: Vec<GitShowFileStat>,
+}
+
+/// Changed file with status (returned with `name_status: true`)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowChange {
+    /// Status code: A (added), M (modified), D (deleted), R (renamed), C (copied), T (type changed)
+    pub status: String,
+    /// File path (absent for renames and copies)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Source path of a rename or copy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_path: Option<String>,
+    /// Destination path of a rename or copy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_path: Option<String>,
+}
+
+/// Commit details
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowCommit {
+    /// Full commit hash
+    pub commit: String,
+    /// Author as "Name <email>"
+    pub author: String,
+    /// Authoring time in RFC3339 format
+    pub author_date: String,
+    /// Committer as "Name <email>"
+    pub committer: String,
+    /// Commit time in RFC3339 format
+    pub commit_date: String,
+    /// Full commit message (subject and body)
+    pub message: String,
+    /// Unified diff (omitted with stat, name_only, name_status or format)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    /// Diff statistics (with `stat: true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<GitShowStats>,
+    /// Changed file names (with `name_only: true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<String>>,
+    /// Changed files with status (with `name_status: true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Vec<GitShowChange>>,
+    /// Output of the custom `format` string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+}
+
+/// Tag details (tagger and message are only present for annotated tags)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowTag {
+    pub tag: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagger: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagger_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Commit the tag points to
+    pub commit: String,
+    pub commit_message: String,
+    pub commit_author: String,
+    pub commit_date: String,
+}
+
+/// File contents at a commit (object given as "commit:path")
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowFile {
+    /// Full hash of the commit the file was read from
+    pub commit: String,
+    /// File path relative to the repository root
+    pub path: String,
+    /// File contents as text
+    pub content: String,
+    /// File size in bytes
+    pub size: u64,
+    /// File mode (e.g., "100644")
+    pub mode: String,
+    /// Git object type (e.g., "blob")
+    #[serde(rename = "type")]
+    pub object_type: String,
+}
+
+/// Typed result based on the kind of object shown
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GitShowResult {
+    Commit(GitShowCommit),
+    Tag(GitShowTag),
+    File(GitShowFile),
+}
+
+/// Output from `git_show` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitShowOutput {
+    pub success: bool,
+    /// Object as requested
+    pub object: String,
+    pub result: GitShowResult,
+}
+
+#[tool_metadata(
+    description = "Show a commit, tag, or file at a commit, with diff formatting options"
+)]
+impl ToolArgs for GitShowArgs {
+    type Output = GitShowOutput;
+    type Prompts = ShowPrompts;
+
+    const NAME: &'static str = GIT_SHOW;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
+    const DESCRIPTION: &'static str = "Show a commit, tag, or file at a commit, with diff formatting options";
+}
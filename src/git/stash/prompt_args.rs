@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_stash_save` prompts
+    pub enum GitStashSaveScenario {
+        /// Simple stash save
+        Basic => "basic",
+        /// Stash save operations
+        Operations => "operations",
+    }
+}
+
 /// Prompt arguments for git_stash_save tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitStashSavePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple stash save
-    /// - "messages": Descriptive stash messages
-    /// - "options": Stash save options
-    /// - "workflows": Common stash workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitStashSaveScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_stash_save tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitStashSavePromptArgs;
+use super::prompt_args::{GitStashSavePromptArgs, GitStashSaveScenario};
 
 /// Prompt provider for git_stash tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for StashPrompts {
     type PromptArgs = GitStashSavePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("operations") => prompt_operations(),
+        match args.scenario {
+            Some(GitStashSaveScenario::Basic) => prompt_basic(),
+            Some(GitStashSaveScenario::Operations) => prompt_operations(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, operations)".to_string()),
-                required: Some(false),
-            }
+            GitStashSaveScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_STASH};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::StashPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -69,4 +70,5 @@ impl ToolArgs for GitStashArgs {
     const NAME: &'static str = GIT_STASH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Temporarily store uncommitted changes and restore them later";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
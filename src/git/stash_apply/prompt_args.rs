@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_stash_apply` prompts
+    pub enum GitStashApplyScenario {
+        /// Simple stash apply
+        Basic => "basic",
+        /// Apply specific stash
+        Specific => "specific",
+        /// Handling apply conflicts
+        Conflicts => "conflicts",
+        /// Common apply workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for git_stash_apply tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitStashApplyPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple stash apply
-    /// - "specific": Apply specific stash
-    /// - "conflicts": Handling apply conflicts
-    /// - "workflows": Common apply workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitStashApplyScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_stash_apply tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitStashApplyPromptArgs;
+use super::prompt_args::{GitStashApplyPromptArgs, GitStashApplyScenario};
 
 /// Prompt provider for git_stash_apply tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for StashApplyPrompts {
     type PromptArgs = GitStashApplyPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("specific") => prompt_specific(),
-            Some("conflicts") => prompt_conflicts(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GitStashApplyScenario::Basic) => prompt_basic(),
+            Some(GitStashApplyScenario::Specific) => prompt_specific(),
+            Some(GitStashApplyScenario::Conflicts) => prompt_conflicts(),
+            Some(GitStashApplyScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, specific, conflicts, workflows)".to_string()),
-                required: Some(false),
-            }
+            GitStashApplyScenario::prompt_argument()
         ]
     }
 }
@@ -229,7 +224,7 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  5. Stage resolved files:\n\
                     git_add({\n\
                         \"path\": \"/project\",\n\
-                        \"files\": [\"src/main.rs\", \"src/lib.rs\"]\n\
+                        \"paths\": [\"src/main.rs\", \"src/lib.rs\"]\n\
                     })\n\
                     // Marks conflicts as resolved\n\n\
                  6. Stash still exists after conflict:\n\
@@ -252,13 +247,13 @@ fn prompt_conflicts() -> Vec<PromptMessage> {
                  Strategy 2: Accept stashed (replace current)\n\
                  // Manually edit to keep only stashed version\n\
                  fs_edit_block({...})\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"src/main.rs\"]})\n\n\
                  Strategy 3: Merge both versions\n\
                  // Manually combine the best of both\n\
                  // Remove conflict markers\n\
                  // Keep needed code from both sides\n\
                  fs_edit_block({...})\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"src/main.rs\"]})\n\n\
                  Strategy 4: Abort and retry differently\n\
                  git_reset({\"path\": \"/project\", \"mode\": \"hard\"})\n\
                  // Start over, maybe apply to different branch\n\
@@ -368,7 +363,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                  Step 2: Test thoroughly\n\
                  terminal({\"command\": \"npm test && npm run lint\"})\n\n\
                  Step 3a: If everything works\n\
-                 git_add({\"path\": \"/project\", \"files\": [\".\"]}) \n\
+                 git_add({\"path\": \"/project\", \"paths\": [\".\"]}) \n\
                  git_commit({\n\
                      \"path\": \"/project\",\n\
                      \"message\": \"Restore stashed feature\"\n\
@@ -387,9 +382,9 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                  Apply stash:\n\
                  git_stash_apply({\"path\": \"/project\"})\n\n\
                  Test file by file:\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"src/module1.rs\"]})\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"src/module1.rs\"]})\n\
                  terminal({\"command\": \"cargo test module1\"})\n\n\
-                 git_add({\"path\": \"/project\", \"files\": [\"src/module2.rs\"]})\n\
+                 git_add({\"path\": \"/project\", \"paths\": [\"src/module2.rs\"]})\n\
                  terminal({\"command\": \"cargo test module2\"})\n\n\
                  If a file causes issues:\n\
                  git_checkout({\n\
@@ -409,7 +404,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                  Keep only desired files:\n\
                  git_add({\n\
                      \"path\": \"/project\",\n\
-                     \"files\": [\"src/wanted.rs\"]\n\
+                     \"paths\": [\"src/wanted.rs\"]\n\
                  })\n\n\
                  Discard unwanted files:\n\
                  git_checkout({\n\
@@ -573,7 +568,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                         \"new_string\": \"[resolved code]\"\n\
                     })\n\n\
                  5. Stage resolved files:\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"src/main.rs\"]})\n\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"src/main.rs\"]})\n\n\
                  6. Continue with commit or further work\n\n\
                  ABORTING CONFLICTED APPLY:\n\
                  git_reset({\"path\": \"/project\", \"mode\": \"hard\"})\n\
@@ -586,7 +581,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                  git_stash_apply({\"path\": \"/project\"})\n\
                  terminal({\"command\": \"cargo test\"})\n\
                  // If tests pass:\n\
-                 git_add({\"path\": \"/project\", \"files\": [\".\"]}) \n\
+                 git_add({\"path\": \"/project\", \"paths\": [\".\"]}) \n\
                  git_commit({\"path\": \"/project\", \"message\": \"Restore feature\"})\n\
                  git_stash_drop({\"path\": \"/project\"})  // Cleanup\n\n\
                  WORKFLOW 2: Multi-branch apply\n\
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_STASH_APPLY};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::StashApplyPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_STASH_APPLY TOOL
@@ -66,4 +67,5 @@ impl ToolArgs for GitStashApplyArgs {
     const NAME: &'static str = GIT_STASH_APPLY;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Apply stashed changes without removing from stash";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_stash_list` prompts
+    pub enum GitStashListScenario {
+        /// Simple stash listing
+        Basic => "basic",
+        /// Detailed stash info
+        Details => "details",
+        /// Managing stash entries
+        Management => "management",
+    }
+}
+
 /// Prompt arguments for git_stash_list tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitStashListPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple stash listing
-    /// - "details": Detailed stash info
-    /// - "management": Managing stash entries
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitStashListScenario>,
 }
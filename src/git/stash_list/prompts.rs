@@ -1,8 +1,8 @@
 //! Prompt messages for git_stash_list tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitStashListPromptArgs;
+use super::prompt_args::{GitStashListPromptArgs, GitStashListScenario};
 
 /// Prompt provider for git_stash_list tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for StashListPrompts {
     type PromptArgs = GitStashListPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("details") => prompt_details(),
-            Some("management") => prompt_management(),
+        match args.scenario {
+            Some(GitStashListScenario::Basic) => prompt_basic(),
+            Some(GitStashListScenario::Details) => prompt_details(),
+            Some(GitStashListScenario::Management) => prompt_management(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, details, management)".to_string()),
-                required: Some(false),
-            }
+            GitStashListScenario::prompt_argument()
         ]
     }
 }
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_stash_pop` prompts
+    pub enum GitStashPopScenario {
+        /// Simple stash pop
+        Basic => "basic",
+        /// Pop specific stash
+        Specific => "specific",
+        /// Handling pop conflicts
+        Conflicts => "conflicts",
+        /// Common pop workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for git_stash_pop tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitStashPopPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple stash pop
-    /// - "specific": Pop specific stash
-    /// - "conflicts": Handling pop conflicts
-    /// - "workflows": Common pop workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitStashPopScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_stash_pop tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitStashPopPromptArgs;
+use super::prompt_args::{GitStashPopPromptArgs, GitStashPopScenario};
 
 /// Prompt provider for git_stash_pop tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GitStashPopPrompts {
     type PromptArgs = GitStashPopPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("specific") => prompt_specific(),
-            Some("conflicts") => prompt_conflicts(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GitStashPopScenario::Basic) => prompt_basic(),
+            Some(GitStashPopScenario::Specific) => prompt_specific(),
+            Some(GitStashPopScenario::Conflicts) => prompt_conflicts(),
+            Some(GitStashPopScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for: basic, specific, conflicts, workflows".to_string()),
-                required: Some(false),
-            }
+            GitStashPopScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,70 @@
+//! Schema types for git_stash_pop tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GIT, GIT_STASH_POP};
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::GitStashPopPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// GIT_STASH_POP TOOL
+// ============================================================================
+
+/// Arguments for `git_stash_pop` tool
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GitStashPopArgs {
+    /// Path to repository
+    pub path: String,
+
+    /// Optional stash reference to pop (e.g., "stash@{1}" or just "1")
+    /// If not specified, pops the most recent stash (stash@{0})
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stash: Option<String>,
+
+    /// Also restore the staged state of stashed changes
+    #[serde(default)]
+    pub index: bool,
+}
+
+// ============================================================================
+// OUTPUT TYPE
+// ============================================================================
+
+/// Output from `git_stash_pop` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitStashPopOutput {
+    /// Whether the stash applied cleanly (false on conflicts)
+    pub success: bool,
+
+    /// The stash that was popped
+    pub stash: String,
+
+    /// Files that were restored (possibly partially, on conflict)
+    #[serde(default)]
+    pub files_restored: Vec<String>,
+
+    /// Whether the stash entry was removed (stashes are kept on conflict)
+    pub stash_removed: bool,
+
+    /// Files with conflicts (empty if the pop applied cleanly)
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+
+    /// Explanation when the pop did not succeed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+#[tool_metadata(
+    description = "Apply stashed changes and remove the stash entry (kept on conflict)"
+)]
+impl ToolArgs for GitStashPopArgs {
+    type Output = GitStashPopOutput;
+    type Prompts = GitStashPopPrompts;
+
+    const NAME: &'static str = GIT_STASH_POP;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
+    const DESCRIPTION: &'static str = "Apply stashed changes and remove the stash entry (kept on conflict)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
+}
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_status` prompts
+    pub enum GitStatusScenario {
+        /// Simple status check (default)
+        Basic => "basic",
+        /// Understanding status output
+        Interpreting => "interpreting",
+    }
+}
+
 /// Prompt arguments for git_status tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitStatusPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple status check
-    /// - "interpreting": Understanding status output
-    /// - "workflows": Status in workflows
-    /// - "options": Status options
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitStatusScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_status tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitStatusPromptArgs;
+use super::prompt_args::{GitStatusPromptArgs, GitStatusScenario};
 
 /// Prompt provider for git_status tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for StatusPrompts {
     type PromptArgs = GitStatusPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("interpreting") => prompt_interpreting(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitStatusScenario::Interpreting) => prompt_interpreting(),
+            Some(GitStatusScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, interpreting)".to_string()),
-                required: Some(false),
-            }
+            GitStatusScenario::prompt_argument()
         ]
     }
 }
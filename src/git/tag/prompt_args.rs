@@ -3,16 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_tag` prompts
+    pub enum GitTagScenario {
+        /// Annotated vs lightweight tags
+        Annotated => "annotated",
+    }
+}
+
 /// Prompt arguments for `git_tag` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitTagPromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "create": Creating tags
-    /// - "annotated": Annotated vs lightweight tags
-    /// - "semver": Semantic versioning with tags
-    /// - "list": Listing and managing tags
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitTagScenario>,
 }
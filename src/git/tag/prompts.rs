@@ -1,8 +1,8 @@
 //! Prompt messages for git_tag tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitTagPromptArgs;
+use super::prompt_args::{GitTagPromptArgs, GitTagScenario};
 
 /// Prompt provider for git_tag tool
 pub struct TagPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for TagPrompts {
     type PromptArgs = GitTagPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("annotated") => prompt_annotated(),
+        match args.scenario {
+            Some(GitTagScenario::Annotated) => prompt_annotated(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: annotated (optional)".to_string()),
-                required: Some(false),
-            }
+            GitTagScenario::prompt_argument()
         ]
     }
 }
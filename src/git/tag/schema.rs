@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GIT, GIT_TAG};
 use crate::{ToolArgs, tool_metadata};
 use super::super::GitTagInfo;
 use super::prompts::TagPrompts;
+use crate::tool::ToolBehavior;
 
 
 // ============================================================================
@@ -80,4 +81,5 @@ impl ToolArgs for GitTagArgs {
     const NAME: &'static str = GIT_TAG;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create, list, or delete tags in a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive();
 }
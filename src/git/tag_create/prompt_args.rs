@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_tag_create` prompts
+    pub enum GitTagCreateScenario {
+        /// Annotated tags with messages
+        Annotated => "annotated",
+        /// Simple pointer tags
+        Lightweight => "lightweight",
+        /// Semantic version tags
+        Versioning => "versioning",
+        /// Release tagging workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for git_tag_create tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitTagCreatePromptArgs {
-    /// Scenario to show examples for
-    /// - "annotated": Annotated tags with messages
-    /// - "lightweight": Simple pointer tags
-    /// - "versioning": Semantic version tags
-    /// - "workflows": Release tagging workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitTagCreateScenario>,
 }
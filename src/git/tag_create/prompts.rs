@@ -1,8 +1,8 @@
 //! Prompt messages for git_tag_create tool
 
-use crate::tool::{PromptProvider, SealedPromptProvider};
+use crate::tool::{PromptProvider, PromptScenario, SealedPromptProvider};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitTagCreatePromptArgs;
+use super::prompt_args::{GitTagCreatePromptArgs, GitTagCreateScenario};
 
 /// Prompt provider for git_tag_create tool
 ///
@@ -17,23 +17,18 @@ impl PromptProvider for TagCreatePrompts {
     type PromptArgs = GitTagCreatePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("annotated") => prompt_annotated(),
-            Some("lightweight") => prompt_lightweight(),
-            Some("versioning") => prompt_versioning(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GitTagCreateScenario::Annotated) => prompt_annotated(),
+            Some(GitTagCreateScenario::Lightweight) => prompt_lightweight(),
+            Some(GitTagCreateScenario::Versioning) => prompt_versioning(),
+            Some(GitTagCreateScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (annotated, lightweight, versioning, workflows)".to_string()),
-                required: Some(false),
-            }
+            GitTagCreateScenario::prompt_argument()
         ]
     }
 }
@@ -428,7 +423,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                     git_checkout({\"path\": \"/project\", \"branch\": \"hotfix-1.0.1\", \"create\": true, \"start_point\": \"v1.0.0\"})\n\n\
                  2. Make fixes and commit:\n\
                     // Make your fixes\n\
-                    git_add({\"path\": \"/project\", \"files\": [\"file.rs\"]})\n\
+                    git_add({\"path\": \"/project\", \"paths\": [\"file.rs\"]})\n\
                     git_commit({\"path\": \"/project\", \"message\": \"Fix critical bug\"})\n\n\
                  3. Merge to main:\n\
                     git_checkout({\"path\": \"/project\", \"branch\": \"main\"})\n\
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_TAG_CREATE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::TagCreatePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_TAG_CREATE TOOL
@@ -57,4 +58,5 @@ impl ToolArgs for GitTagCreateArgs {
     const NAME: &'static str = GIT_TAG_CREATE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create annotated or lightweight tags to mark specific points in repository history";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_tag_list` prompts
+    pub enum GitTagListScenario {
+        /// Simple tag listing
+        Basic => "basic",
+        /// Filter and search tags
+        Filtering => "filtering",
+        /// Sort tags by version
+        Sorting => "sorting",
+        /// Tag listing workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for git_tag_list tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitTagListPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple tag listing
-    /// - "filtering": Filter and search tags
-    /// - "sorting": Sort tags by version
-    /// - "workflows": Tag listing workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitTagListScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_tag_list tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitTagListPromptArgs;
+use super::prompt_args::{GitTagListPromptArgs, GitTagListScenario};
 
 /// Prompt provider for git_tag_list tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GitTagListPrompts {
     type PromptArgs = GitTagListPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
-            Some("sorting") => prompt_sorting(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GitTagListScenario::Basic) => prompt_basic(),
+            Some(GitTagListScenario::Filtering) => prompt_filtering(),
+            Some(GitTagListScenario::Sorting) => prompt_sorting(),
+            Some(GitTagListScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering, sorting, workflows)".to_string()),
-                required: Some(false),
-            }
+            GitTagListScenario::prompt_argument()
         ]
     }
 }
@@ -491,7 +486,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                     // Checkout specific version\n\n\
                  3. With git_show:\n\
                     git_tag_list({\"path\": \"/project\", \"limit\": 1, \"sort\": \"-version\"})\n\
-                    git_show({\"path\": \"/project\", \"ref\": \"v1.5.2\"})\n\
+                    git_show({\"path\": \"/project\", \"object\": \"v1.5.2\"})\n\
                     // Get details about latest tag\n\n\
                  4. Before pushing:\n\
                     git_tag_list({\"path\": \"/project\", \"pattern\": \"v2.0.0\"})\n\
@@ -670,7 +665,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                  git_push({\"path\": \"/project\", \"tags\": true})\n\n\
                  With git_show:\n\
                  git_tag_list({\"path\": \"/project\", \"sort\": \"-version\", \"limit\": 1})\n\
-                 git_show({\"path\": \"/project\", \"ref\": \"v1.5.2\"})\n\n\
+                 git_show({\"path\": \"/project\", \"object\": \"v1.5.2\"})\n\n\
                  =============================================================================\n\
                  USE CASES\n\
                  =============================================================================\n\n\
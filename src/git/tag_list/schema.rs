@@ -0,0 +1,72 @@
+//! Schema types for git_tag_list tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GIT, GIT_TAG_LIST};
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::GitTagListPrompts;
+
+// ============================================================================
+// GIT_TAG_LIST TOOL
+// ============================================================================
+
+/// Sort order for `git_tag_list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum TagSort {
+    /// Semantic version order (v1.9.0 < v1.10.0)
+    #[serde(rename = "version")]
+    Version,
+    /// Reverse semantic version order (newest version first)
+    #[serde(rename = "-version")]
+    VersionDesc,
+    /// Tag creation date (oldest first)
+    #[serde(rename = "creatordate")]
+    CreatorDate,
+    /// Tag creation date (newest first)
+    #[serde(rename = "-creatordate")]
+    CreatorDateDesc,
+}
+
+/// Arguments for `git_tag_list` tool
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct GitTagListArgs {
+    /// Path to repository
+    pub path: String,
+
+    /// Glob pattern to filter tags (e.g., "v1.*", "*-rc*")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Sort order (default: alphabetical by tag name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<TagSort>,
+
+    /// Maximum number of tags to return (applied after sorting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+// ============================================================================
+// OUTPUT TYPE
+// ============================================================================
+
+/// Output from `git_tag_list` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitTagListOutput {
+    pub success: bool,
+    /// Tag names in the requested order
+    pub tags: Vec<String>,
+    pub count: usize,
+}
+
+#[tool_metadata(
+    description = "List tags with optional glob filtering, sorting and limit"
+)]
+impl ToolArgs for GitTagListArgs {
+    type Output = GitTagListOutput;
+    type Prompts = GitTagListPrompts;
+
+    const NAME: &'static str = GIT_TAG_LIST;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
+    const DESCRIPTION: &'static str = "List tags with optional glob filtering, sorting and limit";
+}
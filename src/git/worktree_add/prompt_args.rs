@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_worktree_add` prompts
+    pub enum GitWorktreeAddScenario {
+        /// Creating a linked worktree (default)
+        Basic => "basic",
+        /// Creating new branch in worktree
+        Branch => "branch",
+    }
+}
+
 /// Prompt arguments for `git_worktree_add` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitWorktreeAddPromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Creating a linked worktree
-    /// - "parallel": Working on multiple features simultaneously
-    /// - "branch": Creating new branch in worktree
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitWorktreeAddScenario>,
 }
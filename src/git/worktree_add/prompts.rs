@@ -1,8 +1,8 @@
 //! Prompt messages for git_worktree_add tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitWorktreeAddPromptArgs;
+use super::prompt_args::{GitWorktreeAddPromptArgs, GitWorktreeAddScenario};
 
 /// Prompt provider for git_worktree_add tool
 pub struct WorktreeAddPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for WorktreeAddPrompts {
     type PromptArgs = GitWorktreeAddPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("branch") => prompt_branch(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitWorktreeAddScenario::Branch) => prompt_branch(),
+            Some(GitWorktreeAddScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, branch".to_string()),
-                required: Some(false),
-            }
+            GitWorktreeAddScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_WORKTREE_ADD};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WorktreeAddPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_WORKTREE_ADD TOOL
@@ -53,4 +54,5 @@ impl ToolArgs for GitWorktreeAddArgs {
     const NAME: &'static str = GIT_WORKTREE_ADD;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Create a new linked working tree for parallel development";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
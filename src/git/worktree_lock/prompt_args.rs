@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_worktree_lock` prompts
+    pub enum GitWorktreeLockScenario {
+        /// Locking a worktree
+        Basic => "basic",
+        /// Preventing automatic cleanup
+        Prevent => "prevent",
+    }
+}
+
 /// Prompt arguments for `git_worktree_lock` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitWorktreeLockPromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Locking a worktree
-    /// - "prevent": Preventing automatic cleanup
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitWorktreeLockScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for git_worktree_lock tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitWorktreeLockPromptArgs;
+use super::prompt_args::{GitWorktreeLockPromptArgs, GitWorktreeLockScenario};
 
 /// Prompt provider for git_worktree_lock tool
 pub struct WorktreeLockPrompts;
@@ -11,21 +11,16 @@ impl PromptProvider for WorktreeLockPrompts {
     type PromptArgs = GitWorktreeLockPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("prevent") => prompt_prevent(),
+        match args.scenario {
+            Some(GitWorktreeLockScenario::Basic) => prompt_basic(),
+            Some(GitWorktreeLockScenario::Prevent) => prompt_prevent(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, prevent".to_string()),
-                required: Some(false),
-            }
+            GitWorktreeLockScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_WORKTREE_LOCK};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WorktreeLockPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_WORKTREE_LOCK TOOL
@@ -49,4 +50,5 @@ impl ToolArgs for GitWorktreeLockArgs {
     const NAME: &'static str = GIT_WORKTREE_LOCK;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Lock a working tree to prevent automatic deletion";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent();
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_WORKTREE_PRUNE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WorktreePrunePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_WORKTREE_PRUNE TOOL
@@ -39,4 +40,5 @@ impl ToolArgs for GitWorktreePruneArgs {
     const NAME: &'static str = GIT_WORKTREE_PRUNE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Remove stale administrative files for deleted working trees";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_worktree_remove` prompts
+    pub enum GitWorktreeRemoveScenario {
+        /// Removing a worktree (default)
+        Basic => "basic",
+        /// Forced removal with uncommitted changes
+        Force => "force",
+    }
+}
+
 /// Prompt arguments for `git_worktree_remove` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitWorktreeRemovePromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Removing a worktree
-    /// - "force": Forced removal with uncommitted changes
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitWorktreeRemoveScenario>,
 }
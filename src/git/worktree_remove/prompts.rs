@@ -1,8 +1,8 @@
 //! Prompt messages for git_worktree_remove tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitWorktreeRemovePromptArgs;
+use super::prompt_args::{GitWorktreeRemovePromptArgs, GitWorktreeRemoveScenario};
 
 /// Prompt provider for git_worktree_remove tool
 pub struct WorktreeRemovePrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for WorktreeRemovePrompts {
     type PromptArgs = GitWorktreeRemovePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("force") => prompt_force(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitWorktreeRemoveScenario::Force) => prompt_force(),
+            Some(GitWorktreeRemoveScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, force".to_string()),
-                required: Some(false),
-            }
+            GitWorktreeRemoveScenario::prompt_argument()
         ]
     }
 }
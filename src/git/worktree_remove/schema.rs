@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_WORKTREE_REMOVE};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WorktreeRemovePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_WORKTREE_REMOVE TOOL
@@ -46,4 +47,5 @@ impl ToolArgs for GitWorktreeRemoveArgs {
     const NAME: &'static str = GIT_WORKTREE_REMOVE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Remove a working tree and its administrative files";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `git_worktree_unlock` prompts
+    pub enum GitWorktreeUnlockScenario {
+        /// Unlocking a worktree (default)
+        Basic => "basic",
+        /// Enabling automatic cleanup
+        Cleanup => "cleanup",
+    }
+}
+
 /// Prompt arguments for `git_worktree_unlock` tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitWorktreeUnlockPromptArgs {
-    /// Optional: Use case for customized examples
-    /// - "basic": Unlocking a worktree
-    /// - "cleanup": Enabling automatic cleanup
-    ///
-    /// Default if omitted: comprehensive overview covering all aspects
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitWorktreeUnlockScenario>,
 }
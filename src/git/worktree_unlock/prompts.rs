@@ -1,8 +1,8 @@
 //! Prompt messages for git_worktree_unlock tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitWorktreeUnlockPromptArgs;
+use super::prompt_args::{GitWorktreeUnlockPromptArgs, GitWorktreeUnlockScenario};
 
 /// Prompt provider for git_worktree_unlock tool
 pub struct WorktreeUnlockPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for WorktreeUnlockPrompts {
     type PromptArgs = GitWorktreeUnlockPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("cleanup") => prompt_cleanup(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GitWorktreeUnlockScenario::Cleanup) => prompt_cleanup(),
+            Some(GitWorktreeUnlockScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario: basic, cleanup".to_string()),
-                required: Some(false),
-            }
+            GitWorktreeUnlockScenario::prompt_argument()
         ]
     }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GIT, GIT_WORKTREE_UNLOCK};
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::WorktreeUnlockPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // GIT_WORKTREE_UNLOCK TOOL
@@ -42,4 +43,5 @@ impl ToolArgs for GitWorktreeUnlockArgs {
     const NAME: &'static str = GIT_WORKTREE_UNLOCK;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GIT;
     const DESCRIPTION: &'static str = "Unlock a working tree to allow automatic cleanup";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent();
 }
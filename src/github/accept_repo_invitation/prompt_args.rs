@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_accept_repo_invitation` prompts
+    pub enum GithubAcceptRepoInvitationScenario {
+        /// Accepting invitations
+        Basic => "basic",
+        /// Complete invitation workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_accept_repo_invitation tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubAcceptRepoInvitationPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Accepting invitations
-    /// - "workflows": Complete invitation workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubAcceptRepoInvitationScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_accept_repo_invitation tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubAcceptRepoInvitationPromptArgs;
+use super::prompt_args::{GithubAcceptRepoInvitationPromptArgs, GithubAcceptRepoInvitationScenario};
 
 /// Prompt provider for accept_repo_invitation tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for GithubAcceptRepoInvitationPrompts {
     type PromptArgs = GithubAcceptRepoInvitationPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubAcceptRepoInvitationScenario::Basic) => prompt_basic(),
+            Some(GithubAcceptRepoInvitationScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (basic, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubAcceptRepoInvitationScenario::prompt_argument()
         ]
     }
 }
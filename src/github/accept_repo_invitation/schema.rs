@@ -0,0 +1,55 @@
+//! Schema types for accept_repo_invitation tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_ACCEPT_REPO_INVITATION};
+
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::GithubAcceptRepoInvitationPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `accept_repo_invitation` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AcceptRepoInvitationArgs {
+    /// Invitation ID (from `github_pending_invitations`)
+    pub invitation_id: u64,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_accept_repo_invitation` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubAcceptRepoInvitationOutput {
+    pub success: bool,
+    pub invitation_id: u64,
+    /// Repository in "owner/repo" format
+    pub repository: String,
+    /// Permission granted: "read", "triage", "write", "maintain" or "admin"
+    pub permissions: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Accept a pending repository collaboration invitation"
+)]
+impl ToolArgs for AcceptRepoInvitationArgs {
+    type Output = GitHubAcceptRepoInvitationOutput;
+    type Prompts = GithubAcceptRepoInvitationPrompts;
+
+    const NAME: &'static str = GITHUB_ACCEPT_REPO_INVITATION;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Accept a pending repository collaboration invitation";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent().open_world();
+}
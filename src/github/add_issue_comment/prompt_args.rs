@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_add_comment` prompts
+    pub enum AddIssueCommentScenario {
+        /// Commenting on issues
+        Issues => "issues",
+        /// Commenting on PRs
+        Prs => "prs",
+    }
+}
+
 /// Prompt arguments for github_add_comment tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AddIssueCommentPromptArgs {
-    /// Scenario to show examples for
-    /// - "issues": Commenting on issues
-    /// - "prs": Commenting on PRs
-    /// - "formatting": Markdown and mentions
-    /// - "workflows": Comment workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<AddIssueCommentScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_add_comment tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::AddIssueCommentPromptArgs;
+use super::prompt_args::{AddIssueCommentPromptArgs, AddIssueCommentScenario};
 
 /// Prompt provider for add_issue_comment tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for AddIssueCommentPrompts {
     type PromptArgs = AddIssueCommentPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("issues") => prompt_issues(),
-            Some("prs") => prompt_prs(),
+        match args.scenario {
+            Some(AddIssueCommentScenario::Issues) => prompt_issues(),
+            Some(AddIssueCommentScenario::Prs) => prompt_prs(),
             _ => prompt_issues(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (issues, prs)".to_string()),
-                required: Some(false),
-            }
+            AddIssueCommentScenario::prompt_argument()
         ]
     }
 }
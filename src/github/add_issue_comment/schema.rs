@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_ADD_ISSUE_COMMENT};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::AddIssueCommentPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -53,4 +54,5 @@ impl ToolArgs for AddIssueCommentArgs {
     const NAME: &'static str = GITHUB_ADD_ISSUE_COMMENT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Add a comment to an existing GitHub issue";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
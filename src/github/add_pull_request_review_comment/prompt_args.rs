@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_add_pull_request_review_comment` prompts
+    pub enum AddPullRequestReviewCommentScenario {
+        /// Multi-line comments spanning multiple diff lines
+        Multiline => "multiline",
+        /// Replying to existing comments for threaded discussions
+        Reply => "reply",
+    }
+}
+
 /// Prompt arguments for github_add_pull_request_review_comment tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AddPullRequestReviewCommentPromptArgs {
-    /// Scenario to show examples for
-    /// - "multiline": Multi-line comments spanning multiple diff lines
-    /// - "reply": Replying to existing comments for threaded discussions
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<AddPullRequestReviewCommentScenario>,
 }
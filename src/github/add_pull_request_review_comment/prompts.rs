@@ -1,8 +1,8 @@
 //! Prompt messages for github_add_pull_request_review_comment tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::AddPullRequestReviewCommentPromptArgs;
+use super::prompt_args::{AddPullRequestReviewCommentPromptArgs, AddPullRequestReviewCommentScenario};
 
 /// Prompt provider for add_pull_request_review_comment tool
 ///
@@ -346,14 +346,14 @@ impl PromptProvider for AddPullRequestReviewCommentPrompts {
     type PromptArgs = AddPullRequestReviewCommentPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("multiline") => prompt_multiline(),
-            Some("reply") => prompt_reply(),
+        match args.scenario {
+            Some(AddPullRequestReviewCommentScenario::Multiline) => prompt_multiline(),
+            Some(AddPullRequestReviewCommentScenario::Reply) => prompt_reply(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![]
+        vec![AddPullRequestReviewCommentScenario::prompt_argument()]
     }
 }
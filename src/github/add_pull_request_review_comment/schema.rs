@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_ADD_PULL_REQUEST_REVIEW_COMMENT};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::AddPullRequestReviewCommentPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -77,4 +78,5 @@ impl ToolArgs for AddPullRequestReviewCommentArgs {
     const NAME: &'static str = GITHUB_ADD_PULL_REQUEST_REVIEW_COMMENT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Add a comment to a pull request review";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_code_scanning_alerts` prompts
+    pub enum CodeScanningAlertsScenario {
+        /// Filter by severity, state (default)
+        Filtering => "filtering",
+        /// Listing code scanning alerts
+        Basic => "basic",
+    }
+}
+
 /// Prompt arguments for github_code_scanning_alerts tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CodeScanningAlertsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Listing code scanning alerts
-    /// - "filtering": Filter by severity, state
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CodeScanningAlertsScenario>,
 }
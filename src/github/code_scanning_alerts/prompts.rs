@@ -1,8 +1,8 @@
 //! Prompt messages for github_code_scanning_alerts tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CodeScanningAlertsPromptArgs;
+use super::prompt_args::{CodeScanningAlertsPromptArgs, CodeScanningAlertsScenario};
 
 /// Prompt provider for code_scanning_alerts tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for CodeScanningAlertsPrompts {
     type PromptArgs = CodeScanningAlertsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            _ => prompt_filtering(),
+        match args.scenario {
+            Some(CodeScanningAlertsScenario::Basic) => prompt_basic(),
+            Some(CodeScanningAlertsScenario::Filtering) | None => prompt_filtering(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering)".to_string()),
-                required: Some(false),
-            }
+            CodeScanningAlertsScenario::prompt_argument()
         ]
     }
 }
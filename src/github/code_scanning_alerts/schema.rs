@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CODE_SCANNING_ALERTS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CodeScanningAlertsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -72,4 +73,5 @@ impl ToolArgs for CodeScanningAlertsArgs {
     const NAME: &'static str = GITHUB_CODE_SCANNING_ALERTS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get code scanning alerts for a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
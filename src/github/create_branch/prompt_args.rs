@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_branch` prompts
+    pub enum CreateBranchScenario {
+        /// Simple branch creation
+        Basic => "basic",
+        /// Branch from specific commit/tag
+        FromRef => "from_ref",
+    }
+}
+
 /// Prompt arguments for github_create_branch tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateBranchPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple branch creation
-    /// - "from_ref": Branch from specific commit/tag
-    /// - "workflows": Branch creation workflows
-    /// - "integration": Integration with git operations
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreateBranchScenario>,
 }
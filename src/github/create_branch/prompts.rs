@@ -1,7 +1,7 @@
 //! Prompt messages for github_create_branch tool
 
-use super::prompt_args::CreateBranchPromptArgs;
-use crate::tool::PromptProvider;
+use super::prompt_args::{CreateBranchPromptArgs, CreateBranchScenario};
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
 
 /// Prompt provider for create_branch tool
@@ -14,22 +14,15 @@ impl PromptProvider for CreateBranchPrompts {
     type PromptArgs = CreateBranchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("from_ref") => prompt_from_ref(),
+        match args.scenario {
+            Some(CreateBranchScenario::Basic) => prompt_basic(),
+            Some(CreateBranchScenario::FromRef) => prompt_from_ref(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![PromptArgument {
-            name: "scenario".to_string(),
-            title: None,
-            description: Some(
-                "Scenario to show (basic, from_ref)".to_string(),
-            ),
-            required: Some(false),
-        }]
+        vec![CreateBranchScenario::prompt_argument()]
     }
 }
 
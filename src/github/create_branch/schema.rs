@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_BRANCH};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreateBranchPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -53,4 +54,5 @@ impl ToolArgs for CreateBranchArgs {
     const NAME: &'static str = GITHUB_CREATE_BRANCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Create a new branch in a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_issue` prompts
+    pub enum CreateIssueScenario {
+        /// Creating bug reports
+        BugReport => "bug_report",
+        /// Labels, assignees, milestones
+        Metadata => "metadata",
+        /// Using issue templates
+        Templates => "templates",
+    }
+}
+
 /// Prompt arguments for github_create_issue tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateIssuePromptArgs {
-    /// Scenario to show examples for
-    /// - "bug_report": Creating bug reports
-    /// - "feature_request": Feature request issues
-    /// - "metadata": Labels, assignees, milestones
-    /// - "templates": Using issue templates
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreateIssueScenario>,
 }
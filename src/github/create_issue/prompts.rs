@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_issue tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CreateIssuePromptArgs;
+use super::prompt_args::{CreateIssuePromptArgs, CreateIssueScenario};
 
 /// Prompt provider for github_create_issue tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for CreateIssuePrompts {
     type PromptArgs = CreateIssuePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("bug_report") => prompt_bug_report(),
-            Some("metadata") => prompt_metadata(),
-            Some("templates") => prompt_templates(),
+        match args.scenario {
+            Some(CreateIssueScenario::BugReport) => prompt_bug_report(),
+            Some(CreateIssueScenario::Metadata) => prompt_metadata(),
+            Some(CreateIssueScenario::Templates) => prompt_templates(),
             _ => prompt_metadata(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (bug_report, metadata, templates)".to_string()),
-                required: Some(false),
-            }
+            CreateIssueScenario::prompt_argument()
         ]
     }
 }
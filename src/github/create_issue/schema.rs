@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_ISSUE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreateIssuePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -60,4 +61,5 @@ impl ToolArgs for CreateIssueArgs {
     const NAME: &'static str = GITHUB_CREATE_ISSUE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Create a new issue in a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
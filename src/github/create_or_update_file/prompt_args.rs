@@ -3,8 +3,21 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_or_update_file` prompts
+    pub enum CreateOrUpdateFileScenario {
+        /// Getting and passing the file SHA for updates
+        ShaHandling => "sha_handling",
+        /// Writing files to a specific branch
+        BranchTargeting => "branch_targeting",
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateOrUpdateFilePromptArgs {
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreateOrUpdateFileScenario>,
 }
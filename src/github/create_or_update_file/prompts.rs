@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_or_update_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CreateOrUpdateFilePromptArgs;
+use super::prompt_args::{CreateOrUpdateFilePromptArgs, CreateOrUpdateFileScenario};
 
 /// Prompt provider for create_or_update_file tool
 ///
@@ -101,7 +101,7 @@ fn prompt_sha_handling() -> Vec<PromptMessage> {
                  SHA ensures atomicity: your update applies to the exact version you read.\n\n\
                  COMPLETE WORKFLOW EXAMPLE:\n\
                  Step 1 - Read current file and get SHA:\n\
-                 result = github_get_file_contents({\"owner\": \"rust-lang\", \"repo\": \"rust\", \"path\": \"Cargo.toml\", \"branch\": \"main\"})\n\
+                 result = github_get_file_contents({\"owner\": \"rust-lang\", \"repo\": \"rust\", \"path\": \"Cargo.toml\", \"ref_name\": \"main\"})\n\
                  # Returns: {\"content\": \"W3BhY2thZ2Vd...\", \"sha\": \"abc123def456...\", ...}\n\n\
                  Step 2 - Modify content locally:\n\
                  decoded = base64_decode(result.content)\n\
@@ -259,14 +259,14 @@ impl PromptProvider for CreateOrUpdateFilePrompts {
     type PromptArgs = CreateOrUpdateFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("sha_handling") => prompt_sha_handling(),
-            Some("branch_targeting") => prompt_branch_targeting(),
+        match args.scenario {
+            Some(CreateOrUpdateFileScenario::ShaHandling) => prompt_sha_handling(),
+            Some(CreateOrUpdateFileScenario::BranchTargeting) => prompt_branch_targeting(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![]
+        vec![CreateOrUpdateFileScenario::prompt_argument()]
     }
 }
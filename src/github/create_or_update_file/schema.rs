@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_OR_UPDATE_FILE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreateOrUpdateFilePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -64,4 +65,5 @@ impl ToolArgs for CreateOrUpdateFileArgs {
     const NAME: &'static str = GITHUB_CREATE_OR_UPDATE_FILE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Create or update a file in a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_pull_request` prompts
+    pub enum CreatePullRequestScenario {
+        /// Simple PR creation (default)
+        Basic => "basic",
+        /// Draft PRs and options
+        Options => "options",
+        /// Complete PR workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_create_pull_request tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreatePullRequestPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple PR creation
-    /// - "description": Writing good PR descriptions
-    /// - "options": Draft PRs and options
-    /// - "workflows": Complete PR workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreatePullRequestScenario>,
 }
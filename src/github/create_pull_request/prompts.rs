@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_pull_request tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CreatePullRequestPromptArgs;
+use super::prompt_args::{CreatePullRequestPromptArgs, CreatePullRequestScenario};
 
 /// Prompt provider for create_pull_request tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for CreatePullRequestPrompts {
     type PromptArgs = CreatePullRequestPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("options") => prompt_options(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(CreatePullRequestScenario::Options) => prompt_options(),
+            Some(CreatePullRequestScenario::Workflows) => prompt_workflows(),
+            Some(CreatePullRequestScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, options, workflows)".to_string()),
-                required: Some(false),
-            }
+            CreatePullRequestScenario::prompt_argument()
         ]
     }
 }
@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_pull_request_review` prompts
+    pub enum CreatePullRequestReviewScenario {
+        /// Review event types (APPROVE, REQUEST_CHANGES, COMMENT) (default)
+        Basic => "basic",
+        /// Adding comments to specific code lines
+        InlineComments => "inline_comments",
+        /// Automated review patterns
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_create_pull_request_review tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreatePullRequestReviewPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Review event types (APPROVE, REQUEST_CHANGES, COMMENT)
-    /// - "inline_comments": Adding comments to specific code lines
-    /// - "workflows": Automated review patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreatePullRequestReviewScenario>,
 }
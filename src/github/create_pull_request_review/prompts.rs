@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_pull_request_review tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CreatePullRequestReviewPromptArgs;
+use super::prompt_args::{CreatePullRequestReviewPromptArgs, CreatePullRequestReviewScenario};
 
 /// Prompt provider for create_pull_request_review tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for CreatePullRequestReviewPrompts {
     type PromptArgs = CreatePullRequestReviewPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("inline_comments") => prompt_inline_comments(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(CreatePullRequestReviewScenario::InlineComments) => prompt_inline_comments(),
+            Some(CreatePullRequestReviewScenario::Workflows) => prompt_workflows(),
+            Some(CreatePullRequestReviewScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, inline_comments, workflows)".to_string()),
-                required: Some(false),
-            }
+            CreatePullRequestReviewScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_PULL_REQUEST_REVIEW};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreatePullRequestReviewPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -60,4 +61,5 @@ impl ToolArgs for CreatePullRequestReviewArgs {
     const NAME: &'static str = GITHUB_CREATE_PULL_REQUEST_REVIEW;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Create a review on a pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -1,7 +1,9 @@
 //! GitHub create_release tool schemas
 
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::GithubCreateReleasePromptArgs;
 pub use prompts::GithubCreateReleasePrompts;
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_release` prompts
+    pub enum GithubCreateReleaseScenario {
+        /// Simple release creation
+        Basic => "basic",
+        /// Writing release notes
+        Notes => "notes",
+        /// Draft, pre-release, assets
+        Options => "options",
+        /// Release workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_create_release tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubCreateReleasePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple release creation
-    /// - "notes": Writing release notes
-    /// - "options": Draft, pre-release, assets
-    /// - "workflows": Release workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubCreateReleaseScenario>,
 }
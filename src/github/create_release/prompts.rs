@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_release tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubCreateReleasePromptArgs;
+use super::prompt_args::{GithubCreateReleasePromptArgs, GithubCreateReleaseScenario};
 
 /// Prompt provider for github_create_release tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubCreateReleasePrompts {
     type PromptArgs = GithubCreateReleasePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("notes") => prompt_notes(),
-            Some("options") => prompt_options(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubCreateReleaseScenario::Basic) => prompt_basic(),
+            Some(GithubCreateReleaseScenario::Notes) => prompt_notes(),
+            Some(GithubCreateReleaseScenario::Options) => prompt_options(),
+            Some(GithubCreateReleaseScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, notes, options, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubCreateReleaseScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,73 @@
+//! Schema types for create_release tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_RELEASE};
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::GitHubRelease;
+use super::prompts::GithubCreateReleasePrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `create_release` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CreateReleaseArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Git tag for the release (e.g., "v1.0.0"); created if it does not exist
+    pub tag_name: String,
+    /// Release title (optional, defaults to tag_name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Release notes in Markdown (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Create as an unpublished draft
+    #[serde(default)]
+    pub draft: bool,
+    /// Mark as a pre-release
+    #[serde(default)]
+    pub prerelease: bool,
+    /// Branch or commit SHA the tag is created from (optional, defaults to default branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    /// Auto-generate release notes from merged pull requests
+    #[serde(default)]
+    pub generate_release_notes: bool,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_create_release` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubCreateReleaseOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub release: GitHubRelease,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Create a release (optionally draft or pre-release) in a GitHub repository"
+)]
+impl ToolArgs for CreateReleaseArgs {
+    type Output = GitHubCreateReleaseOutput;
+    type Prompts = GithubCreateReleasePrompts;
+
+    const NAME: &'static str = GITHUB_CREATE_RELEASE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Create a release (optionally draft or pre-release) in a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
+}
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_create_repository` prompts
+    pub enum CreateRepositoryScenario {
+        /// Simple repo creation (default)
+        Basic => "basic",
+        /// Visibility, features
+        Options => "options",
+        /// Org repos
+        Organization => "organization",
+    }
+}
+
 /// Prompt arguments for github_create_repository tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateRepositoryPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple repo creation
-    /// - "options": Visibility, features
-    /// - "organization": Org repos
-    /// - "workflows": Complete repo setup
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<CreateRepositoryScenario>,
 }
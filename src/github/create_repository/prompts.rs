@@ -1,8 +1,8 @@
 //! Prompt messages for github_create_repository tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::CreateRepositoryPromptArgs;
+use super::prompt_args::{CreateRepositoryPromptArgs, CreateRepositoryScenario};
 
 /// Prompt provider for create_repository tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for CreateRepositoryPrompts {
     type PromptArgs = CreateRepositoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("options") => prompt_options(),
-            Some("organization") => prompt_organization(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(CreateRepositoryScenario::Options) => prompt_options(),
+            Some(CreateRepositoryScenario::Organization) => prompt_organization(),
+            Some(CreateRepositoryScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, options, organization)".to_string()),
-                required: Some(false),
-            }
+            CreateRepositoryScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_CREATE_REPOSITORY};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::CreateRepositoryPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -96,4 +97,5 @@ impl ToolArgs for CreateRepositoryArgs {
     const NAME: &'static str = GITHUB_CREATE_REPOSITORY;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Create a new GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
@@ -3,10 +3,23 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_delete_branch` prompts
+    pub enum DeleteBranchScenario {
+        /// Basic branch deletion (default)
+        Basic => "basic",
+        /// Safe branch deletion with protection and verification
+        Safety => "safety",
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DeleteBranchPromptArgs {
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<DeleteBranchScenario>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_permissions: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,8 +1,8 @@
 //! Prompt messages for github_delete_branch tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::DeleteBranchPromptArgs;
+use super::prompt_args::{DeleteBranchPromptArgs, DeleteBranchScenario};
 
 /// Prompt provider for delete_branch tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for DeleteBranchPrompts {
     type PromptArgs = DeleteBranchPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("safety") => prompt_safety(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(DeleteBranchScenario::Safety) => prompt_safety(),
+            Some(DeleteBranchScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, safety)".to_string()),
-                required: Some(false),
-            }
+            DeleteBranchScenario::prompt_argument()
         ]
     }
 }
@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_GITHUB, GITHUB_DELETE_BRANCH};
 
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::prompts::DeleteBranchPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -50,4 +52,16 @@ impl ToolArgs for DeleteBranchArgs {
     const NAME: &'static str = GITHUB_DELETE_BRANCH;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Delete a branch from a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent().open_world();
+
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        Some(
+            DestructiveAction::new(
+                format!("Delete branch {}", self.branch_name),
+                format!("{}/{}", self.owner, self.repo),
+            )
+            .effect("Commits only reachable from this branch become unreachable")
+            .effect("Open pull requests from this branch are closed"),
+        )
+    }
 }
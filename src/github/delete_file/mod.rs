@@ -1,7 +1,9 @@
 //! GitHub delete file prompt support
 
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::GithubDeleteFilePromptArgs;
 pub use prompts::GithubDeleteFilePrompts;
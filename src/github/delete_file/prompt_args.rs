@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_delete_file` prompts
+    pub enum GithubDeleteFileScenario {
+        /// Simple file deletion
+        Basic => "basic",
+        /// Safe deletion practices
+        Safety => "safety",
+        /// Deletion workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_delete_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubDeleteFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple file deletion
-    /// - "safety": Safe deletion practices
-    /// - "workflows": Deletion workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubDeleteFileScenario>,
 }
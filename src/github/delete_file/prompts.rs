@@ -1,8 +1,8 @@
 //! Prompt messages for github_delete_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubDeleteFilePromptArgs;
+use super::prompt_args::{GithubDeleteFilePromptArgs, GithubDeleteFileScenario};
 
 /// Prompt provider for github_delete_file tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for GithubDeleteFilePrompts {
     type PromptArgs = GithubDeleteFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("safety") => prompt_safety(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubDeleteFileScenario::Basic) => prompt_basic(),
+            Some(GithubDeleteFileScenario::Safety) => prompt_safety(),
+            Some(GithubDeleteFileScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, safety, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubDeleteFileScenario::prompt_argument()
         ]
     }
 }
@@ -366,7 +361,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                      \"owner\": \"username\",\n\
                      \"repo\": \"project\",\n\
                      \"path\": \"experiments/prototype.py\",\n\
-                     \"ref\": \"develop\"  // Get from specific branch\n\
+                     \"ref_name\": \"develop\"  // Get from specific branch\n\
                  })\n\
                  github_delete_file({\n\
                      \"owner\": \"username\",\n\
@@ -723,7 +718,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                      \"owner\": \"username\",\n\
                      \"repo\": \"project\",\n\
                      \"path\": \"file.txt\",\n\
-                     \"ref\": \"develop\"  // Get from develop branch\n\
+                     \"ref_name\": \"develop\"  // Get from develop branch\n\
                  })\n\
                  // Step 2: Delete from that branch\n\
                  github_delete_file({\n\
@@ -873,7 +868,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                         \"owner\": \"user\",\n\
                         \"repo\": \"project\",\n\
                         \"path\": \"file.txt\",\n\
-                        \"ref\": \"<commit-sha-before-deletion>\"\n\
+                        \"ref_name\": \"<commit-sha-before-deletion>\"\n\
                     })\n\
                  4. Restore file using create_or_update_file:\n\
                     github_create_or_update_file({\n\
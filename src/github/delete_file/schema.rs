@@ -0,0 +1,69 @@
+//! Schema types for delete_file tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_DELETE_FILE};
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::{GitHubCommitIdentity, GitHubContentCommit};
+use super::prompts::GithubDeleteFilePrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `delete_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DeleteFileArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Path of the file to delete
+    pub path: String,
+    /// Commit message
+    pub message: String,
+    /// Current blob SHA of the file (from `github_get_file_contents`)
+    pub sha: String,
+    /// Branch name (optional, defaults to default branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Committer identity (optional, defaults to the authenticated user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<GitHubCommitIdentity>,
+    /// Author identity (optional, defaults to the committer)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<GitHubCommitIdentity>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_delete_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubDeleteFileOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub path: String,
+    pub commit: GitHubContentCommit,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Delete a file from a repository (requires its current SHA)"
+)]
+impl ToolArgs for DeleteFileArgs {
+    type Output = GitHubDeleteFileOutput;
+    type Prompts = GithubDeleteFilePrompts;
+
+    const NAME: &'static str = GITHUB_DELETE_FILE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Delete a file from a repository (requires its current SHA)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
+}
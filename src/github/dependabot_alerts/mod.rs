@@ -1,7 +1,9 @@
 //! GitHub Dependabot alerts tool schema and prompts
 
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::*;
 pub use prompts::*;
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_dependabot_alerts` prompts
+    pub enum GithubDependabotAlertsScenario {
+        /// Listing alerts
+        Basic => "basic",
+        /// Filter by severity
+        Filtering => "filtering",
+        /// Fixing vulnerabilities
+        Remediation => "remediation",
+        /// Security workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_dependabot_alerts tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubDependabotAlertsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Listing alerts
-    /// - "filtering": Filter by severity
-    /// - "remediation": Fixing vulnerabilities
-    /// - "workflows": Security workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubDependabotAlertsScenario>,
 }
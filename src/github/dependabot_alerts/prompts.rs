@@ -1,8 +1,8 @@
 //! Prompt messages for github_dependabot_alerts tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubDependabotAlertsPromptArgs;
+use super::prompt_args::{GithubDependabotAlertsPromptArgs, GithubDependabotAlertsScenario};
 
 /// Prompt provider for github_dependabot_alerts tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubDependabotAlertsPrompts {
     type PromptArgs = GithubDependabotAlertsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
-            Some("remediation") => prompt_remediation(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubDependabotAlertsScenario::Basic) => prompt_basic(),
+            Some(GithubDependabotAlertsScenario::Filtering) => prompt_filtering(),
+            Some(GithubDependabotAlertsScenario::Remediation) => prompt_remediation(),
+            Some(GithubDependabotAlertsScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering, remediation, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubDependabotAlertsScenario::prompt_argument()
         ]
     }
 }
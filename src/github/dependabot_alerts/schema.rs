@@ -0,0 +1,108 @@
+//! Schema types for dependabot_alerts tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::CATEGORY_GITHUB;
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::GITHUB_DEPENDABOT_ALERTS;
+use super::prompts::GithubDependabotAlertsPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `dependabot_alerts` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DependabotAlertsArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Fetch a single alert by number (other filters are ignored)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_number: Option<u64>,
+    /// Filter by state: "open", "fixed", "dismissed", or "auto_dismissed" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Filter by severity: "critical", "high", "medium", or "low" (optional, comma-separated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    /// Filter by ecosystem: "npm", "pip", "cargo", "maven", etc. (optional, comma-separated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ecosystem: Option<String>,
+    /// Filter by package name (optional, comma-separated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// Filter by manifest path (optional, comma-separated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+    /// Filter by dependency scope: "development" or "runtime" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Sort by: "created" or "updated" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Direction: "asc" or "desc" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    /// Page number for pagination (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Results per page, max 100 (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_dependabot_alerts` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubDependabotAlertsOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub count: usize,
+    pub alerts: Vec<GitHubDependabotAlert>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubDependabotAlert {
+    pub number: u64,
+    pub state: String,
+    pub package: String,
+    pub ecosystem: String,
+    pub manifest_path: String,
+    pub scope: Option<String>,
+    pub severity: String,
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub summary: String,
+    pub vulnerable_version_range: String,
+    /// Lowest version that fixes the vulnerability (None if no fix is released)
+    pub first_patched_version: Option<String>,
+    pub html_url: String,
+    pub created_at: String,
+    pub dismissed_reason: Option<String>,
+    pub fixed_at: Option<String>,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Get Dependabot vulnerability alerts for a repository"
+)]
+impl ToolArgs for DependabotAlertsArgs {
+    type Output = GitHubDependabotAlertsOutput;
+    type Prompts = GithubDependabotAlertsPrompts;
+
+    const NAME: &'static str = GITHUB_DEPENDABOT_ALERTS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Get Dependabot vulnerability alerts for a repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
+}
@@ -3,13 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_fork_repo` prompts
+    pub enum GitHubForkRepositoryScenario {
+        /// Simple forking
+        Basic => "basic",
+        /// Fork to org
+        Organization => "organization",
+    }
+}
+
 /// Prompt arguments for github_fork_repo tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GitHubForkRepositoryPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple forking
-    /// - "organization": Fork to org
-    /// - "workflows": Fork contribution workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GitHubForkRepositoryScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_fork_repository tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GitHubForkRepositoryPromptArgs;
+use super::prompt_args::{GitHubForkRepositoryPromptArgs, GitHubForkRepositoryScenario};
 
 /// Prompt provider for fork_repository tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ForkRepositoryPrompts {
     type PromptArgs = GitHubForkRepositoryPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("organization") => prompt_organization(),
+        match args.scenario {
+            Some(GitHubForkRepositoryScenario::Basic) => prompt_basic(),
+            Some(GitHubForkRepositoryScenario::Organization) => prompt_organization(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, organization)".to_string()),
-                required: Some(false),
-            }
+            GitHubForkRepositoryScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_FORK_REPOSITORY};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ForkRepositoryPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -54,4 +55,5 @@ impl ToolArgs for ForkRepositoryArgs {
     const NAME: &'static str = GITHUB_FORK_REPOSITORY;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Fork a repository to your account or organization";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
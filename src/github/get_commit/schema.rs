@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_COMMIT};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetCommitPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -88,4 +89,5 @@ impl ToolArgs for GetCommitArgs {
     const NAME: &'static str = GITHUB_GET_COMMIT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get details about a specific commit";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_file_contents` prompts
+    pub enum GetFileContentsScenario {
+        /// Reading file contents
+        Files => "files",
+        /// Reading from branches
+        Branches => "branches",
+    }
+}
+
 /// Prompt arguments for github_get_file_contents tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetFileContentsPromptArgs {
-    /// Scenario to show examples for
-    /// - "files": Reading file contents
-    /// - "directories": Listing directories
-    /// - "branches": Reading from branches
-    /// - "workflows": Content workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetFileContentsScenario>,
 }
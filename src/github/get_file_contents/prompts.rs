@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_file_contents tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetFileContentsPromptArgs;
+use super::prompt_args::{GetFileContentsPromptArgs, GetFileContentsScenario};
 
 /// Prompt provider for get_file_contents tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for GetFileContentsPrompts {
     type PromptArgs = GetFileContentsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("files") => prompt_files(),
-            Some("branches") => prompt_branches(),
+        match args.scenario {
+            Some(GetFileContentsScenario::Files) => prompt_files(),
+            Some(GetFileContentsScenario::Branches) => prompt_branches(),
             _ => prompt_files(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (files, branches)".to_string()),
-                required: Some(false),
-            }
+            GetFileContentsScenario::prompt_argument()
         ]
     }
 }
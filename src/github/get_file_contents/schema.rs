@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_FILE_CONTENTS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetFileContentsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -79,4 +80,5 @@ impl ToolArgs for GetFileContentsArgs {
     const NAME: &'static str = GITHUB_GET_FILE_CONTENTS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get file or directory contents from a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_issue` prompts
+    pub enum GetIssueScenario {
+        /// Getting issue details
+        Basic => "basic",
+        /// Labels, assignees, milestone
+        Metadata => "metadata",
+    }
+}
+
 /// Prompt arguments for github_get_issue tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetIssuePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Getting issue details
-    /// - "metadata": Labels, assignees, milestone
-    /// - "comments": Getting issue comments
-    /// - "workflows": Issue investigation workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetIssueScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_issue tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetIssuePromptArgs;
+use super::prompt_args::{GetIssuePromptArgs, GetIssueScenario};
 
 /// Prompt provider for get_issue tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for GetIssuePrompts {
     type PromptArgs = GetIssuePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("metadata") => prompt_metadata(),
+        match args.scenario {
+            Some(GetIssueScenario::Basic) => prompt_basic(),
+            Some(GetIssueScenario::Metadata) => prompt_metadata(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, metadata)".to_string()),
-                required: Some(false),
-            }
+            GetIssueScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_ISSUE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetIssuePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -66,4 +67,5 @@ impl ToolArgs for GetIssueArgs {
     const NAME: &'static str = GITHUB_GET_ISSUE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get details about a specific GitHub issue";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
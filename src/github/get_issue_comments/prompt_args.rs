@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_issue_comments` prompts
+    pub enum GetIssueCommentsScenario {
+        /// Simple comment retrieval (default)
+        Basic => "basic",
+        /// Handling pagination and large comment threads
+        Pagination => "pagination",
+    }
+}
+
 /// Prompt arguments for github_get_issue_comments tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetIssueCommentsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple comment retrieval
-    /// - "pagination": Handling pagination and large comment threads
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetIssueCommentsScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_issue_comments tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetIssueCommentsPromptArgs;
+use super::prompt_args::{GetIssueCommentsPromptArgs, GetIssueCommentsScenario};
 
 /// Prompt provider for get_issue_comments tool
 pub struct GetIssueCommentsPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for GetIssueCommentsPrompts {
     type PromptArgs = GetIssueCommentsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("pagination") => prompt_pagination(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GetIssueCommentsScenario::Pagination) => prompt_pagination(),
+            Some(GetIssueCommentsScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, pagination)".to_string()),
-                required: Some(false),
-            }
+            GetIssueCommentsScenario::prompt_argument()
         ]
     }
 }
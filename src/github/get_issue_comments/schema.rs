@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_ISSUE_COMMENTS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetIssueCommentsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -60,4 +61,5 @@ impl ToolArgs for GetIssueCommentsArgs {
     const NAME: &'static str = GITHUB_GET_ISSUE_COMMENTS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get all comments on a GitHub issue";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
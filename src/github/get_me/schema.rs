@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_ME};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetMePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -54,4 +55,5 @@ impl ToolArgs for GetMeArgs {
     const NAME: &'static str = GITHUB_GET_ME;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get authenticated user information";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_pr` prompts
+    pub enum GithubGetPrScenario {
+        /// Getting PR details
+        Basic => "basic",
+        /// Merge and review status
+        Status => "status",
+        /// PR changes info
+        Changes => "changes",
+        /// PR review workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_get_pr tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubGetPrPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Getting PR details
-    /// - "status": Merge and review status
-    /// - "changes": PR changes info
-    /// - "workflows": PR review workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubGetPrScenario>,
 }
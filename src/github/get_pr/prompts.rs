@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_pr tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubGetPrPromptArgs;
+use super::prompt_args::{GithubGetPrPromptArgs, GithubGetPrScenario};
 
 /// Prompt provider for github_get_pr tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubGetPrPrompts {
     type PromptArgs = GithubGetPrPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("status") => prompt_status(),
-            Some("changes") => prompt_changes(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubGetPrScenario::Basic) => prompt_basic(),
+            Some(GithubGetPrScenario::Status) => prompt_status(),
+            Some(GithubGetPrScenario::Changes) => prompt_changes(),
+            Some(GithubGetPrScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, status, changes, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubGetPrScenario::prompt_argument()
         ]
     }
 }
@@ -344,7 +339,7 @@ fn prompt_changes() -> Vec<PromptMessage> {
                       \"owner\": \"user\",\n\
                       \"repo\": \"project\",\n\
                       \"path\": \"src/app.js\",\n\
-                      \"ref\": \"feature-branch\"  // Use head.ref from PR\n\
+                      \"ref_name\": \"feature-branch\"  // Use head.ref from PR\n\
                     })\n\n\
                  ANALYZING CHANGE IMPACT:\n\
                  1. Check number of commits:\n\
@@ -0,0 +1,55 @@
+//! Schema types for get_pr tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::CATEGORY_GITHUB;
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::{GITHUB_GET_PR, GitHubPullRequest};
+use super::prompts::GithubGetPrPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `get_pr` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetPrArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Pull request number
+    pub pull_number: u64,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_get_pr` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubGetPrOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub pull_request: GitHubPullRequest,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Get full details of a pull request"
+)]
+impl ToolArgs for GetPrArgs {
+    type Output = GitHubGetPrOutput;
+    type Prompts = GithubGetPrPrompts;
+
+    const NAME: &'static str = GITHUB_GET_PR;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Get full details of a pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
+}
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_pull_request_files` prompts
+    pub enum GetPullRequestFilesScenario {
+        /// Getting changed files in a PR
+        Basic => "basic",
+        /// Analyzing PR changes for complexity and impact
+        Analysis => "analysis",
+    }
+}
+
 /// Prompt arguments for github_get_pull_request_files tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetPullRequestFilesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Getting changed files in a PR
-    /// - "analysis": Analyzing PR changes for complexity and impact
-    /// - "workflows": Complete code review workflows
-    /// - "collaboration": Team review and commenting patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetPullRequestFilesScenario>,
 }
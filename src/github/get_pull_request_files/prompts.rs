@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_pull_request_files tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetPullRequestFilesPromptArgs;
+use super::prompt_args::{GetPullRequestFilesPromptArgs, GetPullRequestFilesScenario};
 
 /// Prompt provider for get_pull_request_files tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for GetPullRequestFilesPrompts {
     type PromptArgs = GetPullRequestFilesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("analysis") => prompt_analysis(),
+        match args.scenario {
+            Some(GetPullRequestFilesScenario::Basic) => prompt_basic(),
+            Some(GetPullRequestFilesScenario::Analysis) => prompt_analysis(),
             _ => prompt_analysis(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, analysis)".to_string()),
-                required: Some(false),
-            }
+            GetPullRequestFilesScenario::prompt_argument()
         ]
     }
 }
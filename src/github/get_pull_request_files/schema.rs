@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_PULL_REQUEST_FILES};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetPullRequestFilesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -61,4 +62,5 @@ impl ToolArgs for GetPullRequestFilesArgs {
     const NAME: &'static str = GITHUB_GET_PULL_REQUEST_FILES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get all files changed in a pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,8 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_pull_request_reviews` prompts
+    pub enum GetPullRequestReviewsScenario {
+        /// Basic review retrieval and usage (default)
+        Basic => "basic",
+        /// Advanced review analysis for automation
+        ReviewAnalysis => "review_analysis",
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetPullRequestReviewsPromptArgs {
-    /// Scenario to display: "basic" (default) or "review_analysis"
-    pub scenario: Option<String>,
+    /// Scenario to show examples for (omit for the default prompt)
+    pub scenario: Option<GetPullRequestReviewsScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_pull_request_reviews tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetPullRequestReviewsPromptArgs;
+use super::prompt_args::{GetPullRequestReviewsPromptArgs, GetPullRequestReviewsScenario};
 
 /// Prompt provider for get_pull_request_reviews tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for GetPullRequestReviewsPrompts {
     type PromptArgs = GetPullRequestReviewsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("review_analysis") => Self::prompt_review_analysis(),
-            _ => Self::prompt_basic(),
+        match args.scenario {
+            Some(GetPullRequestReviewsScenario::ReviewAnalysis) => Self::prompt_review_analysis(),
+            Some(GetPullRequestReviewsScenario::Basic) | None => Self::prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, review_analysis)".to_string()),
-                required: Some(false),
-            }
+            GetPullRequestReviewsScenario::prompt_argument()
         ]
     }
 }
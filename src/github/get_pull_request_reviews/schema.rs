@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_PULL_REQUEST_REVIEWS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetPullRequestReviewsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -59,4 +60,5 @@ impl ToolArgs for GetPullRequestReviewsArgs {
     const NAME: &'static str = GITHUB_GET_PULL_REQUEST_REVIEWS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get pull request reviews";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
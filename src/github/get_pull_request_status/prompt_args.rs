@@ -3,12 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_get_pull_request_status` prompts
+    pub enum GetPullRequestStatusScenario {
+        /// Retrieving pull request status and checks (default)
+        Basic => "basic",
+        /// Evaluating merge readiness and workflows
+        MergeDecision => "merge_decision",
+    }
+}
+
 /// Prompt arguments for github_get_pull_request_status tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetPullRequestStatusPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Retrieving pull request status and checks
-    /// - "merge_decision": Evaluating merge readiness and workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetPullRequestStatusScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_get_pull_request_status tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetPullRequestStatusPromptArgs;
+use super::prompt_args::{GetPullRequestStatusPromptArgs, GetPullRequestStatusScenario};
 
 /// Prompt provider for get_pull_request_status tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for GetPullRequestStatusPrompts {
     type PromptArgs = GetPullRequestStatusPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("merge_decision") => Self::prompt_merge_decision(),
-            _ => Self::prompt_basic(),
+        match args.scenario {
+            Some(GetPullRequestStatusScenario::MergeDecision) => Self::prompt_merge_decision(),
+            Some(GetPullRequestStatusScenario::Basic) | None => Self::prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, merge_decision)".to_string()),
-                required: Some(false),
-            }
+            GetPullRequestStatusScenario::prompt_argument()
         ]
     }
 }
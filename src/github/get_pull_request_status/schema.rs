@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_GET_PULL_REQUEST_STATUS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::GetPullRequestStatusPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -55,4 +56,5 @@ impl ToolArgs for GetPullRequestStatusArgs {
     const NAME: &'static str = GITHUB_GET_PULL_REQUEST_STATUS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Get pull request status";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_branches` prompts
+    pub enum ListBranchesScenario {
+        /// Simple branch listing
+        Basic => "basic",
+        /// Handling large branch lists
+        Pagination => "pagination",
+    }
+}
+
 /// Prompt arguments for github_list_branches tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListBranchesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple branch listing
-    /// - "protection": Protected branches
-    /// - "workflows": Branch management workflows
-    /// - "pagination": Handling large branch lists
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ListBranchesScenario>,
 }
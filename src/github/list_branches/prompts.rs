@@ -1,8 +1,8 @@
 //! Prompt messages for github_list_branches tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ListBranchesPromptArgs;
+use super::prompt_args::{ListBranchesPromptArgs, ListBranchesScenario};
 
 /// Prompt provider for list_branches tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ListBranchesPrompts {
     type PromptArgs = ListBranchesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("pagination") => prompt_pagination(),
+        match args.scenario {
+            Some(ListBranchesScenario::Basic) => prompt_basic(),
+            Some(ListBranchesScenario::Pagination) => prompt_pagination(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, pagination)".to_string()),
-                required: Some(false),
-            }
+            ListBranchesScenario::prompt_argument()
         ]
     }
 }
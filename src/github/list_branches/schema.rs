@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_LIST_BRANCHES};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ListBranchesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -61,4 +62,5 @@ impl ToolArgs for ListBranchesArgs {
     const NAME: &'static str = GITHUB_LIST_BRANCHES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "List branches in a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
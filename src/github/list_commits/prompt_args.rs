@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_commits` prompts
+    pub enum ListCommitsScenario {
+        /// Simple commit listing
+        Basic => "basic",
+        /// Filter by author, path, date
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for github_list_commits tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListCommitsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple commit listing
-    /// - "filtering": Filter by author, path, date
-    /// - "branches": Commits on branches
-    /// - "workflows": Commit analysis workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ListCommitsScenario>,
 }
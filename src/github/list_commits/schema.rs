@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_LIST_COMMITS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ListCommitsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -79,4 +80,5 @@ impl ToolArgs for ListCommitsArgs {
     const NAME: &'static str = GITHUB_LIST_COMMITS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "List commits in a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
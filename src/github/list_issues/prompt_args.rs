@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_issues` prompts
+    pub enum GithubListIssuesScenario {
+        /// Simple issue listing
+        Basic => "basic",
+        /// Filter by state, labels
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for github_list_issues tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubListIssuesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple issue listing
-    /// - "filtering": Filter by state, labels
-    /// - "sorting": Sort options
-    /// - "workflows": Issue management workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubListIssuesScenario>,
 }
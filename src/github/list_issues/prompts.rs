@@ -1,8 +1,8 @@
 //! Prompt messages for github_list_issues tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubListIssuesPromptArgs;
+use super::prompt_args::{GithubListIssuesPromptArgs, GithubListIssuesScenario};
 
 /// Prompt provider for github_list_issues tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for ListIssuesPrompts {
     type PromptArgs = GithubListIssuesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
+        match args.scenario {
+            Some(GithubListIssuesScenario::Basic) => prompt_basic(),
+            Some(GithubListIssuesScenario::Filtering) => prompt_filtering(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering)".to_string()),
-                required: Some(false),
-            }
+            GithubListIssuesScenario::prompt_argument()
         ]
     }
 }
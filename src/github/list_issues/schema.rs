@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_LIST_ISSUES};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ListIssuesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -73,4 +74,5 @@ impl ToolArgs for ListIssuesArgs {
     const NAME: &'static str = GITHUB_LIST_ISSUES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "List issues in a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_pull_requests` prompts
+    pub enum ListPullRequestsScenario {
+        /// Simple PR listing (default)
+        Basic => "basic",
+        /// Filter by state, branch
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for github_list_pull_requests tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListPullRequestsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple PR listing
-    /// - "filtering": Filter by state, branch
-    /// - "review": Review-focused listing
-    /// - "workflows": PR management workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ListPullRequestsScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_list_pull_requests tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ListPullRequestsPromptArgs;
+use super::prompt_args::{ListPullRequestsPromptArgs, ListPullRequestsScenario};
 
 /// Prompt provider for github_list_pull_requests tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for ListPullRequestsPrompts {
     type PromptArgs = ListPullRequestsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("filtering") => prompt_filtering(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(ListPullRequestsScenario::Filtering) => prompt_filtering(),
+            Some(ListPullRequestsScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering)".to_string()),
-                required: Some(false),
-            }
+            ListPullRequestsScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_LIST_PULL_REQUESTS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ListPullRequestsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -72,4 +73,5 @@ impl ToolArgs for ListPullRequestsArgs {
     const NAME: &'static str = GITHUB_LIST_PULL_REQUESTS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "List pull requests in a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_releases` prompts
+    pub enum ListReleasesScenario {
+        /// Simple release listing
+        Basic => "basic",
+        /// Release details
+        Details => "details",
+        /// Release management workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_list_releases tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListReleasesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple release listing
-    /// - "details": Release details
-    /// - "workflows": Release management workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ListReleasesScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_list_releases tool
 
-use crate::tool::{PromptProvider, SealedPromptProvider};
+use crate::tool::{PromptProvider, PromptScenario, SealedPromptProvider};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ListReleasesPromptArgs;
+use super::prompt_args::{ListReleasesPromptArgs, ListReleasesScenario};
 
 /// Prompt provider for list_releases tool
 ///
@@ -17,22 +17,17 @@ impl PromptProvider for ListReleasesPrompts {
     type PromptArgs = ListReleasesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("details") => prompt_details(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(ListReleasesScenario::Basic) => prompt_basic(),
+            Some(ListReleasesScenario::Details) => prompt_details(),
+            Some(ListReleasesScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, details, workflows)".to_string()),
-                required: Some(false),
-            }
+            ListReleasesScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,61 @@
+//! Schema types for list_releases tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::CATEGORY_GITHUB;
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::{GITHUB_LIST_RELEASES, GitHubRelease};
+use super::prompts::ListReleasesPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `list_releases` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListReleasesArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Page number for pagination (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Results per page, max 100 (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_list_releases` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubListReleasesOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub count: usize,
+    /// Releases, newest first (drafts only visible with push access)
+    pub releases: Vec<GitHubRelease>,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "List releases in a GitHub repository, newest first"
+)]
+impl ToolArgs for ListReleasesArgs {
+    type Output = GitHubListReleasesOutput;
+    type Prompts = ListReleasesPrompts;
+
+    const NAME: &'static str = GITHUB_LIST_RELEASES;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "List releases in a GitHub repository, newest first";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
+}
@@ -1,7 +1,9 @@
 //! github_list_repos tool module
 
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::GithubListReposPromptArgs;
 pub use prompts::GithubListReposPrompts;
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_list_repos` prompts
+    pub enum GithubListReposScenario {
+        /// User repositories
+        User => "user",
+        /// Org repositories
+        Organization => "organization",
+        /// Sort options
+        Sorting => "sorting",
+        /// Repo discovery workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_list_repos tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubListReposPromptArgs {
-    /// Scenario to show examples for
-    /// - "user": User repositories
-    /// - "organization": Org repositories
-    /// - "sorting": Sort options
-    /// - "workflows": Repo discovery workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubListReposScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_list_repos tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubListReposPromptArgs;
+use super::prompt_args::{GithubListReposPromptArgs, GithubListReposScenario};
 
 /// Prompt provider for list_repos tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubListReposPrompts {
     type PromptArgs = GithubListReposPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("user") => prompt_user(),
-            Some("organization") => prompt_organization(),
-            Some("sorting") => prompt_sorting(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubListReposScenario::User) => prompt_user(),
+            Some(GithubListReposScenario::Organization) => prompt_organization(),
+            Some(GithubListReposScenario::Sorting) => prompt_sorting(),
+            Some(GithubListReposScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (user, organization, sorting, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubListReposScenario::prompt_argument()
         ]
     }
 }
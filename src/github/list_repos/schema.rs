@@ -0,0 +1,77 @@
+//! Schema types for list_repos tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_LIST_REPOS};
+
+use crate::{ToolArgs, ValidateArgs, tool_metadata};
+use crate::github::GitHubRepoSearchResult;
+use super::prompts::GithubListReposPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `list_repos` tool
+///
+/// Lists the authenticated user's repositories when neither `username` nor `org` is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, ValidateArgs)]
+pub struct ListReposArgs {
+    /// List repositories of this user (optional)
+    #[exclusive_with(org)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// List repositories of this organization (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+    /// Type filter: "all", "owner", "public", "private", "member" (users)
+    /// or "all", "public", "private", "forks", "sources", "member" (orgs) (optional)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    /// Sort by: "created", "updated", "pushed", "full_name" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// Direction: "asc" or "desc" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<String>,
+    /// Page number for pagination (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Results per page, max 100 (optional)
+    #[range(min = 1, max = 100)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_list_repos` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubListReposOutput {
+    pub success: bool,
+    /// User or organization listed (None for the authenticated user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    pub count: usize,
+    pub repos: Vec<GitHubRepoSearchResult>,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "List repositories of a user, an organization, or the authenticated user"
+)]
+impl ToolArgs for ListReposArgs {
+    type Output = GitHubListReposOutput;
+    type Prompts = GithubListReposPrompts;
+
+    const NAME: &'static str = GITHUB_LIST_REPOS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "List repositories of a user, an organization, or the authenticated user";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
+}
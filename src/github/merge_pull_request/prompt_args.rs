@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_merge_pr` prompts
+    pub enum MergePullRequestScenario {
+        /// Simple PR merging (default)
+        Basic => "basic",
+        /// Merge strategies
+        Strategies => "strategies",
+        /// Complete merge workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_merge_pr tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MergePullRequestPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple PR merging
-    /// - "strategies": Merge strategies
-    /// - "messages": Commit messages
-    /// - "workflows": Complete merge workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<MergePullRequestScenario>,
 }
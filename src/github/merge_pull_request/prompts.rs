@@ -1,8 +1,8 @@
 //! Prompt messages for github_merge_pr tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::MergePullRequestPromptArgs;
+use super::prompt_args::{MergePullRequestPromptArgs, MergePullRequestScenario};
 
 /// Prompt provider for merge_pull_request tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for MergePullRequestPrompts {
     type PromptArgs = MergePullRequestPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("strategies") => prompt_strategies(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(MergePullRequestScenario::Strategies) => prompt_strategies(),
+            Some(MergePullRequestScenario::Workflows) => prompt_workflows(),
+            Some(MergePullRequestScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, strategies, workflows)".to_string()),
-                required: Some(false),
-            }
+            MergePullRequestScenario::prompt_argument()
         ]
     }
 }
@@ -273,7 +268,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                     github_delete_branch({\n\
                         \"owner\": \"user\",\n\
                         \"repo\": \"project\",\n\
-                        \"branch\": \"feature/dark-mode\"\n\
+                        \"branch_name\": \"feature/dark-mode\"\n\
                     })\n\n\
                  2. PRODUCTION MERGE WITH VERIFICATION:\n\
                     // Step 1: Get PR details\n\
@@ -314,7 +309,7 @@ fn prompt_workflows() -> Vec<PromptMessage> {
                     github_delete_branch({\n\
                         \"owner\": \"company\",\n\
                         \"repo\": \"api\",\n\
-                        \"branch\": \"feature/payment-api\"\n\
+                        \"branch_name\": \"feature/payment-api\"\n\
                     })\n\n\
 WORKFLOW DECISION TREE:\n\n\
                  Feature PR:\n\
@@ -341,7 +336,7 @@ WORKFLOW DECISION TREE:\n\n\
                  github_delete_branch({\n\
                      \"owner\": \"user\",\n\
                      \"repo\": \"project\",\n\
-                     \"branch\": \"feature/branch-name\"\n\
+                     \"branch_name\": \"feature/branch-name\"\n\
                  })\n\
                  Exception: Keep release branches\n\n\
                  AUTO-DELETE CONFIGURATION:\n\
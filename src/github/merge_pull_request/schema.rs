@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_MERGE_PULL_REQUEST};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::MergePullRequestPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -64,4 +65,5 @@ impl ToolArgs for MergePullRequestArgs {
     const NAME: &'static str = GITHUB_MERGE_PULL_REQUEST;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Merge a pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.open_world();
 }
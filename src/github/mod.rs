@@ -16,6 +16,18 @@ pub use kodegen_config::{
     GITHUB_UPDATE_ISSUE, GITHUB_UPDATE_PULL_REQUEST,
 };
 
+// TEMPORARY SHIM: kodegen_config (0.10.11) does not define these tool names
+// yet. Once it does, add them to the re-export above and delete this block;
+// do not add new names here.
+pub const GITHUB_DEPENDABOT_ALERTS: &str = "github_dependabot_alerts";
+pub const GITHUB_GET_PR: &str = "github_get_pr";
+pub const GITHUB_LIST_RELEASES: &str = "github_list_releases";
+pub const GITHUB_UPDATE_FILE: &str = "github_update_file";
+
+// Types shared by several tools
+pub mod shared;
+pub use shared::*;
+
 // Search operations
 pub mod search_code;
 pub mod search_repositories;
@@ -28,6 +40,7 @@ pub mod create_repository;
 pub mod fork_repository;
 pub mod get_file_contents;
 pub mod create_or_update_file;
+pub mod update_file;
 pub mod delete_file;
 pub mod push_file;
 pub mod push_files;
@@ -54,6 +67,7 @@ pub mod add_issue_comment;
 pub mod list_pull_requests;
 pub mod create_pull_request;
 pub mod update_pull_request;
+pub mod get_pr;
 pub mod merge_pull_request;
 pub mod get_pull_request_status;
 pub mod get_pull_request_files;
@@ -65,6 +79,7 @@ pub mod request_copilot_review;
 // Security operations
 pub mod code_scanning_alerts;
 pub mod secret_scanning_alerts;
+pub mod dependabot_alerts;
 
 // User & commit operations
 pub mod get_me;
@@ -113,6 +128,8 @@ pub use search_users::{
 // Repository operations
 // Re-export list_repos tool
 pub use list_repos::{
+    ListReposArgs,
+    GitHubListReposOutput,
     GithubListReposPromptArgs,
     GithubListReposPrompts,
 };
@@ -148,14 +165,26 @@ pub use create_or_update_file::{
     CreateOrUpdateFilePrompts,
 };
 
+// Re-export update_file tool
+pub use update_file::{
+    UpdateFileArgs,
+    GitHubUpdateFileOutput,
+    GithubUpdateFilePromptArgs,
+    GithubUpdateFilePrompts,
+};
+
 // Re-export delete_file tool
 pub use delete_file::{
+    DeleteFileArgs,
+    GitHubDeleteFileOutput,
     GithubDeleteFilePromptArgs,
     GithubDeleteFilePrompts,
 };
 
 // Re-export push_file tool
 pub use push_file::{
+    PushFileArgs,
+    GitHubPushFileOutput,
     GithubPushFilePromptArgs,
     GithubPushFilePrompts,
 };
@@ -277,6 +306,14 @@ pub use update_pull_request::{
     UpdatePullRequestPrompts,
 };
 
+// Re-export get_pr tool
+pub use get_pr::{
+    GetPrArgs,
+    GitHubGetPrOutput,
+    GithubGetPrPromptArgs,
+    GithubGetPrPrompts,
+};
+
 // Re-export merge_pull_request tool
 pub use merge_pull_request::{
     MergePullRequestArgs,
@@ -349,6 +386,15 @@ pub use secret_scanning_alerts::{
     SecretScanningAlertsPrompts,
 };
 
+// Re-export dependabot_alerts tool
+pub use dependabot_alerts::{
+    DependabotAlertsArgs,
+    GitHubDependabotAlertsOutput,
+    GitHubDependabotAlert,
+    GithubDependabotAlertsPromptArgs,
+    GithubDependabotAlertsPrompts,
+};
+
 // User & commit operations
 // Re-export get_me tool
 pub use get_me::{
@@ -371,12 +417,16 @@ pub use get_commit::{
 // Release operations
 // Re-export create_release tool
 pub use create_release::{
+    CreateReleaseArgs,
+    GitHubCreateReleaseOutput,
     GithubCreateReleasePromptArgs,
     GithubCreateReleasePrompts,
 };
 
 // Re-export list_releases tool
 pub use list_releases::{
+    ListReleasesArgs,
+    GitHubListReleasesOutput,
     ListReleasesPromptArgs,
     ListReleasesPrompts,
 };
@@ -384,12 +434,16 @@ pub use list_releases::{
 // Collaboration operations
 // Re-export accept_repo_invitation tool
 pub use accept_repo_invitation::{
+    AcceptRepoInvitationArgs,
+    GitHubAcceptRepoInvitationOutput,
     GithubAcceptRepoInvitationPromptArgs,
     GithubAcceptRepoInvitationPrompts,
 };
 
 // Re-export pending_invitations tool
 pub use pending_invitations::{
+    PendingInvitationsArgs,
+    GitHubPendingInvitationsOutput,
     GithubPendingInvitationsPromptArgs,
     GithubPendingInvitationsPrompts,
 };
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_pending_invitations` prompts
+    pub enum GithubPendingInvitationsScenario {
+        /// Listing invitations
+        Basic => "basic",
+        /// Invitation management workflows
+        Workflows => "workflows",
+        /// Filtering and organizing invitations
+        Filtering => "filtering",
+        /// Integration with other GitHub tools
+        Integration => "integration",
+    }
+}
+
 /// Prompt arguments for github_pending_invitations tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubPendingInvitationsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Listing invitations
-    /// - "workflows": Invitation management workflows
-    /// - "filtering": Filtering and organizing invitations
-    /// - "integration": Integration with other GitHub tools
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubPendingInvitationsScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_pending_invitations tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubPendingInvitationsPromptArgs;
+use super::prompt_args::{GithubPendingInvitationsPromptArgs, GithubPendingInvitationsScenario};
 
 /// Prompt provider for pending_invitations tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubPendingInvitationsPrompts {
     type PromptArgs = GithubPendingInvitationsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("workflows") => prompt_workflows(),
-            Some("filtering") => prompt_filtering(),
-            Some("integration") => prompt_integration(),
+        match args.scenario {
+            Some(GithubPendingInvitationsScenario::Basic) => prompt_basic(),
+            Some(GithubPendingInvitationsScenario::Workflows) => prompt_workflows(),
+            Some(GithubPendingInvitationsScenario::Filtering) => prompt_filtering(),
+            Some(GithubPendingInvitationsScenario::Integration) => prompt_integration(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (basic, workflows, filtering, integration)".to_string()),
-                required: Some(false),
-            }
+            GithubPendingInvitationsScenario::prompt_argument()
         ]
     }
 }
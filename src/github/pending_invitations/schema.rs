@@ -0,0 +1,47 @@
+//! Schema types for pending_invitations tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_PENDING_INVITATIONS};
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::GitHubRepoInvitation;
+use super::prompts::GithubPendingInvitationsPrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `pending_invitations` tool (no parameters)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PendingInvitationsArgs {}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_pending_invitations` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubPendingInvitationsOutput {
+    pub success: bool,
+    pub count: usize,
+    pub invitations: Vec<GitHubRepoInvitation>,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "List pending repository invitations for the authenticated user"
+)]
+impl ToolArgs for PendingInvitationsArgs {
+    type Output = GitHubPendingInvitationsOutput;
+    type Prompts = GithubPendingInvitationsPrompts;
+
+    const NAME: &'static str = GITHUB_PENDING_INVITATIONS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "List pending repository invitations for the authenticated user";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
+}
@@ -1,7 +1,9 @@
 //! Prompt types for github_push_file tool
 
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::GithubPushFilePromptArgs;
 pub use prompts::GithubPushFilePrompts;
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_push_file` prompts
+    pub enum GithubPushFileScenario {
+        /// Basic file creation and updates
+        Basics => "basics",
+        /// Creating new files
+        Create => "create",
+        /// Pushing to branches
+        Branches => "branches",
+        /// File creation workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_push_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubPushFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "basics": Basic file creation and updates
-    /// - "create": Creating new files
-    /// - "branches": Pushing to branches
-    /// - "workflows": File creation workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubPushFileScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_push_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubPushFilePromptArgs;
+use super::prompt_args::{GithubPushFilePromptArgs, GithubPushFileScenario};
 
 /// Prompt provider for github_push_file tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubPushFilePrompts {
     type PromptArgs = GithubPushFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basics") => prompt_basics(),
-            Some("create") => prompt_create(),
-            Some("branches") => prompt_branches(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(GithubPushFileScenario::Basics) => prompt_basics(),
+            Some(GithubPushFileScenario::Create) => prompt_create(),
+            Some(GithubPushFileScenario::Branches) => prompt_branches(),
+            Some(GithubPushFileScenario::Workflows) => prompt_workflows(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basics, create, branches, workflows)".to_string()),
-                required: Some(false),
-            }
+            GithubPushFileScenario::prompt_argument()
         ]
     }
 }
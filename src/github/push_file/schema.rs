@@ -0,0 +1,66 @@
+//! Schema types for push_file tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_GITHUB, GITHUB_PUSH_FILE};
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::{GitHubContentCommit, GitHubContentFile};
+use super::prompts::GithubPushFilePrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `push_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PushFileArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// File path
+    pub path: String,
+    /// File content (plain text, will be base64 encoded automatically)
+    pub content: String,
+    /// Commit message
+    pub message: String,
+    /// Branch name (optional, defaults to default branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Current blob SHA when overwriting an existing file (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_push_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubPushFileOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub content: GitHubContentFile,
+    pub commit: GitHubContentCommit,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Push a single file to a repository in one commit"
+)]
+impl ToolArgs for PushFileArgs {
+    type Output = GitHubPushFileOutput;
+    type Prompts = GithubPushFilePrompts;
+
+    const NAME: &'static str = GITHUB_PUSH_FILE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Push a single file to a repository in one commit";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
+}
@@ -3,12 +3,20 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_push_files` prompts
+    pub enum PushFilesScenario {
+        /// Feature branch workflow with code generation
+        FeatureBranch => "feature_branch",
+    }
+}
+
 /// Prompt arguments for push_files tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PushFilesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic multi-file push to existing branch
-    /// - "feature_branch": Feature branch workflow with code generation
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<PushFilesScenario>,
 }
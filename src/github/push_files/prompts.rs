@@ -1,8 +1,8 @@
 //! Prompt messages for github_push_files tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::PushFilesPromptArgs;
+use super::prompt_args::{PushFilesPromptArgs, PushFilesScenario};
 
 /// Prompt provider for push_files tool
 ///
@@ -14,14 +14,14 @@ impl PromptProvider for PushFilesPrompts {
     type PromptArgs = PushFilesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("feature_branch") => prompt_feature_branch(),
+        match args.scenario {
+            Some(PushFilesScenario::FeatureBranch) => prompt_feature_branch(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![]  // No customization arguments for this tool
+        vec![PushFilesScenario::prompt_argument()]
     }
 }
 
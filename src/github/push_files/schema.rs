@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PushFilesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -58,4 +59,5 @@ impl ToolArgs for PushFilesArgs {
     const NAME: &'static str = GITHUB_PUSH_FILES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Push files to a GitHub repository";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_REQUEST_COPILOT_REVIEW};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::RequestCopilotReviewPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -50,4 +51,5 @@ impl ToolArgs for RequestCopilotReviewArgs {
     const NAME: &'static str = GITHUB_REQUEST_COPILOT_REVIEW;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Request Copilot review for pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.idempotent().open_world();
 }
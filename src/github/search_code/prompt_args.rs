@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_search_code` prompts
+    pub enum SearchCodeScenario {
+        /// Simple code search (default)
+        Basic => "basic",
+        /// Search syntax reference
+        Syntax => "syntax",
+        /// Research workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_search_code tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchCodePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple code search
-    /// - "syntax": Search syntax reference
-    /// - "patterns": Common search patterns
-    /// - "workflows": Research workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<SearchCodeScenario>,
 }
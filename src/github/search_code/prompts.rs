@@ -1,8 +1,8 @@
 //! Prompt messages for github_search_code tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::SearchCodePromptArgs;
+use super::prompt_args::{SearchCodePromptArgs, SearchCodeScenario};
 
 /// Prompt provider for search_code tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for SearchCodePrompts {
     type PromptArgs = SearchCodePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("syntax") => prompt_syntax(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(SearchCodeScenario::Syntax) => prompt_syntax(),
+            Some(SearchCodeScenario::Workflows) => prompt_workflows(),
+            Some(SearchCodeScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show: basic (default), syntax, workflows".to_string()),
-                required: Some(false),
-            }
+            SearchCodeScenario::prompt_argument()
         ]
     }
 }
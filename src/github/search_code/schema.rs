@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_SEARCH_CODE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SearchCodePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -74,4 +75,5 @@ impl ToolArgs for SearchCodeArgs {
     const NAME: &'static str = GITHUB_SEARCH_CODE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Search code across GitHub repositories using GitHub's code search syntax";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_search_issues` prompts
+    pub enum SearchIssuesScenario {
+        /// Simple issue search
+        Basic => "basic",
+        /// Search syntax reference
+        Syntax => "syntax",
+        /// Common search patterns
+        Patterns => "patterns",
+    }
+}
+
 /// Prompt arguments for github_search_issues tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchIssuesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple issue search
-    /// - "syntax": Search syntax reference
-    /// - "patterns": Common search patterns
-    /// - "workflows": Search workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<SearchIssuesScenario>,
 }
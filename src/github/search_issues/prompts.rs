@@ -1,8 +1,8 @@
 //! Prompt messages for github_search_issues tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::SearchIssuesPromptArgs;
+use super::prompt_args::{SearchIssuesPromptArgs, SearchIssuesScenario};
 
 /// Prompt provider for search_issues tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for SearchIssuesPrompts {
     type PromptArgs = SearchIssuesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("syntax") => prompt_syntax(),
-            Some("patterns") => prompt_patterns(),
+        match args.scenario {
+            Some(SearchIssuesScenario::Basic) => prompt_basic(),
+            Some(SearchIssuesScenario::Syntax) => prompt_syntax(),
+            Some(SearchIssuesScenario::Patterns) => prompt_patterns(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, syntax, patterns)".to_string()),
-                required: Some(false),
-            }
+            SearchIssuesScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_SEARCH_ISSUES};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SearchIssuesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -67,4 +68,5 @@ impl ToolArgs for SearchIssuesArgs {
     const NAME: &'static str = GITHUB_SEARCH_ISSUES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Search issues and pull requests across GitHub using query syntax";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
@@ -3,14 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_repo_search` prompts
+    pub enum SearchRepositoriesScenario {
+        /// Simple repo search (default)
+        Basic => "basic",
+        /// Search syntax reference
+        Syntax => "syntax",
+        /// Discovery workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_repo_search tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchRepositoriesPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple repo search
-    /// - "syntax": Search syntax reference
-    /// - "patterns": Common search patterns
-    /// - "workflows": Discovery workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<SearchRepositoriesScenario>,
 }
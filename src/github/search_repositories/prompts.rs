@@ -1,8 +1,8 @@
 //! Prompt messages for github_search_repositories tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::SearchRepositoriesPromptArgs;
+use super::prompt_args::{SearchRepositoriesPromptArgs, SearchRepositoriesScenario};
 
 /// Prompt provider for search_repositories tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for SearchRepositoriesPrompts {
     type PromptArgs = SearchRepositoriesPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("syntax") => prompt_syntax(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(SearchRepositoriesScenario::Syntax) => prompt_syntax(),
+            Some(SearchRepositoriesScenario::Workflows) => prompt_workflows(),
+            Some(SearchRepositoriesScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, syntax, workflows)".to_string()),
-                required: Some(false),
-            }
+            SearchRepositoriesScenario::prompt_argument()
         ]
     }
 }
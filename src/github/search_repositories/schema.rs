@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_SEARCH_REPOSITORIES};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SearchRepositoriesPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -77,4 +78,5 @@ impl ToolArgs for SearchRepositoriesArgs {
     const NAME: &'static str = GITHUB_SEARCH_REPOSITORIES;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Search repositories across GitHub using query syntax";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
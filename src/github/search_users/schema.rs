@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_SEARCH_USERS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SearchUsersPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -74,4 +75,5 @@ impl ToolArgs for SearchUsersArgs {
     const NAME: &'static str = GITHUB_SEARCH_USERS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Search users across GitHub using query syntax";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
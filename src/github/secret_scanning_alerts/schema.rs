@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_SECRET_SCANNING_ALERTS};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SecretScanningAlertsPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -67,4 +68,5 @@ impl ToolArgs for SecretScanningAlertsArgs {
     const NAME: &'static str = GITHUB_SECRET_SCANNING_ALERTS;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "List secret scanning alerts";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::READ_ONLY.open_world();
 }
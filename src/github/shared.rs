@@ -0,0 +1,125 @@
+//! Shared types for GitHub tools
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::GitHubPrSummary;
+
+// ============================================================================
+// RELEASES (create_release, list_releases)
+// ============================================================================
+
+/// Downloadable file attached to a release
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubReleaseAsset {
+    pub name: String,
+    pub size: u64,
+    pub download_count: u64,
+    pub browser_download_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+/// Release summary
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubRelease {
+    pub id: u64,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub author: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub published_at: Option<String>,
+    #[serde(default)]
+    pub assets: Vec<GitHubReleaseAsset>,
+}
+
+// ============================================================================
+// FILE COMMITS (push_file, update_file, delete_file)
+// ============================================================================
+
+/// Author or committer identity
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubCommitIdentity {
+    pub name: String,
+    pub email: String,
+    /// Commit date in ISO 8601 format (ignored when passed as an argument)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+/// Commit created by a contents API write
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubContentCommit {
+    pub sha: String,
+    pub message: String,
+    pub html_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<GitHubCommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<GitHubCommitIdentity>,
+}
+
+/// File metadata after a contents API write
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubContentFile {
+    pub name: String,
+    pub path: String,
+    /// New blob SHA (pass as `sha` for the next update)
+    pub sha: String,
+    pub size: u64,
+    pub html_url: String,
+}
+
+// ============================================================================
+// PULL REQUESTS (get_pr)
+// ============================================================================
+
+/// Full pull request details: the list summary plus merge state and diff stats
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubPullRequest {
+    #[serde(flatten)]
+    pub summary: GitHubPrSummary,
+    pub body: Option<String>,
+    pub html_url: String,
+    pub head_sha: String,
+    pub base_sha: String,
+    pub merged: bool,
+    /// None while GitHub is still computing mergeability
+    pub mergeable: Option<bool>,
+    /// "clean", "dirty", "blocked", "behind", "unstable" or "unknown"
+    pub mergeable_state: Option<String>,
+    pub updated_at: String,
+    pub merged_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub commits: u32,
+    pub additions: u32,
+    pub deletions: u32,
+    pub changed_files: u32,
+    pub comments: u32,
+    pub review_comments: u32,
+}
+
+// ============================================================================
+// INVITATIONS (pending_invitations, accept_repo_invitation)
+// ============================================================================
+
+/// Pending repository collaboration invitation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubRepoInvitation {
+    /// Invitation ID (pass to `github_accept_repo_invitation`)
+    pub id: u64,
+    /// Repository in "owner/repo" format
+    pub repository: String,
+    pub repository_private: bool,
+    pub repository_description: Option<String>,
+    /// Login of the user who sent the invitation
+    pub inviter: String,
+    /// Permission granted on acceptance: "read", "triage", "write", "maintain" or "admin"
+    pub permissions: String,
+    pub created_at: String,
+    pub html_url: String,
+}
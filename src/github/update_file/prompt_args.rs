@@ -3,15 +3,28 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_update_file` prompts
+    pub enum GithubUpdateFileScenario {
+        /// Complete guide to all features (default)
+        Comprehensive => "comprehensive",
+        /// Simple file update
+        Basic => "basic",
+        /// Read-modify-update workflow
+        Workflow => "workflow",
+        /// Updates on branches
+        Branches => "branches",
+        /// Batch updates, error handling, committer info
+        Advanced => "advanced",
+    }
+}
+
 /// Prompt arguments for github_update_file tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GithubUpdateFilePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple file update
-    /// - "workflow": Read-modify-update workflow
-    /// - "branches": Updates on branches
-    /// - "advanced": Batch updates, error handling, committer info
-    /// - "comprehensive": Complete guide to all features
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GithubUpdateFileScenario>,
 }
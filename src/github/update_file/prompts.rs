@@ -1,8 +1,8 @@
 //! Prompt messages for github_update_file tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GithubUpdateFilePromptArgs;
+use super::prompt_args::{GithubUpdateFilePromptArgs, GithubUpdateFileScenario};
 
 /// Prompt provider for github_update_file tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for GithubUpdateFilePrompts {
     type PromptArgs = GithubUpdateFilePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("workflow") => prompt_workflow(),
-            Some("branches") => prompt_branches(),
-            Some("advanced") => prompt_advanced(),
-            _ => prompt_comprehensive(),
+        match args.scenario {
+            Some(GithubUpdateFileScenario::Basic) => prompt_basic(),
+            Some(GithubUpdateFileScenario::Workflow) => prompt_workflow(),
+            Some(GithubUpdateFileScenario::Branches) => prompt_branches(),
+            Some(GithubUpdateFileScenario::Advanced) => prompt_advanced(),
+            Some(GithubUpdateFileScenario::Comprehensive) | None => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, workflow, branches, advanced, comprehensive)".to_string()),
-                required: Some(false),
-            }
+            GithubUpdateFileScenario::prompt_argument()
         ]
     }
 }
@@ -330,7 +325,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                      \"owner\": \"user\",\n\
                      \"repo\": \"project\",\n\
                      \"path\": \"config.json\",\n\
-                     \"ref\": \"develop\"  // Same branch you'll update\n\
+                     \"ref_name\": \"develop\"  // Same branch you'll update\n\
                  })\n\
                  \n\
                  // Step 2: Modify content\n\
@@ -353,7 +348,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                         \"owner\": \"team\",\n\
                         \"repo\": \"app\",\n\
                         \"path\": \"src/features.json\",\n\
-                        \"ref\": \"feature/new-ui\"\n\
+                        \"ref_name\": \"feature/new-ui\"\n\
                     })\n\
                     \n\
                     features = JSON.parse(file.content)\n\
@@ -374,7 +369,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                         \"owner\": \"company\",\n\
                         \"repo\": \"product\",\n\
                         \"path\": \"package.json\",\n\
-                        \"ref\": \"release/v2.0\"\n\
+                        \"ref_name\": \"release/v2.0\"\n\
                     })\n\
                     \n\
                     pkg = JSON.parse(version.content)\n\
@@ -395,7 +390,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                         \"owner\": \"org\",\n\
                         \"repo\": \"service\",\n\
                         \"path\": \"config/app.yml\",\n\
-                        \"ref\": \"develop\"\n\
+                        \"ref_name\": \"develop\"\n\
                     })\n\
                     \n\
                     config = parse_yaml(dev_config.content)\n\
@@ -419,7 +414,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                         \"owner\": \"team\",\n\
                         \"repo\": \"backend\",\n\
                         \"path\": \"src/auth.rs\",\n\
-                        \"ref\": \"main\"\n\
+                        \"ref_name\": \"main\"\n\
                     })\n\
                     \n\
                     fixed_code = fix_security_issue(bug_file.content)\n\
@@ -444,7 +439,7 @@ fn prompt_branches() -> Vec<PromptMessage> {
                          \"owner\": \"org\",\n\
                          \"repo\": \"repo\",\n\
                          \"path\": \"SECURITY.md\",\n\
-                         \"ref\": branch\n\
+                         \"ref_name\": branch\n\
                      })\n\
                      \n\
                      // Apply same update to all branches\n\
@@ -556,7 +551,7 @@ fn prompt_advanced() -> Vec<PromptMessage> {
                                  \"owner\": params.owner,\n\
                                  \"repo\": params.repo,\n\
                                  \"path\": params.path,\n\
-                                 \"ref\": params.branch\n\
+                                 \"ref_name\": params.branch\n\
                              })\n\
                              \n\
                              // Apply modification\n\
@@ -872,7 +867,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                      \"owner\": \"org\",\n\
                      \"repo\": \"repo\",\n\
                      \"path\": \"file.json\",\n\
-                     \"ref\": \"main\"  // Optional: specify branch\n\
+                     \"ref_name\": \"main\"  // Optional: specify branch\n\
                  })\n\
                  // Returns: {content, sha, size, ...}\n\
                  \n\
@@ -917,7 +912,7 @@ fn prompt_comprehensive() -> Vec<PromptMessage> {
                      \"owner\": \"user\",\n\
                      \"repo\": \"project\",\n\
                      \"path\": \"config.json\",\n\
-                     \"ref\": \"develop\"  // Read from same branch\n\
+                     \"ref_name\": \"develop\"  // Read from same branch\n\
                  })\n\
                  // Now file.sha is correct for develop branch\n\n\
                  BRANCH WORKFLOWS:\n\
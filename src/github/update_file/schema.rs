@@ -0,0 +1,71 @@
+//! Schema types for update_file tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::CATEGORY_GITHUB;
+
+use crate::{ToolArgs, tool_metadata};
+use crate::github::{GITHUB_UPDATE_FILE, GitHubContentCommit, GitHubContentFile};
+use super::prompts::GithubUpdateFilePrompts;
+use crate::tool::ToolBehavior;
+
+// ============================================================================
+// ARGS STRUCT
+// ============================================================================
+
+/// Arguments for `update_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateFileArgs {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Path of the existing file
+    pub path: String,
+    /// New file content (plain text, will be base64 encoded automatically)
+    pub content: String,
+    /// Commit message
+    pub message: String,
+    /// Current blob SHA of the file (from `github_get_file_contents`)
+    pub sha: String,
+    /// Branch name (optional, defaults to default branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Committer name (optional, defaults to the authenticated user)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer_name: Option<String>,
+    /// Committer email (optional, required if committer_name is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer_email: Option<String>,
+}
+
+// ============================================================================
+// OUTPUT STRUCT
+// ============================================================================
+
+/// Output from `github_update_file` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GitHubUpdateFileOutput {
+    pub success: bool,
+    pub owner: String,
+    pub repo: String,
+    pub content: GitHubContentFile,
+    pub commit: GitHubContentCommit,
+}
+
+// ============================================================================
+// TOOLARGS IMPLEMENTATION
+// ============================================================================
+
+#[tool_metadata(
+    description = "Update an existing file in a repository (requires its current SHA)"
+)]
+impl ToolArgs for UpdateFileArgs {
+    type Output = GitHubUpdateFileOutput;
+    type Prompts = GithubUpdateFilePrompts;
+
+    const NAME: &'static str = GITHUB_UPDATE_FILE;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
+    const DESCRIPTION: &'static str = "Update an existing file in a repository (requires its current SHA)";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
+}
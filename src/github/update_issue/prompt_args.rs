@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_update_issue` prompts
+    pub enum UpdateIssueScenario {
+        /// Opening/closing issues (default)
+        State => "state",
+        /// Labels, assignees, milestone
+        Metadata => "metadata",
+    }
+}
+
 /// Prompt arguments for github_update_issue tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateIssuePromptArgs {
-    /// Scenario to show examples for
-    /// - "state": Opening/closing issues
-    /// - "metadata": Labels, assignees, milestone
-    /// - "content": Title and body updates
-    /// - "workflows": Issue management workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<UpdateIssueScenario>,
 }
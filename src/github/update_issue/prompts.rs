@@ -1,8 +1,8 @@
 //! Prompt messages for github_update_issue tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::UpdateIssuePromptArgs;
+use super::prompt_args::{UpdateIssuePromptArgs, UpdateIssueScenario};
 
 /// Prompt provider for update_issue tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for UpdateIssuePrompts {
     type PromptArgs = UpdateIssuePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("metadata") => prompt_metadata(),
-            _ => prompt_state(),
+        match args.scenario {
+            Some(UpdateIssueScenario::Metadata) => prompt_metadata(),
+            Some(UpdateIssueScenario::State) | None => prompt_state(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (state, metadata)".to_string()),
-                required: Some(false),
-            }
+            UpdateIssueScenario::prompt_argument()
         ]
     }
 }
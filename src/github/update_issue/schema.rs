@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_UPDATE_ISSUE};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::UpdateIssuePrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -65,4 +66,5 @@ impl ToolArgs for UpdateIssueArgs {
     const NAME: &'static str = GITHUB_UPDATE_ISSUE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Update an existing GitHub issue";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent().open_world();
 }
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `github_update_pull_request` prompts
+    pub enum UpdatePullRequestScenario {
+        /// Title and body updates
+        Content => "content",
+        /// PR management workflows
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for github_update_pull_request tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UpdatePullRequestPromptArgs {
-    /// Scenario to show examples for
-    /// - "state": Opening/closing PRs
-    /// - "content": Title and body updates
-    /// - "draft": Draft status changes
-    /// - "workflows": PR management workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<UpdatePullRequestScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for github_update_pull_request tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::UpdatePullRequestPromptArgs;
+use super::prompt_args::{UpdatePullRequestPromptArgs, UpdatePullRequestScenario};
 
 /// Prompt provider for github_update_pull_request tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for UpdatePullRequestPrompts {
     type PromptArgs = UpdatePullRequestPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("content") => prompt_content(),
-            Some("workflows") => prompt_workflows(),
+        match args.scenario {
+            Some(UpdatePullRequestScenario::Content) => prompt_content(),
+            Some(UpdatePullRequestScenario::Workflows) => prompt_workflows(),
             _ => prompt_content(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (content, workflows)".to_string()),
-                required: Some(false),
-            }
+            UpdatePullRequestScenario::prompt_argument()
         ]
     }
 }
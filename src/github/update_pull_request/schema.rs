@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_GITHUB, GITHUB_UPDATE_PULL_REQUEST};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::UpdatePullRequestPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // ARGS STRUCT
@@ -65,4 +66,5 @@ impl ToolArgs for UpdatePullRequestArgs {
     const NAME: &'static str = GITHUB_UPDATE_PULL_REQUEST;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_GITHUB;
     const DESCRIPTION: &'static str = "Update an existing pull request";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent().open_world();
 }
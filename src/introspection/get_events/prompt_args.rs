@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `introspection_get_events` prompts
+    pub enum IntrospectionGetEventsScenario {
+        /// Getting events
+        Basic => "basic",
+        /// Filter by tool, time
+        Filtering => "filtering",
+        /// Event analysis patterns
+        Analysis => "analysis",
+    }
+}
+
 /// Prompt arguments for introspection_get_events tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IntrospectionGetEventsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Getting events
-    /// - "filtering": Filter by tool, time
-    /// - "analysis": Event analysis patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<IntrospectionGetEventsScenario>,
 }
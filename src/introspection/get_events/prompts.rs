@@ -1,8 +1,8 @@
 //! Prompt messages for introspection_get_events tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::IntrospectionGetEventsPromptArgs;
+use super::prompt_args::{IntrospectionGetEventsPromptArgs, IntrospectionGetEventsScenario};
 
 /// Prompt provider for introspection_get_events tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for IntrospectionGetEventsPrompts {
     type PromptArgs = IntrospectionGetEventsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
-            Some("analysis") => prompt_analysis(),
+        match args.scenario {
+            Some(IntrospectionGetEventsScenario::Basic) => prompt_basic(),
+            Some(IntrospectionGetEventsScenario::Filtering) => prompt_filtering(),
+            Some(IntrospectionGetEventsScenario::Analysis) => prompt_analysis(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering, analysis)".to_string()),
-                required: Some(false),
-            }
+            IntrospectionGetEventsScenario::prompt_argument()
         ]
     }
 }
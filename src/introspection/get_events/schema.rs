@@ -0,0 +1,110 @@
+//! Schema types for introspection_get_events tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use kodegen_config::{CATEGORY_INTROSPECTION, INTROSPECTION_GET_EVENTS};
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::IntrospectionGetEventsPrompts;
+
+// ============================================================================
+// TOOL ARGUMENTS
+// ============================================================================
+
+/// Default value for limit
+fn default_limit() -> usize {
+    50
+}
+
+/// Arguments for `introspection_get_events` tool
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct IntrospectionGetEventsArgs {
+    /// Filter by tool name (exact match)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+
+    /// Filter by success status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+
+    /// Only events at or after this timestamp (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Only events before this timestamp (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// Maximum number of events to return (default: 50)
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// Number of matching events to skip (ignored when cursor is set)
+    #[serde(default)]
+    pub offset: usize,
+
+    /// Resume after the last event of a previous page (its `next_cursor`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+// ============================================================================
+// OUTPUT TYPES
+// ============================================================================
+
+/// Output from `introspection_get_events` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IntrospectionGetEventsOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Events in sequence order (oldest first)
+    pub events: Vec<ToolEvent>,
+    /// Total events matching the filters
+    pub total_count: usize,
+    /// More events are available after this page
+    pub has_more: bool,
+    /// Cursor to pass as `cursor` for the next page (None when has_more is false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// A single tool execution event
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolEvent {
+    /// Monotonic sequence number within the event stream
+    pub seq: u64,
+    /// Tool that was called
+    pub tool: String,
+    /// When the call started (ISO 8601)
+    pub timestamp: String,
+    /// Execution time in milliseconds (if available)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// Whether the call succeeded
+    pub success: bool,
+    /// Arguments passed to the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<Value>,
+    /// Structured output returned by the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Value>,
+    /// Error message when success is false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// ============================================================================
+// TOOL ARGS TRAIT IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+#[tool_metadata(
+    description = "Read the tool execution event stream with filters and cursor-based pagination"
+)]
+impl ToolArgs for IntrospectionGetEventsArgs {
+    type Output = IntrospectionGetEventsOutput;
+    type Prompts = IntrospectionGetEventsPrompts;
+
+    const NAME: &'static str = INTROSPECTION_GET_EVENTS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_INTROSPECTION;
+    const DESCRIPTION: &'static str = "Read the tool execution event stream with filters and cursor-based pagination";
+}
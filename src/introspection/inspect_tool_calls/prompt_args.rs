@@ -3,12 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `inspect_tool_calls` prompts
+    pub enum InspectToolCallsScenario {
+        /// Recovering context after a restart (default)
+        Onboarding => "onboarding",
+        /// Filtering the call history by tool
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for inspect_tool_calls tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct InspectToolCallsPromptArgs {
-    /// Scenario focus: "debugging", "onboarding", "pagination", "filtering", "recent_activity"
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scenario_type: Option<String>,
+    pub scenario_type: Option<InspectToolCallsScenario>,
 
     /// Show detailed usage examples with TypeScript
     #[serde(default, skip_serializing_if = "Option::is_none")]
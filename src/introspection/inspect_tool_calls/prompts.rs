@@ -1,8 +1,8 @@
 //! Prompt messages for inspect_tool_calls tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::InspectToolCallsPromptArgs;
+use super::prompt_args::{InspectToolCallsPromptArgs, InspectToolCallsScenario};
 
 /// Prompt provider for inspect_tool_calls tool
 pub struct InspectToolCallsPrompts;
@@ -11,9 +11,9 @@ impl PromptProvider for InspectToolCallsPrompts {
     type PromptArgs = InspectToolCallsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario_type.as_deref() {
-            Some("filtering") => prompt_filter_by_tool(),
-            _ => prompt_context_recovery(),
+        match args.scenario_type {
+            Some(InspectToolCallsScenario::Filtering) => prompt_filter_by_tool(),
+            Some(InspectToolCallsScenario::Onboarding) | None => prompt_context_recovery(),
         }
     }
 
@@ -21,9 +21,7 @@ impl PromptProvider for InspectToolCallsPrompts {
         vec![
             PromptArgument {
                 name: "scenario_type".to_string(),
-                title: None,
-                description: Some("Inspection scenario: filtering or onboarding (default)".to_string()),
-                required: Some(false),
+                ..InspectToolCallsScenario::prompt_argument()
             },
             PromptArgument {
                 name: "show_examples".to_string(),
@@ -19,7 +19,7 @@ fn default_max_results() -> usize {
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct InspectToolCallsArgs {
     /// Maximum number of results to return (default: 50, max: 1000)
-    /// Ignored when offset is negative
+    /// With a negative offset, caps how many of the most recent results are returned
     #[serde(default = "default_max_results")]
     pub max_results: usize,
 
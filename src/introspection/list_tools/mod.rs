@@ -1,5 +1,7 @@
+pub mod schema;
 pub mod prompt_args;
 pub mod prompts;
 
+pub use schema::*;
 pub use prompt_args::*;
 pub use prompts::*;
@@ -3,13 +3,24 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `introspection_list_tools` prompts
+    pub enum IntrospectionListToolsScenario {
+        /// Listing all tools
+        Basic => "basic",
+        /// Filter by category
+        Filtering => "filtering",
+        /// Tool discovery patterns
+        Discovery => "discovery",
+    }
+}
+
 /// Prompt arguments for introspection_list_tools tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IntrospectionListToolsPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Listing all tools
-    /// - "filtering": Filter by category
-    /// - "discovery": Tool discovery patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<IntrospectionListToolsScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for introspection_list_tools tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::IntrospectionListToolsPromptArgs;
+use super::prompt_args::{IntrospectionListToolsPromptArgs, IntrospectionListToolsScenario};
 
 /// Prompt provider for introspection_list_tools tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for IntrospectionListToolsPrompts {
     type PromptArgs = IntrospectionListToolsPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("filtering") => prompt_filtering(),
-            Some("discovery") => prompt_discovery(),
+        match args.scenario {
+            Some(IntrospectionListToolsScenario::Basic) => prompt_basic(),
+            Some(IntrospectionListToolsScenario::Filtering) => prompt_filtering(),
+            Some(IntrospectionListToolsScenario::Discovery) => prompt_discovery(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering, discovery)".to_string()),
-                required: Some(false),
-            }
+            IntrospectionListToolsScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,89 @@
+//! Schema types for introspection_list_tools tool
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use kodegen_config::{CATEGORY_INTROSPECTION, INTROSPECTION_LIST_TOOLS};
+use crate::{ToolArgs, tool_metadata};
+use super::prompts::IntrospectionListToolsPrompts;
+
+// ============================================================================
+// TOOL ARGUMENTS
+// ============================================================================
+
+/// Arguments for `introspection_list_tools` tool
+///
+/// All filters are optional and combine with AND.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct IntrospectionListToolsArgs {
+    /// Only tools in this category (e.g., "filesystem", "git")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Case-insensitive substring match on tool name or description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+
+    /// Filter by the read_only annotation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+
+    /// Filter by the destructive annotation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destructive: Option<bool>,
+
+    /// Filter by the idempotent annotation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotent: Option<bool>,
+}
+
+// ============================================================================
+// OUTPUT TYPES
+// ============================================================================
+
+/// Output from `introspection_list_tools` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IntrospectionListToolsOutput {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Matching tools, sorted by category then name
+    pub tools: Vec<ToolCatalogEntry>,
+    /// Number of tools returned
+    pub total_count: usize,
+    /// Distinct categories among the returned tools
+    pub categories: Vec<String>,
+}
+
+/// A single tool catalog entry
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolCatalogEntry {
+    /// Tool name used when calling it (e.g., "fs_read_file")
+    pub name: String,
+    /// Category name (e.g., "filesystem")
+    pub category: String,
+    /// Human-readable description
+    pub description: String,
+    /// Tool only reads data
+    pub read_only: bool,
+    /// Tool can delete or irreversibly modify data
+    pub destructive: bool,
+    /// Repeating the call with the same arguments has no further effect
+    pub idempotent: bool,
+    /// Tool interacts with external systems (network, APIs)
+    pub open_world: bool,
+}
+
+// ============================================================================
+// TOOL ARGS TRAIT IMPLEMENTATION (Args→Output Binding)
+// ============================================================================
+
+#[tool_metadata(
+    description = "List available tools with their category and behavior annotations, filterable by category, name and annotations"
+)]
+impl ToolArgs for IntrospectionListToolsArgs {
+    type Output = IntrospectionListToolsOutput;
+    type Prompts = IntrospectionListToolsPrompts;
+
+    const NAME: &'static str = INTROSPECTION_LIST_TOOLS;
+    const CATEGORY: &'static kodegen_config::Category = CATEGORY_INTROSPECTION;
+    const DESCRIPTION: &'static str = "List available tools with their category and behavior annotations, filterable by category, name and annotations";
+}
@@ -1,5 +1,7 @@
 // Re-export all introspection tool name constants from kodegen_config
-pub use kodegen_config::{INSPECT_TOOL_CALLS, INSPECT_USAGE_STATS};
+pub use kodegen_config::{
+    INSPECT_TOOL_CALLS, INSPECT_USAGE_STATS, INTROSPECTION_GET_EVENTS, INTROSPECTION_LIST_TOOLS,
+};
 
 pub mod get_events;
 pub mod inspect_tool_calls;
@@ -24,11 +26,17 @@ pub use inspect_usage_stats::{
 };
 
 pub use get_events::{
+    IntrospectionGetEventsArgs,
+    IntrospectionGetEventsOutput,
     IntrospectionGetEventsPromptArgs,
     IntrospectionGetEventsPrompts,
+    ToolEvent,
 };
 
 pub use list_tools::{
+    IntrospectionListToolsArgs,
+    IntrospectionListToolsOutput,
     IntrospectionListToolsPromptArgs,
     IntrospectionListToolsPrompts,
+    ToolCatalogEntry,
 };
@@ -15,8 +15,9 @@ use serde_json::Value;
 
 /// Tool metadata for automatic discovery.
 ///
-/// Every tool in kodegen-mcp-schema registers exactly one ToolMetadata via
-/// the #[tool_metadata] proc macro. This enables automatic discovery in kodegen.
+/// Every tool in kodegen-mcp-schema registers exactly one ToolMetadata (and
+/// its output deserializer) via the #[tool_metadata] proc macro. This enables
+/// automatic discovery in kodegen.
 pub struct ToolMetadata {
     pub name: &'static str,
     pub category: &'static kodegen_config::Category,
@@ -24,6 +25,8 @@ pub struct ToolMetadata {
     pub args_schema: fn() -> Value,
     pub output_schema: fn() -> Value,
     pub prompt_arguments: fn() -> Vec<PromptArgument>,
+    /// Behavior hints ([`ToolArgs::BEHAVIOR`])
+    pub behavior: tool::ToolBehavior,
     /// Generate prompt messages from JSON arguments.
     /// Takes PromptArgs as JSON Value, deserializes internally, calls generate_prompts.
     pub generate_prompts: fn(&Value) -> Vec<PromptMessage>,
@@ -32,8 +35,14 @@ pub struct ToolMetadata {
 // Collect all ToolMetadata at link time
 inventory::collect!(ToolMetadata);
 
-// Re-export proc macro for convenient use
-pub use kodegen_mcp_schema_macros::tool_metadata;
+// Re-export proc macros for convenient use
+pub use kodegen_mcp_schema_derive::{ValidateArgs, tool_metadata};
+
+// Lets `#[derive(ValidateArgs)]` use `::kodegen_mcp_schema` paths inside this crate too
+extern crate self as kodegen_mcp_schema;
+
+#[doc(hidden)]
+pub use inventory;
 
 // ============================================================================
 // TOOL INFRASTRUCTURE (moved from kodegen-mcp-tool)
@@ -43,8 +52,9 @@ pub mod tool;
 
 // Re-export tool infrastructure for convenience
 pub use tool::{
-    Tool, ToolResponse, ToolExecutionContext,
-    McpError, ToolCallRecord, ToolStatus, add_branded_line_to_result
+    Tool, ToolResponse, ToolExecutionContext, ResponseMode,
+    McpError, ToolErrorData, ToolErrorKind, ToolCallRecord, ToolHistory, ToolStatus, add_branded_line_to_result,
+    SchemaRegistry, ToolSchemas, ValidateArgs,
 };
 
 // ============================================================================
@@ -103,6 +113,32 @@ pub trait ToolArgs: DeserializeOwned + Serialize + JsonSchema + Send + 'static {
     fn icon() -> char {
         Self::CATEGORY.icon
     }
+
+    /// Behavior hints advertised as MCP tool annotations.
+    ///
+    /// `Tool`'s annotation methods default to these, and the schema bundle
+    /// writes them into `index.json`.
+    /// Default: [`ToolBehavior::READ_ONLY`](tool::ToolBehavior::READ_ONLY).
+    const BEHAVIOR: tool::ToolBehavior = tool::ToolBehavior::READ_ONLY;
+
+    /// Describe what this call will destroy, if anything.
+    ///
+    /// When a [`ConfirmationPolicy`](tool::ConfirmationPolicy) is configured,
+    /// calls returning `Some` only run after the user confirms the action.
+    /// Default: `None` (never requires confirmation).
+    fn destructive_action(&self) -> Option<tool::DestructiveAction> {
+        None
+    }
+
+    /// Check requirements that span several fields.
+    ///
+    /// The tool handler calls this after deserializing every call and returns
+    /// failures as `InvalidArguments` errors.
+    /// Default: the checks of `#[derive(ValidateArgs)]`, or `Ok(())` for Args
+    /// without the derive.
+    fn validate(&self) -> Result<(), McpError> {
+        tool::validate::validate_registered(self)
+    }
 }
 
 pub mod filesystem;
@@ -160,12 +196,14 @@ impl tool::SealedPromptProvider for github::create_release::GithubCreateReleaseP
 impl tool::SealedPromptProvider for github::create_repository::CreateRepositoryPrompts {}
 impl tool::SealedPromptProvider for github::delete_branch::DeleteBranchPrompts {}
 impl tool::SealedPromptProvider for github::delete_file::GithubDeleteFilePrompts {}
+impl tool::SealedPromptProvider for github::dependabot_alerts::GithubDependabotAlertsPrompts {}
 impl tool::SealedPromptProvider for github::fork_repository::ForkRepositoryPrompts {}
 impl tool::SealedPromptProvider for github::get_commit::GetCommitPrompts {}
 impl tool::SealedPromptProvider for github::get_file_contents::GetFileContentsPrompts {}
 impl tool::SealedPromptProvider for github::get_issue::GetIssuePrompts {}
 impl tool::SealedPromptProvider for github::get_issue_comments::GetIssueCommentsPrompts {}
 impl tool::SealedPromptProvider for github::get_me::GetMePrompts {}
+impl tool::SealedPromptProvider for github::get_pr::GithubGetPrPrompts {}
 impl tool::SealedPromptProvider for github::get_pull_request_files::GetPullRequestFilesPrompts {}
 impl tool::SealedPromptProvider for github::get_pull_request_reviews::GetPullRequestReviewsPrompts {}
 impl tool::SealedPromptProvider for github::get_pull_request_status::GetPullRequestStatusPrompts {}
@@ -183,6 +221,7 @@ impl tool::SealedPromptProvider for github::search_issues::SearchIssuesPrompts {
 impl tool::SealedPromptProvider for github::search_repositories::SearchRepositoriesPrompts {}
 impl tool::SealedPromptProvider for github::search_users::SearchUsersPrompts {}
 impl tool::SealedPromptProvider for github::secret_scanning_alerts::SecretScanningAlertsPrompts {}
+impl tool::SealedPromptProvider for github::update_file::GithubUpdateFilePrompts {}
 impl tool::SealedPromptProvider for github::update_issue::UpdateIssuePrompts {}
 impl tool::SealedPromptProvider for github::update_pull_request::UpdatePullRequestPrompts {}
 
@@ -308,6 +347,11 @@ impl tool::SealedPromptProvider for memory::memorize::MemorizePrompts {}
 impl tool::SealedPromptProvider for memory::recall::MemoryRecallPrompts {}
 impl tool::SealedPromptProvider for memory::check_memorize_status::CheckMemorizeStatusPrompts {}
 
-// Web tools
-impl tool::SealedPromptProvider for web::scrape_url::ScrapeUrlPrompts {}
-impl tool::SealedPromptProvider for web::web_search::WebSearchPrompts {}
+// ============================================================================
+// SEALED TRAIT IMPLEMENTATIONS FOR RESOURCE PROVIDERS
+// ============================================================================
+
+impl tool::SealedResourceProvider for citescrape::CitescrapeResources {}
+impl tool::SealedResourceProvider for browser::ScreenshotResources {}
+impl tool::SealedResourceProvider for memory::MemoryResources {}
+impl tool::SealedResourceProvider for prompt::PromptResources {}
@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `memory_memorize` prompts
+    pub enum MemorizeScenario {
+        /// Storing memories
+        Basic => "basic",
+        /// Library organization
+        Organization => "organization",
+    }
+}
+
 /// Prompt arguments for memory_memorize tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MemorizePromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Storing memories
-    /// - "organization": Library organization
-    /// - "patterns": Common memorization patterns
-    /// - "workflows": Memory workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<MemorizeScenario>,
 }
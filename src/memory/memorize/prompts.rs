@@ -1,8 +1,8 @@
 //! Prompt messages for memory_memorize tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::MemorizePromptArgs;
+use super::prompt_args::{MemorizePromptArgs, MemorizeScenario};
 
 /// Prompt provider for memory_memorize tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for MemorizePrompts {
     type PromptArgs = MemorizePromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("organization") => prompt_organization(),
+        match args.scenario {
+            Some(MemorizeScenario::Basic) => prompt_basic(),
+            Some(MemorizeScenario::Organization) => prompt_organization(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, organization)".to_string()),
-                required: Some(false),
-            }
+            MemorizeScenario::prompt_argument()
         ]
     }
 }
@@ -40,6 +40,7 @@ pub struct MemorizeOutput {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::MemorizePrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Store content in a named memory library with automatic embedding generation. The memory will be tagged with the library name and can be retrieved later using recall(). Each library is a separate namespace for organizing memories."
@@ -51,4 +52,5 @@ impl ToolArgs for MemorizeArgs {
     const NAME: &'static str = MEMORY_MEMORIZE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_CANDLE_AGENT;
     const DESCRIPTION: &'static str = "Store content in a named memory library with automatic embedding generation. The memory will be tagged with the library name and can be retrieved later using recall(). Each library is a separate namespace for organizing memories.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -10,6 +10,7 @@ pub mod list_libraries;
 pub mod memorize;
 pub mod recall;
 pub mod check_memorize_status;
+pub mod resources;
 
 // Re-export list_libraries tool
 pub use list_libraries::{
@@ -42,3 +43,6 @@ pub use check_memorize_status::{
     CheckMemorizeStatusPrompts,
     MemorizeProgress,
 };
+
+// Re-export resource URIs
+pub use resources::{MemoryLibraryUri, MemoryResources};
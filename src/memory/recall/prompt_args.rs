@@ -3,13 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `memory_recall` prompts
+    pub enum MemoryRecallScenario {
+        /// Basic recall
+        Basic => "basic",
+        /// Semantic search examples
+        Semantic => "semantic",
+    }
+}
+
 /// Prompt arguments for memory_recall tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MemoryRecallPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic recall
-    /// - "semantic": Semantic search examples
-    /// - "workflows": Recall in workflows
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<MemoryRecallScenario>,
 }
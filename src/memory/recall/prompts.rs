@@ -1,8 +1,8 @@
 //! Prompt messages for memory_recall tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::MemoryRecallPromptArgs;
+use super::prompt_args::{MemoryRecallPromptArgs, MemoryRecallScenario};
 
 /// Prompt provider for memory_recall tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for MemoryRecallPrompts {
     type PromptArgs = MemoryRecallPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("semantic") => prompt_semantic(),
+        match args.scenario {
+            Some(MemoryRecallScenario::Basic) => prompt_basic(),
+            Some(MemoryRecallScenario::Semantic) => prompt_semantic(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, semantic)".to_string()),
-                required: Some(false),
-            }
+            MemoryRecallScenario::prompt_argument()
         ]
     }
 }
@@ -0,0 +1,74 @@
+//! Resources for memory libraries
+
+use rmcp::model::RawResourceTemplate;
+
+use crate::tool::{ResourceProvider, ResourceUri, match_template, resource_template};
+use super::{ListMemoryLibrariesOutput, MemorizeOutput};
+
+/// The memories stored in one library
+pub const MEMORY_LIBRARY_URI_TEMPLATE: &str = "kodegen://memory/{library}";
+
+/// URI of a memory library
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MemoryLibraryUri {
+    /// Library name
+    pub library: String,
+}
+
+impl ResourceUri for MemoryLibraryUri {
+    fn parse(uri: &str) -> Option<Self> {
+        let mut vars = match_template(MEMORY_LIBRARY_URI_TEMPLATE, uri)?;
+        Some(Self {
+            library: vars.remove("library")?,
+        })
+    }
+
+    fn to_uri(&self) -> String {
+        format!("kodegen://memory/{}", self.library)
+    }
+
+    fn name(&self) -> String {
+        self.library.clone()
+    }
+
+    fn mime_type(&self) -> Option<&'static str> {
+        Some("application/json")
+    }
+}
+
+/// Memory libraries
+pub struct MemoryResources;
+
+impl ResourceProvider for MemoryResources {
+    type Uri = MemoryLibraryUri;
+
+    fn templates() -> Vec<RawResourceTemplate> {
+        vec![resource_template(
+            MEMORY_LIBRARY_URI_TEMPLATE,
+            "memory_library",
+            "Memories stored in a library",
+            Some("application/json"),
+        )]
+    }
+}
+
+impl ListMemoryLibrariesOutput {
+    /// Resources for every listed library
+    #[must_use]
+    pub fn resource_uris(&self) -> Vec<MemoryLibraryUri> {
+        self.libraries
+            .iter()
+            .map(|library| MemoryLibraryUri { library: library.clone() })
+            .collect()
+    }
+}
+
+impl MemorizeOutput {
+    /// Resource for the library the memory was stored in
+    #[must_use]
+    pub fn resource_uri(&self) -> MemoryLibraryUri {
+        MemoryLibraryUri {
+            library: self.library.clone(),
+        }
+    }
+}
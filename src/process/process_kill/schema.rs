@@ -5,7 +5,9 @@ use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_PROCESS, PROCESS_KILL};
 
 use crate::{ToolArgs, tool_metadata};
+use crate::tool::DestructiveAction;
 use super::prompts::ProcessKillPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // PROCESS KILL TOOL
@@ -43,4 +45,12 @@ impl ToolArgs for ProcessKillArgs {
     const NAME: &'static str = PROCESS_KILL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROCESS;
     const DESCRIPTION: &'static str = "Terminate a process by PID. Sends SIGKILL signal - the process cannot catch or ignore it. Use with caution";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
+
+    fn destructive_action(&self) -> Option<DestructiveAction> {
+        Some(
+            DestructiveAction::new(format!("Kill process {} with SIGKILL", self.pid), format!("PID {}", self.pid))
+                .effect("The process cannot clean up; unsaved work is lost"),
+        )
+    }
 }
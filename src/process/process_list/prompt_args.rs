@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `process_list` prompts
+    pub enum ProcessListScenario {
+        /// List all processes (default)
+        Basic => "basic",
+        /// Filter by name
+        Filtering => "filtering",
+    }
+}
+
 /// Prompt arguments for process_list tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProcessListPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": List all processes
-    /// - "filtering": Filter by name
-    /// - "monitoring": System monitoring patterns
-    /// - "workflows": Process management workflows
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ProcessListScenario>,
 }
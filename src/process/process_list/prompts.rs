@@ -1,8 +1,8 @@
 //! Prompt messages for process_list tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::ProcessListPromptArgs;
+use super::prompt_args::{ProcessListPromptArgs, ProcessListScenario};
 
 /// Prompt provider for process_list tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for ProcessListPrompts {
     type PromptArgs = ProcessListPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("filtering") => prompt_filtering(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(ProcessListScenario::Filtering) => prompt_filtering(),
+            Some(ProcessListScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, filtering)".to_string()),
-                required: Some(false),
-            }
+            ProcessListScenario::prompt_argument()
         ]
     }
 }
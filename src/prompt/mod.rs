@@ -7,6 +7,7 @@ pub mod prompt_add;
 pub mod prompt_delete;
 pub mod prompt_edit;
 pub mod prompt_get;
+pub mod resources;
 
 // Re-export prompt_get tool (includes all shared types)
 pub use prompt_get::{
@@ -52,3 +53,6 @@ pub use prompt_edit::{
     EditPromptPromptArgs,
     PromptEditPrompts,
 };
+
+// Re-export resource URIs
+pub use resources::{PromptResources, PromptUri};
@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `add_prompt` prompts
+    pub enum AddPromptScenario {
+        /// Using Jinja2 templates (default)
+        Templating => "templating",
+        /// Creating simple prompts
+        Basic => "basic",
+    }
+}
+
 /// Prompt arguments for add_prompt tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AddPromptPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Creating simple prompts
-    /// - "templating": Using Jinja2 templates
-    /// - "organization": Prompt naming and organization
-    /// - "workflows": Prompt creation workflows
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<AddPromptScenario>,
 }
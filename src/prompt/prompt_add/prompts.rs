@@ -1,8 +1,8 @@
 //! Prompt messages for add_prompt tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::AddPromptPromptArgs;
+use super::prompt_args::{AddPromptPromptArgs, AddPromptScenario};
 
 /// Prompt provider for add_prompt tool
 ///
@@ -14,20 +14,15 @@ impl PromptProvider for PromptAddPrompts {
     type PromptArgs = AddPromptPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            _ => prompt_templating(),
+        match args.scenario {
+            Some(AddPromptScenario::Basic) => prompt_basic(),
+            Some(AddPromptScenario::Templating) | None => prompt_templating(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, templating)".to_string()),
-                required: Some(false),
-            }
+            AddPromptScenario::prompt_argument()
         ]
     }
 }
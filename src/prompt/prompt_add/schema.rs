@@ -49,6 +49,7 @@ pub struct PromptAddOutput {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PromptAddPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Create new reusable Jinja2 prompt template with variables, metadata, and categorization"
@@ -60,4 +61,5 @@ impl ToolArgs for AddPromptArgs {
     const NAME: &'static str = PROMPT_ADD;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROMPT;
     const DESCRIPTION: &'static str = "Create new reusable Jinja2 prompt template with variables, metadata, and categorization";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
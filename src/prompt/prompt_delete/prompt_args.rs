@@ -3,14 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `delete_prompt` prompts
+    pub enum DeletePromptScenario {
+        /// Basic deletion
+        Basic => "basic",
+        /// Safe deletion practices
+        Safety => "safety",
+    }
+}
+
 /// Prompt arguments for delete_prompt tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DeletePromptPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic deletion
-    /// - "cleanup": Cleanup workflows
-    /// - "safety": Safe deletion practices
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<DeletePromptScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for delete_prompt tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::DeletePromptPromptArgs;
+use super::prompt_args::{DeletePromptPromptArgs, DeletePromptScenario};
 
 /// Prompt provider for delete_prompt tool
 ///
@@ -14,21 +14,16 @@ impl PromptProvider for PromptDeletePrompts {
     type PromptArgs = DeletePromptPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("safety") => prompt_safety(),
+        match args.scenario {
+            Some(DeletePromptScenario::Basic) => prompt_basic(),
+            Some(DeletePromptScenario::Safety) => prompt_safety(),
             _ => prompt_safety(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, safety)".to_string()),
-                required: Some(false),
-            }
+            DeletePromptScenario::prompt_argument()
         ]
     }
 }
@@ -40,6 +40,7 @@ pub struct PromptDeleteOutput {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PromptDeletePrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Delete prompt template with required confirmation to prevent accidental removal"
@@ -51,4 +52,5 @@ impl ToolArgs for DeletePromptArgs {
     const NAME: &'static str = PROMPT_DELETE;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROMPT;
     const DESCRIPTION: &'static str = "Delete prompt template with required confirmation to prevent accidental removal";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
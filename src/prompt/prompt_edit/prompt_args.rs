@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `edit_prompt` prompts
+    pub enum EditPromptScenario {
+        /// Basic prompt editing (default)
+        Basic => "basic",
+        /// Iterative prompt refinement
+        Refinement => "refinement",
+        /// Managing prompt versions
+        Versioning => "versioning",
+        /// Edit workflows and patterns
+        Workflows => "workflows",
+    }
+}
+
 /// Prompt arguments for edit_prompt tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EditPromptPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic prompt editing (default)
-    /// - "refinement": Iterative prompt refinement
-    /// - "versioning": Managing prompt versions
-    /// - "workflows": Edit workflows and patterns
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<EditPromptScenario>,
 }
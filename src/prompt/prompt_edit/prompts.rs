@@ -1,8 +1,8 @@
 //! Prompt messages for edit_prompt tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::EditPromptPromptArgs;
+use super::prompt_args::{EditPromptPromptArgs, EditPromptScenario};
 
 /// Prompt provider for edit_prompt tool
 ///
@@ -14,22 +14,17 @@ impl PromptProvider for PromptEditPrompts {
     type PromptArgs = EditPromptPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("refinement") => prompt_refinement(),
-            Some("versioning") => prompt_versioning(),
-            Some("workflows") => prompt_workflows(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(EditPromptScenario::Refinement) => prompt_refinement(),
+            Some(EditPromptScenario::Versioning) => prompt_versioning(),
+            Some(EditPromptScenario::Workflows) => prompt_workflows(),
+            Some(EditPromptScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, refinement, versioning, workflows)".to_string()),
-                required: Some(false),
-            }
+            EditPromptScenario::prompt_argument()
         ]
     }
 }
@@ -42,6 +42,7 @@ pub struct PromptEditOutput {
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::PromptEditPrompts;
+use crate::tool::ToolBehavior;
 
 #[tool_metadata(
     description = "Update existing prompt template content, metadata, or variables with version iteration support"
@@ -53,4 +54,5 @@ impl ToolArgs for EditPromptArgs {
     const NAME: &'static str = PROMPT_EDIT;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_PROMPT;
     const DESCRIPTION: &'static str = "Update existing prompt template content, metadata, or variables with version iteration support";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent();
 }
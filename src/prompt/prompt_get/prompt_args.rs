@@ -3,15 +3,22 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `get_prompt` prompts
+    pub enum GetPromptScenario {
+        /// Retrieving prompts (default)
+        Basic => "basic",
+        /// Variable substitution
+        Variables => "variables",
+    }
+}
+
 /// Prompt arguments for get_prompt tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetPromptPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Retrieving prompts
-    /// - "variables": Variable substitution
-    /// - "rendering": Template rendering
-    /// - "workflows": Common usage patterns
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<GetPromptScenario>,
 }
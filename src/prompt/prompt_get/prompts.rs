@@ -1,8 +1,8 @@
 //! Prompt messages for get_prompt tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::GetPromptPromptArgs;
+use super::prompt_args::{GetPromptPromptArgs, GetPromptScenario};
 
 /// Prompt provider for get_prompt tool
 pub struct PromptGetPrompts;
@@ -11,20 +11,15 @@ impl PromptProvider for PromptGetPrompts {
     type PromptArgs = GetPromptPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("variables") => prompt_variables(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(GetPromptScenario::Variables) => prompt_variables(),
+            Some(GetPromptScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for (basic, variables)".to_string()),
-                required: Some(false),
-            }
+            GetPromptScenario::prompt_argument()
         ]
     }
 }
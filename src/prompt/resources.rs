@@ -0,0 +1,69 @@
+//! Resources for stored prompt templates
+
+use rmcp::model::RawResourceTemplate;
+
+use crate::tool::{ResourceProvider, ResourceUri, match_template, resource_template};
+use super::{PromptAddOutput, PromptEditOutput};
+
+/// A stored prompt template, addressed by name
+pub const PROMPT_URI_TEMPLATE: &str = "kodegen://prompt/{name}";
+
+/// URI of a stored prompt template
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PromptUri {
+    /// Prompt name
+    pub name: String,
+}
+
+impl ResourceUri for PromptUri {
+    fn parse(uri: &str) -> Option<Self> {
+        let mut vars = match_template(PROMPT_URI_TEMPLATE, uri)?;
+        Some(Self {
+            name: vars.remove("name")?,
+        })
+    }
+
+    fn to_uri(&self) -> String {
+        format!("kodegen://prompt/{}", self.name)
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn mime_type(&self) -> Option<&'static str> {
+        Some("text/markdown")
+    }
+}
+
+/// Stored prompt templates
+pub struct PromptResources;
+
+impl ResourceProvider for PromptResources {
+    type Uri = PromptUri;
+
+    fn templates() -> Vec<RawResourceTemplate> {
+        vec![resource_template(
+            PROMPT_URI_TEMPLATE,
+            "prompt",
+            "Stored prompt template",
+            Some("text/markdown"),
+        )]
+    }
+}
+
+impl PromptAddOutput {
+    /// Resource for the added prompt
+    #[must_use]
+    pub fn resource_uri(&self) -> PromptUri {
+        PromptUri { name: self.name.clone() }
+    }
+}
+
+impl PromptEditOutput {
+    /// Resource for the edited prompt
+    #[must_use]
+    pub fn resource_uri(&self) -> PromptUri {
+        PromptUri { name: self.name.clone() }
+    }
+}
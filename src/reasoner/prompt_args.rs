@@ -3,16 +3,28 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `reasoner` prompts
+    pub enum ReasonerScenario {
+        /// Basic reasoning steps (default)
+        Basic => "basic",
+        /// Beam search strategy
+        BeamSearch => "beam_search",
+        /// Monte Carlo Tree Search
+        Mcts => "mcts",
+        /// Branching and revision
+        Branching => "branching",
+        /// Comparing strategies
+        Strategies => "strategies",
+    }
+}
+
 /// Prompt arguments for reasoner tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ReasonerPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic reasoning steps
-    /// - "beam_search": Beam search strategy
-    /// - "mcts": Monte Carlo Tree Search
-    /// - "branching": Branching and revision
-    /// - "strategies": Comparing strategies
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<ReasonerScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for reasoner tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptArgument, PromptMessage, PromptMessageContent, PromptMessageRole};
-use super::prompt_args::ReasonerPromptArgs;
+use super::prompt_args::{ReasonerPromptArgs, ReasonerScenario};
 
 /// Prompt provider for reasoner tool
 ///
@@ -14,24 +14,17 @@ impl PromptProvider for ReasonerPrompts {
     type PromptArgs = ReasonerPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("beam_search") => prompt_beam_search(),
-            Some("mcts") => prompt_mcts(),
-            Some("branching") => prompt_branching(),
-            Some("strategies") => prompt_strategies(),
-            _ => prompt_basic(),
+        match args.scenario {
+            Some(ReasonerScenario::BeamSearch) => prompt_beam_search(),
+            Some(ReasonerScenario::Mcts) => prompt_mcts(),
+            Some(ReasonerScenario::Branching) => prompt_branching(),
+            Some(ReasonerScenario::Strategies) => prompt_strategies(),
+            Some(ReasonerScenario::Basic) | None => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
-        vec![PromptArgument {
-            name: "scenario".to_string(),
-            title: None,
-            description: Some(
-                "Scenario to show (basic, beam_search, mcts, branching, strategies)".to_string(),
-            ),
-            required: Some(false),
-        }]
+        vec![ReasonerScenario::prompt_argument()]
     }
 }
 
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_REASONER, REASONER};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::ReasonerPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // REASONER TOOL
@@ -92,4 +93,5 @@ impl ToolArgs for ReasonerArgs {
     const NAME: &'static str = REASONER;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_REASONER;
     const DESCRIPTION: &'static str = "Advanced reasoning tool with multiple strategies (beam search, MCTS). Processes thoughts step-by-step, supports branching and revision, and tracks best reasoning paths. Use for complex problem-solving that requires exploration of multiple solution approaches.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
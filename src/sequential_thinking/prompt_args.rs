@@ -3,16 +3,28 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `sequential_thinking` prompts
+    pub enum SequentialThinkingScenario {
+        /// Basic sequential thoughts
+        Basic => "basic",
+        /// Revising previous thoughts
+        Revision => "revision",
+        /// Creating thought branches
+        Branching => "branching",
+        /// Managing thinking sessions
+        Sessions => "sessions",
+        /// Continuing thoughts across calls (sessions are automatic)
+        Continuation => "continuation",
+    }
+}
+
 /// Prompt arguments for sequential_thinking tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SequentialThinkingPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Basic sequential thoughts
-    /// - "revision": Revising previous thoughts
-    /// - "branching": Creating thought branches
-    /// - "sessions": Managing thinking sessions
-    /// - "patterns": Common thinking patterns
-    /// - "comprehensive": All scenarios combined
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<SequentialThinkingScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for sequential_thinking tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::SequentialThinkingPromptArgs;
+use super::prompt_args::{SequentialThinkingPromptArgs, SequentialThinkingScenario};
 
 /// Prompt provider for sequential_thinking tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for SequentialThinkingPrompts {
     type PromptArgs = SequentialThinkingPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("revision") => prompt_revision(),
-            Some("branching") => prompt_branching(),
-            Some("sessions") | Some("continuation") => prompt_continuation(),
+        match args.scenario {
+            Some(SequentialThinkingScenario::Basic) => prompt_basic(),
+            Some(SequentialThinkingScenario::Revision) => prompt_revision(),
+            Some(SequentialThinkingScenario::Branching) => prompt_branching(),
+            Some(SequentialThinkingScenario::Sessions) | Some(SequentialThinkingScenario::Continuation) => prompt_continuation(),
             _ => prompt_basic(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show examples for: basic, revision, branching, sessions".to_string()),
-                required: Some(false),
-            }
+            SequentialThinkingScenario::prompt_argument()
         ]
     }
 }
@@ -6,6 +6,7 @@ use kodegen_config::{CATEGORY_SEQUENTIAL_THINKING, SEQUENTIAL_THINKING};
 
 use crate::{ToolArgs, tool_metadata};
 use super::prompts::SequentialThinkingPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // SEQUENTIAL THINKING TOOL
@@ -107,4 +108,5 @@ impl ToolArgs for SequentialThinkingArgs {
     const NAME: &'static str = SEQUENTIAL_THINKING;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_SEQUENTIAL_THINKING;
     const DESCRIPTION: &'static str = "A detailed tool for dynamic and reflective problem-solving through thoughts. This tool helps analyze problems through a flexible thinking process that can adapt and evolve. Each thought can build on, question, or revise previous insights as understanding deepens.";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES;
 }
@@ -3,14 +3,26 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::tool::prompt_scenarios;
+
+prompt_scenarios! {
+    /// Scenarios for `terminal` prompts
+    pub enum TerminalScenario {
+        /// Simple command execution
+        Basic => "basic",
+        /// Multiple terminal usage
+        Parallel => "parallel",
+        /// Fire-and-forget patterns
+        Background => "background",
+        /// Progress checking with READ
+        Monitoring => "monitoring",
+    }
+}
+
 /// Arguments for customizing terminal tool prompt examples
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TerminalPromptArgs {
-    /// Scenario to show examples for
-    /// - "basic": Simple command execution
-    /// - "parallel": Multiple terminal usage
-    /// - "background": Fire-and-forget patterns
-    /// - "monitoring": Progress checking with READ
+    /// Scenario to show examples for (omit for the default prompt)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scenario: Option<String>,
+    pub scenario: Option<TerminalScenario>,
 }
@@ -1,8 +1,8 @@
 //! Prompt messages for terminal tool
 
-use crate::tool::PromptProvider;
+use crate::tool::{PromptProvider, PromptScenario};
 use rmcp::model::{PromptMessage, PromptMessageRole, PromptMessageContent, PromptArgument};
-use super::prompt_args::TerminalPromptArgs;
+use super::prompt_args::{TerminalPromptArgs, TerminalScenario};
 
 /// Prompt provider for terminal tool
 ///
@@ -14,23 +14,18 @@ impl PromptProvider for TerminalPrompts {
     type PromptArgs = TerminalPromptArgs;
 
     fn generate_prompts(args: &Self::PromptArgs) -> Vec<PromptMessage> {
-        match args.scenario.as_deref() {
-            Some("basic") => prompt_basic(),
-            Some("parallel") => prompt_parallel(),
-            Some("background") => prompt_background(),
-            Some("monitoring") => prompt_monitoring(),
+        match args.scenario {
+            Some(TerminalScenario::Basic) => prompt_basic(),
+            Some(TerminalScenario::Parallel) => prompt_parallel(),
+            Some(TerminalScenario::Background) => prompt_background(),
+            Some(TerminalScenario::Monitoring) => prompt_monitoring(),
             _ => prompt_comprehensive(),
         }
     }
 
     fn prompt_arguments() -> Vec<PromptArgument> {
         vec![
-            PromptArgument {
-                name: "scenario".to_string(),
-                title: None,
-                description: Some("Scenario to show (basic, parallel, background, monitoring)".to_string()),
-                required: Some(false),
-            }
+            TerminalScenario::prompt_argument()
         ]
     }
 }
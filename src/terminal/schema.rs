@@ -4,8 +4,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use kodegen_config::{CATEGORY_TERMINAL, TERMINAL};
 
-use crate::{ToolArgs, tool_metadata};
+use crate::{ToolArgs, ValidateArgs, tool_metadata};
 use super::prompts::TerminalPrompts;
+use crate::tool::ToolBehavior;
 
 // ============================================================================
 // UNIFIED TERMINAL TOOL
@@ -52,7 +53,7 @@ pub enum TerminalAction {
 }
 
 /// Arguments for unified `terminal` tool
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
 pub struct TerminalInput {
     /// Action to perform - defaults to EXEC for backward compatibility
     #[serde(default)]
@@ -60,6 +61,7 @@ pub struct TerminalInput {
 
     /// Command to execute (required for EXEC, ignored for READ/LIST/KILL)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[required_if(action = "Exec")]
     pub command: Option<String>,
 
     /// Terminal number (0, 1, 2...) - defaults to 0
@@ -85,6 +87,7 @@ pub struct TerminalInput {
     /// Default: 2000 (scrollback buffer size)
     /// Max: 2000
     #[serde(default = "default_tail")]
+    #[range(max = 2000)]
     pub tail: u32,
 }
 
@@ -144,4 +147,5 @@ impl ToolArgs for TerminalInput {
     const NAME: &'static str = TERMINAL;
     const CATEGORY: &'static kodegen_config::Category = CATEGORY_TERMINAL;
     const DESCRIPTION: &'static str = "Execute shell commands in persistent, stateful terminal sessions with support for parallel execution and background tasks";
+    const BEHAVIOR: ToolBehavior = ToolBehavior::WRITES.destructive().open_world();
 }
@@ -0,0 +1,87 @@
+//! Behavior hints declared next to each tool's Args
+//!
+//! [`ToolArgs::BEHAVIOR`](crate::ToolArgs::BEHAVIOR) says whether a tool reads
+//! or writes, can destroy data, is safe to retry and reaches outside the local
+//! machine. `Tool`'s annotation methods default to it, and the schema bundle
+//! writes it into every `index.json` entry, so clients see the same hints with
+//! or without a running server.
+
+use rmcp::model::ToolAnnotations;
+
+/// Behavior hints of a tool, advertised as MCP [`ToolAnnotations`]
+///
+/// Start from [`READ_ONLY`](Self::READ_ONLY) or [`WRITES`](Self::WRITES) and
+/// add the remaining hints:
+///
+/// ```rust
+/// use kodegen_mcp_schema::tool::ToolBehavior;
+///
+/// const DELETE_BRANCH: ToolBehavior = ToolBehavior::WRITES.destructive().idempotent().open_world();
+/// assert!(!DELETE_BRANCH.read_only && DELETE_BRANCH.destructive);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToolBehavior {
+    /// Only reads state, never modifies it
+    pub read_only: bool,
+
+    /// Can delete or overwrite existing data (only meaningful when not read-only)
+    pub destructive: bool,
+
+    /// Repeating a call with the same arguments has no further effect
+    pub idempotent: bool,
+
+    /// Talks to systems outside the local machine (network, remote APIs)
+    pub open_world: bool,
+}
+
+impl ToolBehavior {
+    /// Local tool that only reads state (the default)
+    pub const READ_ONLY: Self = Self {
+        read_only: true,
+        destructive: false,
+        idempotent: true,
+        open_world: false,
+    };
+
+    /// Local tool that adds or changes state without destroying any
+    pub const WRITES: Self = Self {
+        read_only: false,
+        destructive: false,
+        idempotent: false,
+        open_world: false,
+    };
+
+    /// Can delete or overwrite existing data
+    #[must_use]
+    pub const fn destructive(self) -> Self {
+        Self { destructive: true, ..self }
+    }
+
+    /// Safe to retry with the same arguments
+    #[must_use]
+    pub const fn idempotent(self) -> Self {
+        Self { idempotent: true, ..self }
+    }
+
+    /// Talks to systems outside the local machine
+    #[must_use]
+    pub const fn open_world(self) -> Self {
+        Self { open_world: true, ..self }
+    }
+
+    /// MCP annotations carrying these hints
+    #[must_use]
+    pub fn annotations(self) -> ToolAnnotations {
+        ToolAnnotations::new()
+            .read_only(self.read_only)
+            .destructive(self.destructive)
+            .idempotent(self.idempotent)
+            .open_world(self.open_world)
+    }
+}
+
+impl Default for ToolBehavior {
+    fn default() -> Self {
+        Self::READ_ONLY
+    }
+}
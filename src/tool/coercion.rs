@@ -0,0 +1,262 @@
+//! Lenient coercion of LLM-produced tool arguments
+//!
+//! Models routinely send `"true"` for a boolean, `"5000"` for
+//! `await_completion_ms` or `"exec"` for the `EXEC` action. Strict
+//! deserialization rejects these and wastes an agent turn on a fix the server
+//! could make itself. Routes built with `RouteConfig::coerce_arguments` run
+//! [`coerce_arguments`] over the raw arguments before they are deserialized
+//! into `T::Args`, guided by the tool's input schema:
+//!
+//! - a string holding a number where an integer or number is expected
+//! - `"true"` / `"false"` (any case) where a boolean is expected
+//! - a single value where an array is expected, wrapped in a one-element array
+//! - an enum value in the wrong case, when exactly one variant matches
+//!
+//! Values the schema already accepts are never touched, and nothing is guessed
+//! inside ambiguous unions. Every coercion is recorded and reported in the
+//! result `_meta` under [`ARGUMENT_COERCIONS_META_KEY`], so agents can learn
+//! the canonical form.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+/// `_meta` key under which a result reports the [`Coercion`]s applied to its arguments
+pub const ARGUMENT_COERCIONS_META_KEY: &str = "argumentCoercions";
+
+/// Which fix was applied to an argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CoercionKind {
+    /// `"5000"` → `5000`
+    StringToNumber,
+
+    /// `"true"` → `true`
+    StringToBoolean,
+
+    /// `"a.rs"` → `["a.rs"]`
+    SingleToArray,
+
+    /// `"exec"` → `"EXEC"`
+    EnumCase,
+}
+
+/// One argument value rewritten before deserialization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Coercion {
+    /// Location of the value, e.g. `await_completion_ms` or `paths[0]`
+    pub path: String,
+
+    /// Which fix was applied
+    pub kind: CoercionKind,
+
+    /// Value as sent
+    pub from: Value,
+
+    /// Value after coercion
+    pub to: Value,
+}
+
+/// Rewrite safe mismatches in `arguments` against the input `schema` (with its `$defs`)
+///
+/// Returns the coercions applied, in document order.
+pub fn coerce_arguments(schema: &Map<String, Value>, arguments: &mut Map<String, Value>) -> Vec<Coercion> {
+    let mut coercions = Vec::new();
+    coerce_properties(schema, schema, arguments, "", &mut coercions);
+    coercions
+}
+
+/// Follow `schema`'s `$ref`s into `root`'s `$defs`
+fn resolve<'a>(root: &'a Map<String, Value>, mut schema: &'a Value) -> &'a Value {
+    for _ in 0..32 {
+        let Some(target) = schema
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix("#/"))
+            .and_then(|pointer| {
+                let (first, rest) = pointer.split_once('/').unwrap_or((pointer, ""));
+                let value = root.get(first)?;
+                if rest.is_empty() { Some(value) } else { value.pointer(&format!("/{}", rest)) }
+            })
+        else {
+            break;
+        };
+        schema = target;
+    }
+    schema
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) }
+}
+
+fn coerce_properties(
+    root: &Map<String, Value>,
+    schema: &Map<String, Value>,
+    object: &mut Map<String, Value>,
+    path: &str,
+    out: &mut Vec<Coercion>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    let additional = schema.get("additionalProperties").filter(|values| values.is_object());
+    for (key, value) in object.iter_mut() {
+        if let Some(property) = properties.and_then(|properties| properties.get(key)).or(additional) {
+            coerce(root, property, value, &child_path(path, key), out);
+        }
+    }
+}
+
+fn coerce(root: &Map<String, Value>, schema: &Value, value: &mut Value, path: &str, out: &mut Vec<Coercion>) {
+    let schema = resolve(root, schema);
+    let Some(object) = schema.as_object() else {
+        return;
+    };
+
+    if let Some(Value::Array(variants)) = object.get("oneOf").or_else(|| object.get("anyOf")) {
+        let mut constants = Vec::new();
+        let mut others = Vec::new();
+        for variant in variants.iter().map(|variant| resolve(root, variant)) {
+            match variant.get("const") {
+                Some(Value::Null) => {}
+                Some(constant) => constants.push(constant),
+                None if variant.get("type").and_then(Value::as_str) == Some("null") => {}
+                None => others.push(variant),
+            }
+        }
+        match (constants.is_empty(), others.as_slice()) {
+            (false, []) => fold_case(constants, value, path, out),
+            // `Option<T>`: the only real variant
+            (true, [single]) if !value.is_null() => coerce(root, single, value, path, out),
+            _ => {}
+        }
+        return;
+    }
+
+    if value.is_null() {
+        return;
+    }
+    if let Some(Value::Array(values)) = object.get("enum") {
+        fold_case(values.iter().collect(), value, path, out);
+        return;
+    }
+    if let Some(constant) = object.get("const") {
+        fold_case(vec![constant], value, path, out);
+        return;
+    }
+
+    let types: Vec<&str> = match object.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+
+    if types.is_empty() || types.iter().any(|kind| accepts(kind, value)) {
+        match value {
+            Value::Object(fields) => coerce_properties(root, object, fields, path, out),
+            Value::Array(items) => {
+                if let Some(item_schema) = object.get("items") {
+                    for (index, item) in items.iter_mut().enumerate() {
+                        coerce(root, item_schema, item, &format!("{}[{}]", path, index), out);
+                    }
+                }
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    if let Value::String(text) = value
+        && let Some((kind, coerced)) = parse_scalar(&types, text)
+    {
+        out.push(Coercion {
+            path: path.to_string(),
+            kind,
+            from: value.clone(),
+            to: coerced.clone(),
+        });
+        *value = coerced;
+        return;
+    }
+
+    if types.contains(&"array") {
+        let from = value.take();
+        let position = out.len();
+        let mut item = from.clone();
+        if let Some(item_schema) = object.get("items") {
+            coerce(root, item_schema, &mut item, &format!("{}[0]", path), out);
+        }
+        *value = Value::Array(vec![item]);
+        out.insert(
+            position,
+            Coercion {
+                path: path.to_string(),
+                kind: CoercionKind::SingleToArray,
+                from,
+                to: value.clone(),
+            },
+        );
+    }
+}
+
+/// Whether JSON type `kind` accepts `value` as is
+fn accepts(kind: &str, value: &Value) -> bool {
+    match kind {
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+/// Parse `text` as the first of `types` it represents exactly
+fn parse_scalar(types: &[&str], text: &str) -> Option<(CoercionKind, Value)> {
+    let trimmed = text.trim();
+    for kind in types {
+        let parsed = match *kind {
+            "integer" => trimmed
+                .parse::<u64>()
+                .map(Value::from)
+                .or_else(|_| trimmed.parse::<i64>().map(Value::from))
+                .ok()
+                .map(|number| (CoercionKind::StringToNumber, number)),
+            "number" => trimmed
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map(|number| (CoercionKind::StringToNumber, Value::Number(number))),
+            "boolean" if trimmed.eq_ignore_ascii_case("true") => Some((CoercionKind::StringToBoolean, Value::Bool(true))),
+            "boolean" if trimmed.eq_ignore_ascii_case("false") => Some((CoercionKind::StringToBoolean, Value::Bool(false))),
+            _ => None,
+        };
+        if parsed.is_some() {
+            return parsed;
+        }
+    }
+    None
+}
+
+/// Replace a string matching exactly one of `allowed` up to ASCII case by that value
+fn fold_case(allowed: Vec<&Value>, value: &mut Value, path: &str, out: &mut Vec<Coercion>) {
+    let Value::String(text) = value else {
+        return;
+    };
+    if allowed.iter().any(|candidate| candidate.as_str() == Some(text.as_str())) {
+        return;
+    }
+    let mut matches = allowed
+        .iter()
+        .filter(|candidate| candidate.as_str().is_some_and(|candidate| candidate.eq_ignore_ascii_case(text.trim())));
+    if let (Some(&canonical), None) = (matches.next(), matches.next()) {
+        let canonical = canonical.clone();
+        out.push(Coercion {
+            path: path.to_string(),
+            kind: CoercionKind::EnumCase,
+            from: std::mem::replace(value, canonical.clone()),
+            to: canonical,
+        });
+    }
+}
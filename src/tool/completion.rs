@@ -0,0 +1,404 @@
+//! Argument-value completion for tools, prompts and resource templates
+//!
+//! MCP clients send `completion/complete` requests while the user is typing an
+//! argument. A [`CompletionRouter`] answers them from three sources:
+//!
+//! - **Prompts** (`ref/prompt`): every tool's `{tool}_help` prompt completes
+//!   through [`PromptProvider::complete_prompt_argument`](super::PromptProvider::complete_prompt_argument).
+//! - **Tools**: tools implementing [`ArgumentCompleter`] complete values of
+//!   their Args, keyed by JSON pointer (e.g. `"/target"`). MCP has no tool
+//!   reference type, so clients address them as `ref/resource` with the URI
+//!   returned by [`tool_reference`] (`tool://{name}`).
+//! - **Resource templates** (`ref/resource`): any [`TemplateCompleter`]
+//!   registered for a URI template, keyed by template variable name.
+//!
+//! Tool and template completers run asynchronously with a
+//! [`ToolExecutionContext`], so they can resolve values relative to the
+//! client's `pwd` or `git_root`.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let (tools, completions) = ToolRouterBuilder::<MyServer>::new()
+//!     .tool(ReadFileTool::new())
+//!     .completing_tool(GitCheckoutTool::new())
+//!     .build_with_completions();
+//!
+//! let completions = completions.resource_template("file:///{path}", |query: CompletionQuery, ctx: ToolExecutionContext| async move {
+//!     Ok(complete_path(&query.prefix, ctx.pwd()).await)
+//! });
+//!
+//! // In the server's ServerHandler impl:
+//! async fn complete(&self, request: CompleteRequestParam, context: RequestContext<RoleServer>)
+//!     -> Result<CompleteResult, ErrorData>
+//! {
+//!     self.completions.complete(request, context).await
+//! }
+//! ```
+
+use async_trait::async_trait;
+use rmcp::model::{CompleteRequestParam, CompleteResult, CompletionInfo, Reference};
+use rmcp::service::RequestContext;
+use rmcp::RoleServer;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::error::McpError;
+use super::traits::{Tool, ToolExecutionContext, ToolPrompts};
+
+/// URI scheme used to address a tool in a `ref/resource` completion reference
+pub const TOOL_REFERENCE_SCHEME: &str = "tool://";
+
+/// Completion reference addressing the arguments of tool `name`
+#[must_use]
+pub fn tool_reference(name: &str) -> Reference {
+    Reference::for_resource(format!("{}{}", TOOL_REFERENCE_SCHEME, name))
+}
+
+// ============================================================================
+// COMPLETION QUERY
+// ============================================================================
+
+/// One argument being completed.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionQuery {
+    /// Argument being completed: a JSON pointer into Args (e.g. `"/target"`)
+    /// for tools, the variable name for resource templates
+    pub argument: String,
+
+    /// Partial value typed so far
+    pub prefix: String,
+
+    /// Values the client already resolved for other arguments, keyed the same
+    /// way as `argument`
+    pub resolved: HashMap<String, String>,
+}
+
+impl CompletionQuery {
+    /// Previously resolved value of another argument
+    #[must_use]
+    pub fn resolved(&self, argument: &str) -> Option<&str> {
+        self.resolved.get(argument).map(String::as_str)
+    }
+}
+
+// ============================================================================
+// COMPLETER TRAITS
+// ============================================================================
+
+/// Optional extension of [`Tool`] completing values of its arguments.
+///
+/// Arguments are addressed by JSON pointer into the Args schema, so nested
+/// fields complete too (`"/options/branch"`). Completers receive the prefix
+/// typed so far and return candidate values; the router deduplicates and
+/// caps the list at [`CompletionInfo::MAX_VALUES`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl ArgumentCompleter for GitCheckoutTool {
+///     fn completable_arguments() -> &'static [&'static str] {
+///         &["/path", "/target"]
+///     }
+///
+///     async fn complete_argument(&self, query: CompletionQuery, ctx: ToolExecutionContext)
+///         -> Result<Vec<String>, McpError>
+///     {
+///         match query.argument.as_str() {
+///             "/path" => Ok(complete_path(&query.prefix, ctx.pwd()).await),
+///             "/target" => {
+///                 let repo = query.resolved("/path").map(PathBuf::from).or(ctx.git_root().map(Path::to_path_buf));
+///                 Ok(list_branches(repo, &query.prefix).await?)
+///             }
+///             _ => Ok(vec![]),
+///         }
+///     }
+/// }
+/// ```
+pub trait ArgumentCompleter: Tool {
+    /// JSON pointers into Args that [`complete_argument`](Self::complete_argument)
+    /// can complete.
+    ///
+    /// Checked against the Args schema when the tool is registered.
+    fn completable_arguments() -> &'static [&'static str];
+
+    /// Complete the value of `query.argument`
+    fn complete_argument(
+        &self,
+        query: CompletionQuery,
+        ctx: ToolExecutionContext,
+    ) -> impl Future<Output = Result<Vec<String>, McpError>> + Send;
+}
+
+/// Completes the variables of a resource URI template.
+///
+/// Implemented for async closures taking `(CompletionQuery, ToolExecutionContext)`.
+#[async_trait]
+pub trait TemplateCompleter: Send + Sync + 'static {
+    /// Complete the value of template variable `query.argument`
+    async fn complete(&self, query: CompletionQuery, ctx: ToolExecutionContext) -> Result<Vec<String>, McpError>;
+}
+
+#[async_trait]
+impl<F, Fut> TemplateCompleter for F
+where
+    F: Fn(CompletionQuery, ToolExecutionContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<String>, McpError>> + Send + 'static,
+{
+    async fn complete(&self, query: CompletionQuery, ctx: ToolExecutionContext) -> Result<Vec<String>, McpError> {
+        self(query, ctx).await
+    }
+}
+
+/// Type-erased [`ArgumentCompleter`] stored by the router
+struct ToolCompleter<T: ArgumentCompleter>(Arc<T>);
+
+#[async_trait]
+impl<T: ArgumentCompleter> TemplateCompleter for ToolCompleter<T> {
+    async fn complete(&self, query: CompletionQuery, ctx: ToolExecutionContext) -> Result<Vec<String>, McpError> {
+        if !T::completable_arguments().contains(&query.argument.as_str()) {
+            return Ok(Vec::new());
+        }
+        self.0.complete_argument(query, ctx).await
+    }
+}
+
+type PromptCompleter = fn(&str, &str) -> Vec<String>;
+
+// ============================================================================
+// COMPLETION ROUTER
+// ============================================================================
+
+/// Serves `completion/complete` requests for prompts, tools and resource templates.
+#[derive(Clone, Default)]
+pub struct CompletionRouter {
+    prompts: HashMap<String, PromptCompleter>,
+    tools: HashMap<String, Arc<dyn TemplateCompleter>>,
+    templates: HashMap<String, Arc<dyn TemplateCompleter>>,
+}
+
+impl CompletionRouter {
+    /// Create an empty router
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Complete the arguments of `T`'s help prompt
+    #[must_use]
+    pub fn prompt<T: Tool>(mut self) -> Self {
+        self.add_prompt::<T>();
+        self
+    }
+
+    /// Complete the arguments of tool `T`
+    ///
+    /// # Panics
+    /// Panics if a pointer in [`ArgumentCompleter::completable_arguments`] does
+    /// not resolve to a property of `T::Args`.
+    #[must_use]
+    pub fn tool<T: ArgumentCompleter>(mut self, tool: Arc<T>) -> Self {
+        self.add_tool(tool);
+        self
+    }
+
+    /// Complete the variables of resource URI `template` (e.g. `"file:///{path}"`)
+    #[must_use]
+    pub fn resource_template(mut self, template: impl Into<String>, completer: impl TemplateCompleter) -> Self {
+        self.templates.insert(template.into(), Arc::new(completer));
+        self
+    }
+
+    pub(crate) fn add_prompt<T: Tool>(&mut self) {
+        self.prompts
+            .insert(T::prompt_name().into_owned(), <T as ToolPrompts>::complete_prompt_argument);
+    }
+
+    pub(crate) fn add_tool<T: ArgumentCompleter>(&mut self, tool: Arc<T>) {
+        let schema = Value::Object((*T::input_schema()).clone());
+        for pointer in T::completable_arguments() {
+            assert!(
+                schema_has_pointer(&schema, pointer),
+                "{}: completable argument {:?} is not a property of its Args",
+                T::name(),
+                pointer
+            );
+        }
+        self.tools.insert(T::name().to_string(), Arc::new(ToolCompleter(tool)));
+    }
+
+    /// Complete a prompt argument (prompts complete without a request context)
+    #[must_use]
+    pub fn complete_prompt(&self, prompt: &str, argument: &str, prefix: &str) -> Vec<String> {
+        self.prompts
+            .get(prompt)
+            .map(|complete| complete(argument, prefix))
+            .unwrap_or_default()
+    }
+
+    /// Complete an argument of `tool` (`query.argument` is a JSON pointer or a
+    /// top-level property name)
+    pub async fn complete_tool(
+        &self,
+        tool: &str,
+        mut query: CompletionQuery,
+        ctx: ToolExecutionContext,
+    ) -> Result<Vec<String>, McpError> {
+        let Some(completer) = self.tools.get(tool) else {
+            return Ok(Vec::new());
+        };
+        query.argument = to_pointer(&query.argument);
+        query.resolved = query
+            .resolved
+            .into_iter()
+            .map(|(argument, value)| (to_pointer(&argument), value))
+            .collect();
+        completer.complete(query, ctx).await
+    }
+
+    /// Answer a `completion/complete` request.
+    ///
+    /// Unknown references complete to an empty list, as the MCP spec suggests.
+    pub async fn complete(
+        &self,
+        request: CompleteRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CompleteResult, rmcp::ErrorData> {
+        let query = CompletionQuery {
+            argument: request.argument.name,
+            prefix: request.argument.value,
+            resolved: request.context.and_then(|c| c.arguments).unwrap_or_default(),
+        };
+
+        let values = match request.r#ref {
+            Reference::Prompt(prompt) => self.complete_prompt(&prompt.name, &query.argument, &query.prefix),
+            Reference::Resource(resource) => {
+                let ctx = ToolExecutionContext::from_request_context(&context);
+                if let Some(tool) = resource.uri.strip_prefix(TOOL_REFERENCE_SCHEME) {
+                    self.complete_tool(tool, query, ctx).await?
+                } else if let Some(completer) = self.templates.get(&resource.uri) {
+                    completer.complete(query, ctx).await?
+                } else {
+                    Vec::new()
+                }
+            }
+        };
+
+        Ok(CompleteResult { completion: completion_info(values) })
+    }
+}
+
+/// Deduplicate `values` and cap them at [`CompletionInfo::MAX_VALUES`]
+fn completion_info(mut values: Vec<String>) -> CompletionInfo {
+    let mut seen = std::collections::HashSet::new();
+    values.retain(|value| seen.insert(value.clone()));
+
+    let total = values.len();
+    let has_more = total > CompletionInfo::MAX_VALUES;
+    values.truncate(CompletionInfo::MAX_VALUES);
+
+    CompletionInfo {
+        values,
+        total: Some(u32::try_from(total).unwrap_or(u32::MAX)),
+        has_more: Some(has_more),
+    }
+}
+
+/// `"target"` → `"/target"`; pointers are returned unchanged
+fn to_pointer(argument: &str) -> String {
+    if argument.starts_with('/') {
+        argument.to_string()
+    } else {
+        format!("/{}", argument.replace('~', "~0").replace('/', "~1"))
+    }
+}
+
+/// Does JSON pointer `pointer` name a property reachable through `properties`?
+///
+/// Follows `$ref`, `allOf`, `anyOf` and `oneOf`, so `Option` and flattened
+/// fields resolve.
+fn schema_has_pointer(root: &Value, pointer: &str) -> bool {
+    let Some(path) = pointer.strip_prefix('/') else {
+        return false;
+    };
+    let tokens: Vec<String> = path.split('/').map(|t| t.replace("~1", "/").replace("~0", "~")).collect();
+    resolves(root, root, &tokens, 0)
+}
+
+fn resolves(schema: &Value, root: &Value, tokens: &[String], depth: usize) -> bool {
+    let Some((first, rest)) = tokens.split_first() else {
+        return true;
+    };
+    let Some(obj) = schema.as_object() else {
+        return false;
+    };
+    if depth > 16 {
+        return false;
+    }
+
+    if let Some(property) = obj.get("properties").and_then(|p| p.get(first))
+        && resolves(property, root, rest, depth + 1)
+    {
+        return true;
+    }
+    if let Some(target) = obj
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        && resolves(target, root, tokens, depth + 1)
+    {
+        return true;
+    }
+    ["allOf", "anyOf", "oneOf"].iter().any(|key| {
+        obj.get(*key)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .any(|branch| resolves(branch, root, tokens, depth + 1))
+    })
+}
+
+// ============================================================================
+// COMMON COMPLETERS
+// ============================================================================
+
+/// Complete a filesystem path.
+///
+/// Relative prefixes resolve against `base` (typically
+/// [`ToolExecutionContext::pwd`]); a trailing `/` is appended to directories.
+/// Hidden entries are only offered once the typed name starts with `.`.
+pub async fn complete_path(prefix: &str, base: Option<&Path>) -> Vec<String> {
+    let (dir, partial) = match prefix.rfind('/') {
+        Some(i) => (&prefix[..=i], &prefix[i + 1..]),
+        None => ("", prefix),
+    };
+
+    let mut search = match base {
+        Some(base) if !Path::new(dir).is_absolute() => base.join(dir),
+        _ => Path::new(dir).to_path_buf(),
+    };
+    if search.as_os_str().is_empty() {
+        search = Path::new(".").to_path_buf();
+    }
+
+    let Ok(mut entries) = tokio::fs::read_dir(&search).await else {
+        return Vec::new();
+    };
+
+    let mut values = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !name.starts_with(partial) || (name.starts_with('.') && !partial.starts_with('.')) {
+            continue;
+        }
+        let is_dir = entry.file_type().await.is_ok_and(|t| t.is_dir());
+        values.push(format!("{}{}{}", dir, name, if is_dir { "/" } else { "" }));
+    }
+
+    values.sort();
+    values
+}
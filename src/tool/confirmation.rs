@@ -0,0 +1,213 @@
+//! Confirmation gate for destructive tool calls
+//!
+//! `Tool::destructive()` is only an annotation. A [`ConfirmationPolicy`] set on
+//! a [`ToolRouterBuilder`](super::router::ToolRouterBuilder) enforces it: before
+//! a call whose Args describe a [`DestructiveAction`]
+//! (see [`ToolArgs::destructive_action`](crate::ToolArgs::destructive_action)),
+//! the server sends an MCP elicitation request describing what will happen,
+//! and the call only proceeds if the user accepts.
+//!
+//! Clients that do not support elicitation fall back to
+//! [`ConfirmationPolicy::fallback`]. A declined call fails with
+//! [`McpError::ConfirmationDeclined`], which tool-call middlewares observe like
+//! any other error.
+
+use rmcp::model::{CreateElicitationRequestParam, ElicitationAction, ElicitationSchema};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::error::McpError;
+use super::traits::ToolExecutionContext;
+
+/// Property of the elicitation form the user must set to proceed
+const CONFIRM_PROPERTY: &str = "confirm";
+
+// ============================================================================
+// DESTRUCTIVE ACTION SUMMARY
+// ============================================================================
+
+/// Structured summary of what a destructive call will do, shown to the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DestructiveAction {
+    /// One-line description (e.g. "Hard reset to HEAD~3")
+    pub summary: String,
+
+    /// What is affected (path, branch, PID, database, ...)
+    pub target: String,
+
+    /// Consequences the user should know about
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effects: Vec<String>,
+
+    /// Whether the effects can be undone afterwards
+    pub reversible: bool,
+}
+
+impl DestructiveAction {
+    /// Irreversible action on `target`
+    #[must_use]
+    pub fn new(summary: impl Into<String>, target: impl Into<String>) -> Self {
+        Self {
+            summary: summary.into(),
+            target: target.into(),
+            effects: Vec::new(),
+            reversible: false,
+        }
+    }
+
+    /// Add a consequence
+    #[must_use]
+    pub fn effect(mut self, effect: impl Into<String>) -> Self {
+        self.effects.push(effect.into());
+        self
+    }
+
+    /// Mark whether the action can be undone
+    #[must_use]
+    pub fn reversible(mut self, reversible: bool) -> Self {
+        self.reversible = reversible;
+        self
+    }
+
+    /// Message shown in the elicitation request for a call to `tool`
+    #[must_use]
+    pub fn message(&self, tool: &str) -> String {
+        let mut message = format!("{} wants to: {}\nTarget: {}", tool, self.summary, self.target);
+        for effect in &self.effects {
+            message.push_str("\n- ");
+            message.push_str(effect);
+        }
+        if !self.reversible {
+            message.push_str("\nThis cannot be undone.");
+        }
+        message
+    }
+}
+
+// ============================================================================
+// POLICY
+// ============================================================================
+
+/// Decision applied when the client cannot be asked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfirmationFallback {
+    /// Run the call without confirmation
+    Allow,
+    /// Refuse the call (default)
+    #[default]
+    Deny,
+}
+
+/// How destructive calls are confirmed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let router = ToolRouterBuilder::<MyServer>::new()
+///     .confirmation(ConfirmationPolicy::default().fallback(ConfirmationFallback::Allow))
+///     .tool(GitResetTool::new())
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfirmationPolicy {
+    /// Decision when the client does not support elicitation
+    pub fallback: ConfirmationFallback,
+
+    /// How long to wait for the user before treating the request as declined
+    /// (None waits until the call is cancelled)
+    pub timeout: Option<Duration>,
+}
+
+impl Default for ConfirmationPolicy {
+    fn default() -> Self {
+        Self {
+            fallback: ConfirmationFallback::Deny,
+            timeout: Some(Duration::from_secs(300)),
+        }
+    }
+}
+
+impl ConfirmationPolicy {
+    /// Set the decision used when the client does not support elicitation
+    #[must_use]
+    pub fn fallback(mut self, fallback: ConfirmationFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Set how long to wait for the user (None waits until cancelled)
+    #[must_use]
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Ask the user to confirm `action` before `tool` runs.
+    ///
+    /// Returns `Ok(())` only if the user accepted (or the fallback allows the
+    /// call when the client cannot be asked).
+    pub async fn confirm(
+        &self,
+        tool: &str,
+        action: &DestructiveAction,
+        ctx: &ToolExecutionContext,
+    ) -> Result<(), McpError> {
+        let peer = ctx.peer();
+        if !peer.supports_elicitation() {
+            return match self.fallback {
+                ConfirmationFallback::Allow => Ok(()),
+                ConfirmationFallback::Deny => Err(McpError::ConfirmationDeclined(format!(
+                    "{} requires confirmation but the client does not support elicitation ({})",
+                    tool, action.summary
+                ))),
+            };
+        }
+
+        let params = CreateElicitationRequestParam {
+            message: action.message(tool),
+            requested_schema: confirmation_schema(),
+        };
+
+        let response = tokio::select! {
+            biased;
+            () = ctx.cancellation_token().cancelled() => {
+                return Err(McpError::cancelled(format!("{} was cancelled while awaiting confirmation", tool)));
+            }
+            response = peer.create_elicitation_with_timeout(params, self.timeout) => response,
+        };
+
+        let confirmed = match response {
+            Ok(result) => {
+                result.action == ElicitationAction::Accept
+                    && result
+                        .content
+                        .as_ref()
+                        .and_then(|content| content.get(CONFIRM_PROPERTY))
+                        .and_then(serde_json::Value::as_bool)
+                        .unwrap_or(false)
+            }
+            Err(e) => {
+                return Err(McpError::ConfirmationDeclined(format!(
+                    "{} could not be confirmed: {}",
+                    tool, e
+                )));
+            }
+        };
+
+        if confirmed {
+            Ok(())
+        } else {
+            Err(McpError::ConfirmationDeclined(format!("user declined {}: {}", tool, action.summary)))
+        }
+    }
+}
+
+/// Form with a single required "Proceed?" checkbox
+fn confirmation_schema() -> ElicitationSchema {
+    ElicitationSchema::builder()
+        .required_bool_with(CONFIRM_PROPERTY, |schema| {
+            schema.title("Proceed?").description("Check to run this operation")
+        })
+        .build_unchecked()
+}
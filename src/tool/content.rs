@@ -0,0 +1,85 @@
+//! Non-text content parts carried by a [`ToolResponse`](super::ToolResponse)
+//!
+//! The display text and typed metadata always occupy the first content blocks
+//! (see [`ToolResponse::into_call_tool_result`](super::ToolResponse::into_call_tool_result)).
+//! Images, audio, embedded resources and resource links follow them, in the
+//! order they were added, so the display/metadata layout never shifts and
+//! clients that only read text are unaffected.
+//!
+//! Vision-capable clients render image parts directly instead of receiving
+//! base64 inside the metadata JSON.
+
+use rmcp::model::{
+    AnnotateAble, Content, RawAudioContent, RawContent, RawEmbeddedResource, RawResource, ResourceContents,
+};
+
+/// One non-text content block of a tool result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentPart {
+    /// Base64-encoded image
+    Image { data: String, mime_type: String },
+
+    /// Base64-encoded audio
+    Audio { data: String, mime_type: String },
+
+    /// Resource contents embedded in the result
+    Resource(ResourceContents),
+
+    /// Link to a resource the client can read or subscribe to
+    ResourceLink(RawResource),
+}
+
+impl ContentPart {
+    /// Image from base64 `data` (e.g. `image/png`)
+    #[must_use]
+    pub fn image(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+
+    /// Audio from base64 `data` (e.g. `audio/wav`)
+    #[must_use]
+    pub fn audio(data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self::Audio {
+            data: data.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+
+    /// Embedded resource contents
+    #[must_use]
+    pub fn resource(contents: ResourceContents) -> Self {
+        Self::Resource(contents)
+    }
+
+    /// Link to a resource (see [`ResourceUri::link`](super::ResourceUri::link))
+    #[must_use]
+    pub fn link(resource: RawResource) -> Self {
+        Self::ResourceLink(resource)
+    }
+
+    /// The MCP content block
+    #[must_use]
+    pub fn into_content(self) -> Content {
+        match self {
+            Self::Image { data, mime_type } => Content::image(data, mime_type),
+            Self::Audio { data, mime_type } => RawContent::Audio(RawAudioContent { data, mime_type }).no_annotation(),
+            Self::Resource(resource) => RawContent::Resource(RawEmbeddedResource { meta: None, resource }).no_annotation(),
+            Self::ResourceLink(resource) => RawContent::resource_link(resource).no_annotation(),
+        }
+    }
+
+    /// Parse a content block; `None` for text, which belongs to display or metadata
+    #[must_use]
+    pub fn from_content(content: &Content) -> Option<Self> {
+        match &**content {
+            RawContent::Text(_) => None,
+            RawContent::Image(image) => Some(Self::image(image.data.clone(), image.mime_type.clone())),
+            RawContent::Audio(audio) => Some(Self::audio(audio.data.clone(), audio.mime_type.clone())),
+            RawContent::Resource(embedded) => Some(Self::Resource(embedded.resource.clone())),
+            RawContent::ResourceLink(resource) => Some(Self::ResourceLink(resource.clone())),
+        }
+    }
+}
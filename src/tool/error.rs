@@ -1,5 +1,14 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
+use super::traits::ResponseMode;
+
+/// `_meta` key holding the [`ToolErrorData`] of an error result in the
+/// `Structured` and `Both` response modes
+pub const TOOL_ERROR_META_KEY: &str = "toolError";
+
 #[derive(Error, Debug)]
 pub enum McpError {
     #[error("Authentication error: {0}")]
@@ -8,9 +17,33 @@ pub enum McpError {
     #[error("Permission denied: {0}")]
     PermissionDenied(String),
 
+    /// The client cancelled the call (or disconnected) before it finished
+    #[error("Cancelled: {message}")]
+    Cancelled {
+        message: String,
+        /// Output the tool streamed before it was stopped
+        partial_output: Option<String>,
+    },
+
+    /// The call ran longer than its timeout and was stopped
+    #[error("Timed out after {}ms", timeout.as_millis())]
+    TimedOut {
+        timeout: Duration,
+        /// Output the tool streamed before it was stopped
+        partial_output: Option<String>,
+    },
+
+    /// The user (or the fallback policy) did not confirm a destructive call
+    #[error("Confirmation declined: {0}")]
+    ConfirmationDeclined(String),
+
     #[error("Invalid arguments: {0}")]
     InvalidArguments(String),
 
+    /// Invalid value for a specific argument
+    #[error("Invalid argument '{argument}': {message}")]
+    InvalidArgument { argument: String, message: String },
+
     #[error("Tool not found: {0}")]
     ToolNotFound(String),
 
@@ -20,8 +53,9 @@ pub enum McpError {
     #[error("Operation not supported in read-only mode: {0}")]
     ReadOnlyViolation(String),
 
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    /// Rate limit exceeded, with the delay the upstream service asked for (if any)
+    #[error("Rate limit exceeded{}", retry_after.map(|d| format!(", retry after {}ms", d.as_millis())).unwrap_or_default())]
+    RateLimitExceeded { retry_after: Option<Duration> },
 
     #[error("Network error: {0}")]
     Network(String),
@@ -29,6 +63,10 @@ pub enum McpError {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    /// Tool output does not match its declared output schema
+    #[error("Output schema violation: {0}")]
+    OutputSchema(String),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -82,17 +120,264 @@ impl McpError {
     pub fn resource_not_found(msg: impl Into<String>) -> Self {
         Self::ResourceNotFound(msg.into())
     }
+
+    /// Helper to create an invalid argument error naming the offending argument
+    pub fn invalid_argument(argument: impl Into<String>, msg: impl Into<String>) -> Self {
+        Self::InvalidArgument {
+            argument: argument.into(),
+            message: msg.into(),
+        }
+    }
+
+    /// Helper to create a cancellation error
+    pub fn cancelled(msg: impl Into<String>) -> Self {
+        Self::Cancelled {
+            message: msg.into(),
+            partial_output: None,
+        }
+    }
+
+    /// Helper to create a timeout error
+    pub fn timed_out(timeout: Duration) -> Self {
+        Self::TimedOut {
+            timeout,
+            partial_output: None,
+        }
+    }
+
+    /// Attach the output streamed before a cancellation or timeout
+    /// (other errors are returned unchanged)
+    #[must_use]
+    pub fn with_partial_output(mut self, output: Option<String>) -> Self {
+        if let Self::Cancelled { partial_output, .. } | Self::TimedOut { partial_output, .. } = &mut self {
+            *partial_output = output.filter(|o| !o.is_empty());
+        }
+        self
+    }
+
+    /// Helper to create a rate limit error with a retry delay
+    pub fn rate_limited(retry_after: Duration) -> Self {
+        Self::RateLimitExceeded {
+            retry_after: Some(retry_after),
+        }
+    }
+
+    /// Whether this error is a protocol-level problem rather than a tool failure.
+    ///
+    /// Protocol errors are returned as JSON-RPC errors; everything else is
+    /// reported as a `CallToolResult` with `is_error: true`.
+    #[must_use]
+    pub fn is_protocol_error(&self) -> bool {
+        matches!(self, Self::ToolNotFound(_))
+    }
+
+    /// Machine-readable error kind
+    #[must_use]
+    pub fn kind(&self) -> ToolErrorKind {
+        match self {
+            Self::Authentication(_) => ToolErrorKind::Authentication,
+            Self::PermissionDenied(_) => ToolErrorKind::PermissionDenied,
+            Self::ConfirmationDeclined(_) => ToolErrorKind::ConfirmationDeclined,
+            Self::Cancelled { .. } => ToolErrorKind::Cancelled,
+            Self::TimedOut { .. } => ToolErrorKind::TimedOut,
+            Self::InvalidArguments(_) | Self::InvalidArgument { .. } => ToolErrorKind::InvalidArguments,
+            Self::ToolNotFound(_) => ToolErrorKind::ToolNotFound,
+            Self::ResourceNotFound(_) => ToolErrorKind::ResourceNotFound,
+            Self::ReadOnlyViolation(_) => ToolErrorKind::ReadOnlyViolation,
+            Self::RateLimitExceeded { .. } => ToolErrorKind::RateLimited,
+            Self::Network(_) => ToolErrorKind::Network,
+            Self::Serialization(_) => ToolErrorKind::Serialization,
+            Self::OutputSchema(_) => ToolErrorKind::OutputSchema,
+            Self::Io(_) => ToolErrorKind::Io,
+            Self::SearchIndex(_) => ToolErrorKind::SearchIndex,
+            Self::Manifest(_) => ToolErrorKind::Manifest,
+            Self::InvalidUrl(_) => ToolErrorKind::InvalidUrl,
+            Self::SearchEngine(_) => ToolErrorKind::SearchEngine,
+            Self::Other(_) => ToolErrorKind::Internal,
+        }
+    }
+
+    /// Build the machine-readable payload describing this error
+    #[must_use]
+    pub fn error_data(&self) -> ToolErrorData {
+        let kind = self.kind();
+        let retry_after_ms = match self {
+            Self::RateLimitExceeded { retry_after } => {
+                retry_after.map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+            }
+            _ => None,
+        };
+        let argument = match self {
+            Self::InvalidArgument { argument, .. } => Some(argument.clone()),
+            _ => None,
+        };
+        let timeout_ms = match self {
+            Self::TimedOut { timeout, .. } => Some(u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX)),
+            _ => None,
+        };
+        let partial_output = match self {
+            Self::Cancelled { partial_output, .. } | Self::TimedOut { partial_output, .. } => partial_output.clone(),
+            _ => None,
+        };
+
+        ToolErrorData {
+            kind,
+            message: self.to_string(),
+            retryable: kind.is_retryable(),
+            retry_after_ms,
+            argument,
+            timeout_ms,
+            partial_output,
+            hint: kind.hint().map(str::to_string),
+        }
+    }
+
+    /// Convert into a `CallToolResult` with `is_error: true`.
+    ///
+    /// Follows the same layout as successful responses:
+    /// - `content[0]`: Human-readable error message
+    /// - `content[1]`: [`ToolErrorData`] as pretty-printed JSON
+    pub fn into_call_tool_result(self) -> rmcp::model::CallToolResult {
+        self.into_call_tool_result_with_mode(ResponseMode::Text)
+    }
+
+    /// Convert into a `CallToolResult` with `is_error: true`, placing the
+    /// [`ToolErrorData`] payload according to `mode` (see [`ResponseMode`]).
+    ///
+    /// `structured_content` is never set: it must match the tool's
+    /// `outputSchema`, which describes successful output. Where `mode` would
+    /// use it, the payload goes to `_meta` under [`TOOL_ERROR_META_KEY`]
+    /// instead:
+    /// - `Text`: message and JSON payload in `content`
+    /// - `Structured`: message in `content`, payload in `_meta`
+    /// - `Both`: message and JSON payload in `content`, payload in `_meta`
+    pub fn into_call_tool_result_with_mode(self, mode: ResponseMode) -> rmcp::model::CallToolResult {
+        use rmcp::model::{CallToolResult, Content, Meta};
+
+        let data = self.error_data();
+        let mut content = vec![Content::text(data.message.clone())];
+        // ToolErrorData always serializes (plain strings, bools and integers)
+        let payload = serde_json::to_value(&data).unwrap_or_default();
+
+        if mode != ResponseMode::Structured
+            && let Ok(json) = serde_json::to_string_pretty(&payload)
+        {
+            content.push(Content::text(json));
+        }
+
+        let mut result = CallToolResult::error(content);
+        if matches!(mode, ResponseMode::Structured | ResponseMode::Both) {
+            let mut meta = Meta::new();
+            meta.0.insert(TOOL_ERROR_META_KEY.to_string(), payload);
+            result.meta = Some(meta);
+        }
+        result
+    }
+}
+
+// ============================================================================
+// MACHINE-READABLE ERROR PAYLOAD
+// ============================================================================
+
+/// Error category reported in [`ToolErrorData`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolErrorKind {
+    Authentication,
+    PermissionDenied,
+    ConfirmationDeclined,
+    Cancelled,
+    TimedOut,
+    InvalidArguments,
+    ToolNotFound,
+    ResourceNotFound,
+    ReadOnlyViolation,
+    RateLimited,
+    Network,
+    Serialization,
+    OutputSchema,
+    Io,
+    SearchIndex,
+    Manifest,
+    InvalidUrl,
+    SearchEngine,
+    Internal,
+}
+
+impl ToolErrorKind {
+    /// Whether retrying the same call later may succeed
+    #[must_use]
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Network | Self::TimedOut)
+    }
+
+    /// Remediation hint for agents
+    #[must_use]
+    pub fn hint(self) -> Option<&'static str> {
+        match self {
+            Self::Authentication => Some("Check that credentials are configured and valid"),
+            Self::PermissionDenied => Some("The operation is not permitted; do not retry with the same arguments"),
+            Self::ConfirmationDeclined => Some("The user did not approve this operation; ask them before trying again"),
+            Self::Cancelled => Some("The call was cancelled by the client; do not retry unless asked to"),
+            Self::TimedOut => Some("Retry with narrower arguments or a longer timeout (timeoutMs in _meta)"),
+            Self::InvalidArguments => Some("Fix the arguments and call the tool again"),
+            Self::ResourceNotFound => Some("Verify the path, name or identifier exists"),
+            Self::ReadOnlyViolation => Some("This server is read-only; use a read-only tool instead"),
+            Self::RateLimited => Some("Wait before retrying (see retry_after_ms when present)"),
+            Self::Network => Some("Transient network failure; retrying may succeed"),
+            Self::InvalidUrl => Some("Provide a valid absolute URL"),
+            _ => None,
+        }
+    }
+}
+
+/// Machine-readable payload for tool failures.
+///
+/// Returned in `content[1]` of a `CallToolResult` with `is_error: true`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolErrorData {
+    /// Error category
+    pub kind: ToolErrorKind,
+
+    /// Human-readable error message
+    pub message: String,
+
+    /// Whether retrying the same call later may succeed
+    pub retryable: bool,
+
+    /// Milliseconds to wait before retrying (rate limits)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+
+    /// Name of the offending argument (invalid arguments)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub argument: Option<String>,
+
+    /// Timeout that was exceeded, in milliseconds (timeouts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+
+    /// Output streamed before the call was stopped (cancellations and timeouts)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_output: Option<String>,
+
+    /// Remediation hint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
 }
 
 impl From<McpError> for rmcp::ErrorData {
     fn from(err: McpError) -> Self {
         match err {
             McpError::InvalidArguments(msg) => Self::invalid_params(msg, None),
+            McpError::InvalidArgument { .. } => Self::invalid_params(err.to_string(), None),
             McpError::ToolNotFound(msg) => {
                 Self::new(rmcp::model::ErrorCode::METHOD_NOT_FOUND, msg, None)
             }
             McpError::ResourceNotFound(msg) => Self::resource_not_found(msg, None),
-            McpError::PermissionDenied(msg) | McpError::ReadOnlyViolation(msg) => {
+            McpError::PermissionDenied(msg)
+            | McpError::ConfirmationDeclined(msg)
+            | McpError::ReadOnlyViolation(msg) => {
                 Self::internal_error(format!("Unauthorized: {msg}"), None)
             }
             // New variants map to internal_error (semantics for Rust, not MCP)
@@ -0,0 +1,307 @@
+//! Composable middleware around tool execution
+//!
+//! A `ToolMiddleware` observes (and may alter) every call routed through a
+//! `ToolHandler`. Middlewares are registered on a [`ToolRouterBuilder`] and
+//! applied to every tool route it builds, replacing the per-server wrapper
+//! code for timing, branding, history recording and error mapping.
+//!
+//! # Ordering
+//!
+//! Middlewares wrap execution like an onion. The first registered middleware
+//! is the outermost: its `before` runs first and its `after`/`on_error` runs
+//! last. If a `before` hook fails, only the middlewares that already ran
+//! `before` see the error.
+//!
+//! [`ToolRouterBuilder`]: super::router::ToolRouterBuilder
+
+use async_trait::async_trait;
+use rmcp::model::{CallToolResult, Meta, RequestId};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::info;
+
+use super::error::McpError;
+use super::tool_history::ToolCallRecord;
+use super::traits::{ResponseMode, ToolStatus, add_branded_line_to_result};
+
+// ============================================================================
+// CALL INFORMATION
+// ============================================================================
+
+/// Information about the tool call currently passing through the middleware stack.
+#[derive(Debug, Clone)]
+pub struct ToolCall {
+    /// Name of the tool being called
+    pub tool_name: &'static str,
+
+    /// Tool icon character
+    pub icon: char,
+
+    /// Arguments passed to the tool (re-serialized from the parsed Args)
+    pub arguments: Value,
+
+    /// Unique request identifier
+    pub request_id: RequestId,
+
+    /// Connection ID from the kodegen stdio server, if any
+    pub connection_id: Option<String>,
+
+    /// When the call entered the middleware stack
+    pub started: Instant,
+
+    /// Result layout of the route, resolved against the client's protocol version
+    pub response_mode: ResponseMode,
+}
+
+impl ToolCall {
+    /// Time elapsed since the call entered the middleware stack
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Elapsed time in whole milliseconds
+    #[must_use]
+    pub fn elapsed_ms(&self) -> u64 {
+        self.elapsed().as_millis() as u64
+    }
+}
+
+// ============================================================================
+// MIDDLEWARE TRAIT
+// ============================================================================
+
+/// Hooks invoked around every tool execution.
+///
+/// All hooks have no-op defaults, so implementations only override what they need.
+#[async_trait]
+pub trait ToolMiddleware: Send + Sync + 'static {
+    /// Called before the tool executes.
+    ///
+    /// Returning an error aborts the call; the error is passed to the `on_error`
+    /// hooks of the middlewares that already ran.
+    async fn before(&self, _call: &ToolCall) -> Result<(), McpError> {
+        Ok(())
+    }
+
+    /// Called after the tool produced a result.
+    ///
+    /// `metadata` is the tool's typed output as JSON (`Value::Null` when the
+    /// result was produced by an `on_error` hook rather than the tool).
+    async fn after(
+        &self,
+        _call: &ToolCall,
+        _metadata: &Value,
+        _result: &mut CallToolResult,
+    ) -> Result<(), McpError> {
+        Ok(())
+    }
+
+    /// Called when the tool (or an inner middleware) failed.
+    ///
+    /// Return `Ok(result)` to recover with a result, or `Err` to propagate
+    /// the (possibly replaced) error outward.
+    async fn on_error(&self, _call: &ToolCall, error: McpError) -> Result<CallToolResult, McpError> {
+        Err(error)
+    }
+}
+
+/// Ordered, cheaply clonable list of middlewares shared by every tool route.
+#[derive(Clone, Default)]
+pub struct MiddlewareStack {
+    layers: Arc<Vec<Arc<dyn ToolMiddleware>>>,
+}
+
+impl MiddlewareStack {
+    /// Create a stack from middlewares, outermost first.
+    #[must_use]
+    pub fn new(layers: Vec<Arc<dyn ToolMiddleware>>) -> Self {
+        Self {
+            layers: Arc::new(layers),
+        }
+    }
+
+    /// Whether the stack has no middlewares
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Number of middlewares in the stack
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Run `execute` through every middleware.
+    ///
+    /// `execute` returns the tool's metadata JSON together with the converted result.
+    pub async fn run<F>(&self, call: &ToolCall, execute: F) -> Result<CallToolResult, McpError>
+    where
+        F: std::future::Future<Output = Result<(Value, CallToolResult), McpError>> + Send,
+    {
+        // Before hooks, outermost first; stop at the first failure
+        let mut entered = 0;
+        let mut rejected = None;
+        for layer in self.layers.iter() {
+            if let Err(e) = layer.before(call).await {
+                rejected = Some(e);
+                break;
+            }
+            entered += 1;
+        }
+
+        let mut outcome = match rejected {
+            Some(e) => Err(e),
+            None => execute.await,
+        };
+
+        // After / on_error hooks, innermost first
+        for layer in self.layers[..entered].iter().rev() {
+            outcome = match outcome {
+                Ok((metadata, mut result)) => layer
+                    .after(call, &metadata, &mut result)
+                    .await
+                    .map(|()| (metadata, result)),
+                Err(e) => layer.on_error(call, e).await.map(|r| (Value::Null, r)),
+            };
+        }
+
+        outcome.map(|(_, result)| result)
+    }
+}
+
+// ============================================================================
+// BUILT-IN MIDDLEWARES
+// ============================================================================
+
+/// Logs execution time and stores it in `_meta.duration_ms` of the result.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimingMiddleware;
+
+#[async_trait]
+impl ToolMiddleware for TimingMiddleware {
+    async fn after(
+        &self,
+        call: &ToolCall,
+        _metadata: &Value,
+        result: &mut CallToolResult,
+    ) -> Result<(), McpError> {
+        let duration_ms = call.elapsed_ms();
+        info!("Tool '{}' completed in {}ms", call.tool_name, duration_ms);
+
+        result
+            .meta
+            .get_or_insert_with(Meta::new)
+            .0
+            .insert("duration_ms".to_string(), serde_json::json!(duration_ms));
+        Ok(())
+    }
+
+    async fn on_error(&self, call: &ToolCall, error: McpError) -> Result<CallToolResult, McpError> {
+        info!("Tool '{}' failed after {}ms: {}", call.tool_name, call.elapsed_ms(), error);
+        Err(error)
+    }
+}
+
+/// Inserts the branded display line (see [`add_branded_line_to_result`]) as `content[0]`.
+///
+/// Results with `is_error: Some(true)` are branded with [`ToolStatus::Error`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrandingMiddleware;
+
+#[async_trait]
+impl ToolMiddleware for BrandingMiddleware {
+    async fn after(
+        &self,
+        call: &ToolCall,
+        _metadata: &Value,
+        result: &mut CallToolResult,
+    ) -> Result<(), McpError> {
+        let status = if result.is_error == Some(true) {
+            ToolStatus::Error
+        } else {
+            ToolStatus::Success
+        };
+        add_branded_line_to_result(result, call.tool_name, call.icon, call.elapsed_ms(), status);
+        Ok(())
+    }
+}
+
+/// Destination for tool call records produced by [`HistoryMiddleware`].
+pub trait ToolHistorySink: Send + Sync + 'static {
+    /// Record a completed tool call for the given connection.
+    fn record(&self, connection_id: Option<&str>, record: ToolCallRecord);
+}
+
+/// Records every tool call (successful or failed) as a [`ToolCallRecord`].
+///
+/// [`ToolHistory`](super::tool_history::ToolHistory) is the built-in sink.
+#[derive(Clone)]
+pub struct HistoryMiddleware {
+    sink: Arc<dyn ToolHistorySink>,
+}
+
+impl HistoryMiddleware {
+    /// Create a history middleware writing into `sink`
+    pub fn new(sink: Arc<dyn ToolHistorySink>) -> Self {
+        Self { sink }
+    }
+}
+
+#[async_trait]
+impl ToolMiddleware for HistoryMiddleware {
+    async fn after(
+        &self,
+        call: &ToolCall,
+        metadata: &Value,
+        result: &mut CallToolResult,
+    ) -> Result<(), McpError> {
+        let record = ToolCallRecord {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            tool_name: call.tool_name.to_string(),
+            args_json: call.arguments.to_string(),
+            output_json: metadata.to_string(),
+            duration_ms: Some(call.elapsed_ms()),
+            // Failures turned into results by an inner `ErrorResultMiddleware`
+            success: result.is_error != Some(true),
+        };
+        self.sink.record(call.connection_id.as_deref(), record);
+        Ok(())
+    }
+
+    async fn on_error(&self, call: &ToolCall, error: McpError) -> Result<CallToolResult, McpError> {
+        let record = ToolCallRecord {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            tool_name: call.tool_name.to_string(),
+            args_json: call.arguments.to_string(),
+            output_json: serde_json::json!({ "error": error.to_string() }).to_string(),
+            duration_ms: Some(call.elapsed_ms()),
+            success: false,
+        };
+        self.sink.record(call.connection_id.as_deref(), record);
+        Err(error)
+    }
+}
+
+/// Converts tool failures into `is_error` results inside the stack.
+///
+/// The handler already does this after the stack has run; use this middleware
+/// when outer middlewares (e.g. branding) should see failures as results.
+/// The result is laid out like the handler's own error results for the
+/// route's [`ResponseMode`] (in `Text` mode: the message in `content[0]`, the
+/// `ToolErrorData` payload in `content[1]`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ErrorResultMiddleware;
+
+#[async_trait]
+impl ToolMiddleware for ErrorResultMiddleware {
+    async fn on_error(&self, call: &ToolCall, error: McpError) -> Result<CallToolResult, McpError> {
+        if error.is_protocol_error() {
+            return Err(error);
+        }
+        Ok(error.into_call_tool_result_with_mode(call.response_mode))
+    }
+}
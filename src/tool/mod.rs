@@ -3,7 +3,20 @@
 //! This crate provides the fundamental abstractions for building MCP tools:
 //! - The `Tool` trait that defines tool behavior and RMCP integration
 //! - The `McpError` type for tool execution errors
-//! - The `tool_history` module for tracking tool call history
+//! - The `tool_history` module for tracking tool call history (`ToolHistory` store)
+//! - The `SchemaRegistry` of precomputed input/output schemas for every registered tool
+//! - The `SchemaBundle` exporting those schemas as static files (see the `kodegen-schema` binary)
+//! - TypeScript and Python type generation from that bundle (`type_gen`)
+//! - The schema compatibility check between two bundles (`schema_compat`)
+//! - The `ToolMiddleware` pipeline and `ToolRouterBuilder` applying it to every route
+//! - `ContentPart`s (images, audio, resources) carried by a `ToolResponse`
+//! - Progress notification throttling (`ProgressStats`)
+//! - Opt-in `coercion` of LLM-produced arguments against the input schema
+//! - Cross-field argument checks (`ValidateArgs`) run before every call
+//! - `ToolBehavior` hints (read-only, destructive, idempotent, open-world) declared with each tool's Args
+//! - The `ConfirmationPolicy` gate asking users to confirm destructive calls
+//! - The `ResourceRouter` serving `kodegen://` resources from sealed `ResourceProvider`s
+//! - The `CompletionRouter` answering argument completions for prompts, tools and resource templates
 //!
 //! # Example
 //!
@@ -48,8 +61,54 @@
 pub mod error;
 pub mod traits;
 pub mod tool_history;
+pub mod schema_registry;
+pub mod schema_bundle;
+pub mod type_gen;
+pub mod schema_compat;
+pub mod middleware;
+pub mod router;
+pub mod completion;
+pub mod behavior;
+pub mod confirmation;
+pub mod content;
+pub mod coercion;
+pub mod validate;
+pub mod progress;
+pub mod resource;
+pub mod prompt_drift;
+pub mod prompt_scenario;
+#[cfg(feature = "validate-output")]
+pub mod output_validation;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 // Re-export the main types for convenience
-pub use error::McpError;
-pub use traits::{Tool, ToolArgs, ToolExecutionContext, ToolResponse, PromptProvider, SealedPromptProvider, ToolStatus, add_branded_line_to_result};
-pub use tool_history::ToolCallRecord;
+pub use error::{McpError, TOOL_ERROR_META_KEY, ToolErrorData, ToolErrorKind};
+pub use traits::{Tool, ToolArgs, ToolExecutionContext, ToolResponse, ResponseMode, PromptProvider, SealedPromptProvider, ToolStatus, add_branded_line_to_result, STREAMED_OUTPUT_LIMIT, TIMEOUT_META_KEY};
+pub use tool_history::{ToolCallRecord, ToolHistory};
+pub use schema_registry::{SchemaRegistry, ToolSchemas};
+pub use schema_bundle::{BUNDLE_FORMAT_VERSION, BundleIndex, BundleTool, SchemaBundle};
+pub use type_gen::PythonStyle;
+pub use schema_compat::{ChangeKind, CompatReport, Compatibility, SchemaChange};
+pub use middleware::{
+    BrandingMiddleware, ErrorResultMiddleware, HistoryMiddleware, MiddlewareStack, TimingMiddleware,
+    ToolCall, ToolHistorySink, ToolMiddleware,
+};
+pub use router::{RouteConfig, ToolRouterBuilder};
+pub use content::ContentPart;
+pub use validate::{ArgsValidator, ValidateArgs};
+pub use coercion::{ARGUMENT_COERCIONS_META_KEY, Coercion, CoercionKind};
+pub use progress::{DEFAULT_PROGRESS_INTERVAL, PROGRESS_STATS_META_KEY, ProgressStats};
+pub use behavior::ToolBehavior;
+pub use confirmation::{ConfirmationFallback, ConfirmationPolicy, DestructiveAction};
+pub use resource::{
+    ProviderUri, RESOURCE_SCHEME, ResourceProvider, ResourceRouter, ResourceSource, ResourceUri,
+    SealedResourceProvider, match_template, resource_template,
+};
+pub use completion::{
+    ArgumentCompleter, CompletionQuery, CompletionRouter, TemplateCompleter, TOOL_REFERENCE_SCHEME,
+    complete_path, tool_reference,
+};
+pub use prompt_scenario::PromptScenario;
+pub(crate) use prompt_scenario::prompt_scenarios;
+pub use prompt_drift::{PromptDrift, PromptExample, check_all_prompts, check_tool_prompts};
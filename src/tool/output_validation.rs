@@ -0,0 +1,118 @@
+//! Runtime validation of tool output against its declared output schema
+//!
+//! Enabled by the `validate-output` cargo feature. When enabled, `ToolHandler`
+//! validates the serialized metadata of every successful call against
+//! `Tool::output_schema()` - the same schema advertised to clients - before
+//! returning it.
+//!
+//! Violations are logged by default. Set `KODEGEN_VALIDATE_OUTPUT=strict` to
+//! turn them into `McpError::OutputSchema` errors instead.
+
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use log::{error, warn};
+
+use super::error::McpError;
+
+/// Environment variable selecting the validation mode
+pub const VALIDATE_OUTPUT_ENV: &str = "KODEGEN_VALIDATE_OUTPUT";
+
+/// How output schema violations are surfaced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputValidationMode {
+    /// Log violations and return the result unchanged
+    Warn,
+    /// Fail the call with `McpError::OutputSchema`
+    Strict,
+}
+
+impl OutputValidationMode {
+    /// Read the mode from `KODEGEN_VALIDATE_OUTPUT` (`strict` or `warn`, default `warn`).
+    #[must_use]
+    pub fn from_env() -> Self {
+        match std::env::var(VALIDATE_OUTPUT_ENV) {
+            Ok(v) if v.eq_ignore_ascii_case("strict") => Self::Strict,
+            _ => Self::Warn,
+        }
+    }
+}
+
+static MODE: LazyLock<OutputValidationMode> = LazyLock::new(OutputValidationMode::from_env);
+
+/// Compiled validators, keyed by tool name
+type ValidatorCache = parking_lot::RwLock<HashMap<&'static str, Option<Arc<jsonschema::Validator>>>>;
+
+static VALIDATOR_CACHE: LazyLock<ValidatorCache> =
+    LazyLock::new(|| parking_lot::RwLock::new(HashMap::new()));
+
+/// Validate `metadata` against `schema`, returning every violation found.
+///
+/// Each violation is formatted as `<instance path>: <message>`.
+pub fn validate_output(schema: &Map<String, Value>, metadata: &Value) -> Result<(), Vec<String>> {
+    let validator = jsonschema::validator_for(&Value::Object(schema.clone()))
+        .map_err(|e| vec![format!("invalid output schema: {}", e)])?;
+    collect_violations(&validator, metadata)
+}
+
+fn collect_violations(validator: &jsonschema::Validator, metadata: &Value) -> Result<(), Vec<String>> {
+    let violations: Vec<String> = validator
+        .iter_errors(metadata)
+        .map(|e| format!("{}: {}", e.instance_path(), e))
+        .collect();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// Validate a tool's serialized metadata, applying the configured mode.
+///
+/// Validators are compiled once per tool. A schema that fails to compile is
+/// logged once and skipped on subsequent calls.
+pub(crate) fn check_tool_output(
+    tool_name: &'static str,
+    schema: &Map<String, Value>,
+    metadata: &Value,
+) -> Result<(), McpError> {
+    let cached = VALIDATOR_CACHE.read().get(tool_name).cloned();
+    let validator = match cached {
+        Some(v) => v,
+        None => {
+            let compiled = match jsonschema::validator_for(&Value::Object(schema.clone())) {
+                Ok(v) => Some(Arc::new(v)),
+                Err(e) => {
+                    warn!("Output schema for tool '{}' could not be compiled: {}", tool_name, e);
+                    None
+                }
+            };
+            VALIDATOR_CACHE.write().insert(tool_name, compiled.clone());
+            compiled
+        }
+    };
+
+    let Some(validator) = validator else {
+        return Ok(());
+    };
+
+    match collect_violations(&validator, metadata) {
+        Ok(()) => Ok(()),
+        Err(violations) => {
+            let msg = format!(
+                "tool '{}' output does not match its output schema: {}",
+                tool_name,
+                violations.join("; ")
+            );
+            match *MODE {
+                OutputValidationMode::Warn => {
+                    error!("{}", msg);
+                    Ok(())
+                }
+                OutputValidationMode::Strict => Err(McpError::OutputSchema(msg)),
+            }
+        }
+    }
+}
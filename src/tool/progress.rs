@@ -0,0 +1,142 @@
+//! Throttling and coalescing of progress notifications
+//!
+//! Tools may call `ctx.stream()` or `ctx.progress()` thousands of times per
+//! second (terminal output, per-file search progress). With a throttle
+//! interval set on the route, a [`ToolExecutionContext`](super::ToolExecutionContext)
+//! sends at most one batch per interval:
+//!
+//! - text chunks from `stream()` are concatenated into one message
+//! - numeric updates from `progress()`, `update()` and `notify()` keep only the latest
+//!
+//! The first update after a quiet interval is sent immediately; later ones
+//! are flushed when the interval elapses and when the call completes.
+//! Contexts not built by a throttled route use a zero interval, which sends
+//! every update immediately.
+
+use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use std::time::{Duration, Instant};
+
+/// Default minimum time between progress notifications of one call
+pub const DEFAULT_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// `_meta` key under which a result reports its [`ProgressStats`]
+pub const PROGRESS_STATS_META_KEY: &str = "progressStats";
+
+/// How many progress updates of a call were sent, merged or dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProgressStats {
+    /// Notifications actually sent to the client
+    pub sent: u64,
+    /// Text chunks concatenated into an earlier pending chunk
+    pub merged: u64,
+    /// Numeric updates replaced by a later one before being sent
+    pub dropped: u64,
+}
+
+impl ProgressStats {
+    /// Whether throttling changed anything
+    #[must_use]
+    pub fn coalesced(&self) -> bool {
+        self.merged > 0 || self.dropped > 0
+    }
+}
+
+/// One progress notification's payload
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ProgressUpdate {
+    /// Streamed text (sent with progress 0)
+    Text(String),
+    /// Numeric progress with an optional status message
+    Progress {
+        progress: f64,
+        total: Option<f64>,
+        message: Option<String>,
+    },
+}
+
+/// What the caller should do after [`Throttle::push`]
+#[derive(Debug, PartialEq)]
+pub(crate) enum Push {
+    /// Nothing was sent recently: flush now
+    Flush,
+    /// Buffered; schedule a flush at this instant
+    Schedule(Instant),
+    /// Buffered; a flush is already scheduled
+    Buffered,
+}
+
+/// Pending updates and rate state of one call
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    interval: Duration,
+    last_sent: Option<Instant>,
+    text: String,
+    progress: Option<ProgressUpdate>,
+    flush_scheduled: bool,
+    stats: ProgressStats,
+}
+
+impl Throttle {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_sent: None,
+            text: String::new(),
+            progress: None,
+            flush_scheduled: false,
+            stats: ProgressStats::default(),
+        }
+    }
+
+    /// Buffer `update` and decide when it should go out
+    pub(crate) fn push(&mut self, update: ProgressUpdate, now: Instant) -> Push {
+        match update {
+            ProgressUpdate::Text(text) => {
+                if !self.text.is_empty() {
+                    self.stats.merged += 1;
+                }
+                self.text.push_str(&text);
+            }
+            progress @ ProgressUpdate::Progress { .. } => {
+                if self.progress.replace(progress).is_some() {
+                    self.stats.dropped += 1;
+                }
+            }
+        }
+
+        match self.last_sent {
+            Some(last) if now.duration_since(last) < self.interval => {
+                if self.flush_scheduled {
+                    Push::Buffered
+                } else {
+                    self.flush_scheduled = true;
+                    Push::Schedule(last + self.interval)
+                }
+            }
+            _ => Push::Flush,
+        }
+    }
+
+    /// Take everything pending (text first, then the latest numeric update)
+    pub(crate) fn take(&mut self, now: Instant) -> Vec<ProgressUpdate> {
+        self.flush_scheduled = false;
+
+        let mut batch = Vec::new();
+        if !self.text.is_empty() {
+            batch.push(ProgressUpdate::Text(std::mem::take(&mut self.text)));
+        }
+        batch.extend(self.progress.take());
+
+        if !batch.is_empty() {
+            self.last_sent = Some(now);
+            self.stats.sent += batch.len() as u64;
+        }
+        batch
+    }
+
+    /// Counts so far
+    pub(crate) fn stats(&self) -> ProgressStats {
+        self.stats
+    }
+}
@@ -0,0 +1,366 @@
+//! Drift checking between teaching prompts and the Args schemas they teach
+//!
+//! Prompts embed example calls such as `git_diff({"path": ".", "staged": true})`.
+//! When an Args type changes, those examples silently go stale and agents are
+//! taught arguments that get ignored. This module extracts every
+//! `tool_name({...})` example from a provider's generated messages and checks it
+//! against that tool's `args_schema` from [`ToolMetadata`].
+//!
+//! Examples are partial by nature, so missing `required` properties are not
+//! reported. Unknown properties and values of the wrong type, enum or const are.
+//! Examples that are not valid JSON (placeholders such as `{...}`) are skipped.
+
+use rmcp::model::{PromptMessage, PromptMessageContent};
+use serde_json::{Map, Value, json};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::ToolMetadata;
+
+/// An example call found in prompt text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptExample {
+    /// Tool the example calls
+    pub tool: String,
+    /// Source text of the argument object, as written in the prompt
+    pub source: String,
+    /// Parsed argument object, or the JSON parse error
+    pub arguments: Result<Value, String>,
+}
+
+/// An example whose arguments do not match the called tool's Args schema.
+#[derive(Debug, Clone)]
+pub struct PromptDrift {
+    /// Tool whose prompts contain the example
+    pub provider: &'static str,
+    /// Scenario that generated the prompt (None for the default prompt)
+    pub scenario: Option<String>,
+    /// Tool the example calls
+    pub tool: String,
+    /// Source text of the argument object
+    pub source: String,
+    /// Violations, formatted as `<path>: <message>`
+    pub violations: Vec<String>,
+}
+
+impl fmt::Display for PromptDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} prompt (scenario {}): {}({}): {}",
+            self.provider,
+            self.scenario.as_deref().unwrap_or("default"),
+            self.tool,
+            self.source,
+            self.violations.join("; ")
+        )
+    }
+}
+
+/// Extract every `name({...})` example from `text` whose `name` is in `tool_names`.
+pub fn extract_examples(text: &str, tool_names: &[&str]) -> Vec<PromptExample> {
+    let mut examples = Vec::new();
+
+    for &name in tool_names {
+        let pattern = format!("{}(", name);
+        for (start, _) in text.match_indices(&pattern) {
+            let preceded_by_ident = text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+            if preceded_by_ident {
+                continue;
+            }
+
+            let rest = text[start + pattern.len()..].trim_start();
+            if let Some(source) = balanced_object(rest) {
+                examples.push(PromptExample {
+                    tool: name.to_string(),
+                    source: source.to_string(),
+                    arguments: serde_json::from_str(source).map_err(|e| e.to_string()),
+                });
+            }
+        }
+    }
+
+    examples
+}
+
+/// Return the `{...}` object at the start of `text`, honoring JSON strings.
+fn balanced_object(text: &str) -> Option<&str> {
+    if !text.starts_with('{') {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[..=i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Validate an example argument object against an Args schema.
+///
+/// Required properties are not enforced; unknown properties and type, enum or
+/// const mismatches are reported as `<path>: <message>`.
+pub fn validate_example(args_schema: &Value, arguments: &Value) -> Result<(), Vec<String>> {
+    let mut violations = Vec::new();
+    check(arguments, args_schema, args_schema, "$", &mut violations);
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+/// Check every example in the prompts of one tool.
+///
+/// Runs the default prompt plus every scenario named in the provider's
+/// `scenario` argument description, and validates examples of any registered
+/// tool against that tool's Args schema.
+pub fn check_tool_prompts(metadata: &ToolMetadata) -> Vec<PromptDrift> {
+    let tools: Vec<&ToolMetadata> = inventory::iter::<ToolMetadata>.into_iter().collect();
+    check_with_catalog(metadata, &tools)
+}
+
+/// Check the prompts of every tool registered via `#[tool_metadata]`.
+pub fn check_all_prompts() -> Vec<PromptDrift> {
+    let tools: Vec<&ToolMetadata> = inventory::iter::<ToolMetadata>.into_iter().collect();
+    tools.iter().flat_map(|metadata| check_with_catalog(metadata, &tools)).collect()
+}
+
+fn check_with_catalog(metadata: &ToolMetadata, tools: &[&ToolMetadata]) -> Vec<PromptDrift> {
+    // Longest names first so `git_stash_pop(` is not also read as `git_stash(`
+    let mut names: Vec<&str> = tools.iter().map(|t| t.name).collect();
+    names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    let mut seen = HashSet::new();
+    let mut drift = Vec::new();
+
+    for scenario in scenarios(metadata) {
+        let args = match &scenario {
+            Some(scenario) => json!({ "scenario": scenario }),
+            None => json!({}),
+        };
+
+        for text in message_texts(&(metadata.generate_prompts)(&args)) {
+            for example in extract_examples(text, &names) {
+                let Ok(arguments) = &example.arguments else {
+                    continue;
+                };
+                if !seen.insert((example.tool.clone(), example.source.clone())) {
+                    continue;
+                }
+                let Some(target) = tools.iter().find(|t| t.name == example.tool) else {
+                    continue;
+                };
+                if let Err(violations) = validate_example(&(target.args_schema)(), arguments) {
+                    drift.push(PromptDrift {
+                        provider: metadata.name,
+                        scenario: scenario.clone(),
+                        tool: example.tool,
+                        source: example.source,
+                        violations,
+                    });
+                }
+            }
+        }
+    }
+
+    drift
+}
+
+/// Default prompt plus every identifier mentioned in the `scenario` argument description.
+///
+/// Words that are not scenarios fall back to the default prompt, whose
+/// examples are deduplicated by the caller.
+fn scenarios(metadata: &ToolMetadata) -> Vec<Option<String>> {
+    let mut scenarios = vec![None];
+
+    for argument in (metadata.prompt_arguments)() {
+        if argument.name != "scenario" {
+            continue;
+        }
+        let description = argument.description.unwrap_or_default();
+        for word in description.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-')) {
+            if !word.is_empty() && !scenarios.contains(&Some(word.to_string())) {
+                scenarios.push(Some(word.to_string()));
+            }
+        }
+    }
+
+    scenarios
+}
+
+fn message_texts(messages: &[PromptMessage]) -> impl Iterator<Item = &str> {
+    messages.iter().filter_map(|message| match &message.content {
+        PromptMessageContent::Text { text } => Some(text.as_str()),
+        _ => None,
+    })
+}
+
+// ============================================================================
+// SCHEMA WALKER
+// ============================================================================
+
+/// Flatten `$ref` and `allOf` into the list of schema objects that apply together.
+fn parts<'a>(schema: &'a Value, root: &'a Value, out: &mut Vec<&'a Map<String, Value>>) {
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+    out.push(obj);
+
+    if let Some(target) = obj
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        parts(target, root, out);
+    }
+    for member in obj.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+        parts(member, root, out);
+    }
+}
+
+fn check(value: &Value, schema: &Value, root: &Value, path: &str, violations: &mut Vec<String>) {
+    if schema == &Value::Bool(false) {
+        violations.push(format!("{}: not allowed", path));
+        return;
+    }
+
+    let mut applied = Vec::new();
+    parts(schema, root, &mut applied);
+
+    for part in &applied {
+        if let Some(expected) = part.get("const")
+            && value != expected
+        {
+            violations.push(format!("{}: expected {}, found {}", path, expected, value));
+            return;
+        }
+        if let Some(allowed) = part.get("enum").and_then(Value::as_array)
+            && !allowed.contains(value)
+        {
+            violations.push(format!("{}: {} is not one of {}", path, value, Value::Array(allowed.clone())));
+            return;
+        }
+        if let Some(types) = part.get("type")
+            && !type_matches(value, types)
+        {
+            violations.push(format!("{}: expected {}, found {}", path, types, json_type(value)));
+            return;
+        }
+        for key in ["oneOf", "anyOf"] {
+            if let Some(branches) = part.get(key).and_then(Value::as_array) {
+                let best = branches
+                    .iter()
+                    .map(|branch| {
+                        let mut branch_violations = Vec::new();
+                        check(value, branch, root, path, &mut branch_violations);
+                        branch_violations
+                    })
+                    .min_by_key(Vec::len);
+                if let Some(best) = best
+                    && !best.is_empty()
+                {
+                    violations.extend(best);
+                    return;
+                }
+            }
+        }
+    }
+
+    match value {
+        Value::Object(fields) => check_object(fields, &applied, root, path, violations),
+        Value::Array(items) => {
+            let item_schema = applied.iter().find_map(|part| part.get("items"));
+            if let Some(item_schema) = item_schema {
+                for (i, item) in items.iter().enumerate() {
+                    check(item, item_schema, root, &format!("{}[{}]", path, i), violations);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_object(
+    fields: &Map<String, Value>,
+    applied: &[&Map<String, Value>],
+    root: &Value,
+    path: &str,
+    violations: &mut Vec<String>,
+) {
+    let declares_properties = applied.iter().any(|part| part.contains_key("properties"));
+    let additional = applied.iter().find_map(|part| part.get("additionalProperties"));
+
+    for (name, field) in fields {
+        let field_path = format!("{}.{}", path, name);
+        let property = applied
+            .iter()
+            .filter_map(|part| part.get("properties").and_then(Value::as_object))
+            .find_map(|properties| properties.get(name));
+
+        match (property, additional) {
+            (Some(property), _) => check(field, property, root, &field_path, violations),
+            (None, Some(Value::Bool(false))) => {
+                violations.push(format!("{}: unknown field", field_path));
+            }
+            (None, Some(additional)) => check(field, additional, root, &field_path, violations),
+            (None, None) if declares_properties => {
+                violations.push(format!("{}: unknown field", field_path));
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn type_matches(value: &Value, types: &Value) -> bool {
+    match types {
+        Value::String(ty) => single_type_matches(value, ty),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|ty| single_type_matches(value, ty)),
+        _ => true,
+    }
+}
+
+fn single_type_matches(value: &Value, ty: &str) -> bool {
+    match ty {
+        "integer" => value.is_i64() || value.is_u64(),
+        other => json_type(value) == other || (other == "number" && value.is_number()),
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
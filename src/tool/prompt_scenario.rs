@@ -0,0 +1,156 @@
+//! Typed scenarios for teaching prompts
+//!
+//! Prompt providers declare their scenarios with [`prompt_scenarios!`], which
+//! generates an enum implementing [`PromptScenario`]. The enum is used as the
+//! `scenario` field of the provider's `PromptArgs`, so unknown scenario names
+//! are rejected instead of silently falling back to the default prompt, and
+//! the advertised `PromptArgument` always lists the values that exist.
+
+use rmcp::model::PromptArgument;
+use schemars::JsonSchema;
+use serde_json::Value;
+
+/// A closed set of scenarios a prompt provider can teach.
+pub trait PromptScenario: Copy + Eq + Send + Sync + 'static {
+    /// Every scenario, in declaration order
+    const ALL: &'static [Self];
+
+    /// Value of the `scenario` prompt argument selecting this scenario
+    fn name(self) -> &'static str;
+
+    /// One-line summary of what the scenario teaches
+    fn description(self) -> &'static str;
+
+    /// Look up a scenario by its argument value
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|scenario| scenario.name() == name)
+    }
+
+    /// The optional `scenario` prompt argument, listing every valid value
+    fn prompt_argument() -> PromptArgument {
+        let values: Vec<String> = Self::ALL
+            .iter()
+            .map(|scenario| format!("{} ({})", scenario.name(), scenario.description()))
+            .collect();
+
+        PromptArgument {
+            name: "scenario".to_string(),
+            title: None,
+            description: Some(format!("Scenario to show, one of: {}", values.join(", "))),
+            required: Some(false),
+        }
+    }
+}
+
+/// Declare a scenario enum implementing [`PromptScenario`].
+///
+/// Each variant's doc comment becomes its description; the string after `=>`
+/// is the value clients pass as the `scenario` argument.
+///
+/// ```rust,ignore
+/// prompt_scenarios! {
+///     /// Scenarios for `git_diff` prompts
+///     pub enum GitDiffScenario {
+///         /// Comparing commits and the working tree
+///         Basic => "basic",
+///         /// Diffing specific files
+///         Files => "files",
+///     }
+/// }
+/// ```
+macro_rules! prompt_scenarios {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                #[doc = $doc:literal]
+                $variant:ident => $value:literal
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, Hash,
+            serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+        )]
+        $vis enum $name {
+            $(
+                #[doc = $doc]
+                #[serde(rename = $value)]
+                $variant,
+            )+
+        }
+
+        impl $crate::tool::PromptScenario for $name {
+            const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)+
+                }
+            }
+
+            fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $doc.trim(),)+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str($crate::tool::PromptScenario::name(*self))
+            }
+        }
+    };
+}
+
+pub(crate) use prompt_scenarios;
+
+/// Values accepted by property `argument` of `T`, read from its JSON schema.
+///
+/// Collects `enum` and `const` values through `$ref`, `anyOf` and `oneOf`, so
+/// both scenario enums and `Option` wrappers resolve. Returns an empty list for
+/// unknown or free-form properties.
+#[must_use]
+pub fn argument_values<T: JsonSchema>(argument: &str) -> Vec<String> {
+    let schema = schemars::SchemaGenerator::default().into_root_schema_for::<T>();
+    let root = schema.as_value();
+
+    let mut values = Vec::new();
+    if let Some(property) = root.pointer(&format!("/properties/{}", argument)) {
+        collect_values(property, root, 0, &mut values);
+    }
+    values
+}
+
+fn collect_values(schema: &Value, root: &Value, depth: usize, out: &mut Vec<String>) {
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+    if depth > 16 {
+        return;
+    }
+
+    if let Some(target) = obj
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        collect_values(target, root, depth + 1, out);
+    }
+    if let Some(value) = obj.get("const").and_then(Value::as_str) {
+        out.push(value.to_string());
+    }
+    for value in obj.get("enum").and_then(Value::as_array).into_iter().flatten() {
+        if let Some(value) = value.as_str() {
+            out.push(value.to_string());
+        }
+    }
+    for key in ["anyOf", "oneOf"] {
+        for branch in obj.get(key).and_then(Value::as_array).into_iter().flatten() {
+            collect_values(branch, root, depth + 1, out);
+        }
+    }
+}
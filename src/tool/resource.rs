@@ -0,0 +1,388 @@
+//! MCP resources: typed URIs, sealed providers and the resource router
+//!
+//! Tools produce artifacts clients want to browse later: crawl output, fetched
+//! pages, screenshots, memory libraries, stored prompts. Resources expose them
+//! under `kodegen://` URIs instead of pasting file paths into chat.
+//!
+//! The split mirrors tools and prompts:
+//!
+//! - [`ResourceProvider`] (sealed, implemented only in kodegen-mcp-schema)
+//!   declares a family of URIs: its typed [`ResourceUri`] and the URI
+//!   templates advertised to clients (e.g. `kodegen://crawl/{crawl_id}/page/{n}`).
+//! - [`ResourceSource`] (implemented by tool packages) lists and reads the
+//!   resources of one provider, the way [`Tool`](super::Tool) executes
+//!   against schema-defined Args.
+//! - [`ResourceRouter`] answers `resources/list`, `resources/templates/list`,
+//!   `resources/read`, `resources/subscribe` and `resources/unsubscribe`, and
+//!   sends `notifications/resources/updated` to subscribers.
+//!
+//! Tool outputs point at resources with links built from the same typed URIs
+//! (see [`ResourceUri::link`] and [`ToolResponse::with_link`](super::ToolResponse::with_link)).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! struct CrawlStore { root: PathBuf }
+//!
+//! impl ResourceSource for CrawlStore {
+//!     type Provider = CitescrapeResources;
+//!
+//!     async fn read(&self, uri: CitescrapeUri, _ctx: ToolExecutionContext) -> Result<Vec<ResourceContents>, McpError> {
+//!         let text = tokio::fs::read_to_string(self.path_of(&uri)).await?;
+//!         Ok(vec![ResourceContents::text(text, uri.to_uri())])
+//!     }
+//! }
+//!
+//! let resources = ResourceRouter::new().source(CrawlStore::new(root));
+//!
+//! // In the server's ServerHandler impl:
+//! async fn read_resource(&self, request: ReadResourceRequestParam, context: RequestContext<RoleServer>)
+//!     -> Result<ReadResourceResult, ErrorData>
+//! {
+//!     self.resources.read_resource(request, context).await
+//! }
+//! ```
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use rmcp::model::{
+    AnnotateAble, ListResourceTemplatesResult, ListResourcesResult, RawResource, RawResourceTemplate,
+    ReadResourceRequestParam, ReadResourceResult, ResourceContents, ResourceTemplate, ResourceUpdatedNotificationParam,
+    ResourcesCapability, SubscribeRequestParam, UnsubscribeRequestParam,
+};
+use rmcp::service::{Peer, RequestContext};
+use rmcp::{ErrorData, RoleServer};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use super::error::McpError;
+use super::traits::ToolExecutionContext;
+
+/// URI scheme of every resource defined in kodegen-mcp-schema
+pub const RESOURCE_SCHEME: &str = "kodegen://";
+
+// ============================================================================
+// TYPED URIS
+// ============================================================================
+
+/// A parsed resource URI of one [`ResourceProvider`].
+pub trait ResourceUri: Sized + Send + Sync + 'static {
+    /// Parse `uri`, or `None` if it does not belong to this provider
+    fn parse(uri: &str) -> Option<Self>;
+
+    /// The URI string (inverse of [`parse`](Self::parse))
+    fn to_uri(&self) -> String;
+
+    /// Short name shown to users (e.g. a file name)
+    fn name(&self) -> String;
+
+    /// MIME type of the resource contents, if known from the URI
+    fn mime_type(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Resource link pointing at this URI (for tool results and `resources/list`)
+    fn link(&self) -> RawResource {
+        RawResource {
+            mime_type: self.mime_type().map(str::to_string),
+            ..RawResource::new(self.to_uri(), self.name())
+        }
+    }
+}
+
+/// Match `uri` against a level-1 URI template such as `kodegen://crawl/{crawl_id}/page/{n}`.
+///
+/// Returns the variable values, or `None` if the literal parts differ. A
+/// variable matches one non-empty path segment (it never contains `/`).
+#[must_use]
+pub fn match_template(template: &str, uri: &str) -> Option<HashMap<String, String>> {
+    let mut values = HashMap::new();
+    let mut rest = uri;
+    let mut parts = template.split('{');
+
+    let prefix = parts.next().unwrap_or_default();
+    rest = rest.strip_prefix(prefix)?;
+
+    for part in parts {
+        let (name, literal) = part.split_once('}')?;
+        let end = if literal.is_empty() {
+            rest.len()
+        } else {
+            rest.find(literal)?
+        };
+        let value = &rest[..end];
+        if value.is_empty() || value.contains('/') {
+            return None;
+        }
+        values.insert(name.to_string(), value.to_string());
+        rest = rest[end..].strip_prefix(literal)?;
+    }
+
+    rest.is_empty().then_some(values)
+}
+
+/// Template advertised in `resources/templates/list`
+#[must_use]
+pub fn resource_template(
+    uri_template: &str,
+    name: &str,
+    description: &str,
+    mime_type: Option<&str>,
+) -> RawResourceTemplate {
+    RawResourceTemplate {
+        uri_template: uri_template.to_string(),
+        name: name.to_string(),
+        title: None,
+        description: Some(description.to_string()),
+        mime_type: mime_type.map(str::to_string),
+    }
+}
+
+// ============================================================================
+// SEALED RESOURCE PROVIDER TRAIT
+// ============================================================================
+
+/// Sealed module - ONLY kodegen-mcp-schema can implement Sealed trait
+mod sealed {
+    /// Sealed trait that prevents external crates from implementing ResourceProvider
+    pub trait Sealed {}
+}
+
+/// Family of resource URIs that ONLY kodegen-mcp-schema can define.
+///
+/// Like [`PromptProvider`](super::PromptProvider), this keeps every URI shape
+/// in the schema package, so tools linking to a resource and servers reading
+/// it cannot disagree on the format.
+pub trait ResourceProvider: sealed::Sealed + Send + Sync + 'static {
+    /// Parsed form of this provider's URIs
+    type Uri: ResourceUri;
+
+    /// URI templates advertised to clients
+    fn templates() -> Vec<RawResourceTemplate>;
+}
+
+/// Re-export sealed trait for schema package to implement
+///
+/// Only kodegen-mcp-schema can `impl SealedResourceProvider for MyResources {}`.
+pub use sealed::Sealed as SealedResourceProvider;
+
+/// Typed URI of provider `P`
+pub type ProviderUri<P> = <P as ResourceProvider>::Uri;
+
+// ============================================================================
+// RESOURCE SOURCE
+// ============================================================================
+
+/// Lists and reads the resources of one [`ResourceProvider`].
+///
+/// Implemented by tool packages for the artifacts they store.
+pub trait ResourceSource: Send + Sync + 'static {
+    /// The URI family this source serves (MUST be from schema)
+    type Provider: ResourceProvider;
+
+    /// Concrete resources to include in `resources/list`.
+    ///
+    /// Default: none (the provider's templates are still advertised).
+    fn list(
+        &self,
+        _ctx: ToolExecutionContext,
+    ) -> impl Future<Output = Result<Vec<RawResource>, McpError>> + Send {
+        async { Ok(Vec::new()) }
+    }
+
+    /// Read the resource at `uri`.
+    ///
+    /// Return [`McpError::ResourceNotFound`] if it does not exist.
+    fn read(
+        &self,
+        uri: ProviderUri<Self::Provider>,
+        ctx: ToolExecutionContext,
+    ) -> impl Future<Output = Result<Vec<ResourceContents>, McpError>> + Send;
+}
+
+/// Type-erased [`ResourceSource`] stored in the router
+#[async_trait]
+trait ErasedSource: Send + Sync {
+    fn templates(&self) -> Vec<RawResourceTemplate>;
+    fn handles(&self, uri: &str) -> bool;
+    async fn list(&self, ctx: ToolExecutionContext) -> Result<Vec<RawResource>, McpError>;
+    async fn read(&self, uri: &str, ctx: ToolExecutionContext) -> Option<Result<Vec<ResourceContents>, McpError>>;
+}
+
+struct SourceRoute<S>(Arc<S>);
+
+#[async_trait]
+impl<S: ResourceSource> ErasedSource for SourceRoute<S> {
+    fn templates(&self) -> Vec<RawResourceTemplate> {
+        S::Provider::templates()
+    }
+
+    fn handles(&self, uri: &str) -> bool {
+        ProviderUri::<S::Provider>::parse(uri).is_some()
+    }
+
+    async fn list(&self, ctx: ToolExecutionContext) -> Result<Vec<RawResource>, McpError> {
+        self.0.list(ctx).await
+    }
+
+    async fn read(&self, uri: &str, ctx: ToolExecutionContext) -> Option<Result<Vec<ResourceContents>, McpError>> {
+        let uri = ProviderUri::<S::Provider>::parse(uri)?;
+        Some(self.0.read(uri, ctx).await)
+    }
+}
+
+// ============================================================================
+// ROUTER
+// ============================================================================
+
+/// A client subscribed to a resource URI
+struct Subscriber {
+    connection_id: Option<String>,
+    peer: Peer<RoleServer>,
+}
+
+/// Answers the MCP resource requests from registered [`ResourceSource`]s.
+///
+/// Subscriptions are keyed by URI and by the client's connection ID (the
+/// `x-kodegen-connection-id` header); clients without one share a key, which
+/// matches one router per session.
+#[derive(Clone, Default)]
+pub struct ResourceRouter {
+    sources: Vec<Arc<dyn ErasedSource>>,
+    subscriptions: Arc<Mutex<HashMap<String, Vec<Subscriber>>>>,
+}
+
+impl ResourceRouter {
+    /// Router with no sources
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a resource source
+    #[must_use]
+    pub fn source<S: ResourceSource>(self, source: S) -> Self {
+        self.arc_source(Arc::new(source))
+    }
+
+    /// Register an Arc-wrapped resource source
+    #[must_use]
+    pub fn arc_source<S: ResourceSource>(mut self, source: Arc<S>) -> Self {
+        self.sources.push(Arc::new(SourceRoute(source)));
+        self
+    }
+
+    /// Capability to advertise in the server's `ServerCapabilities`
+    #[must_use]
+    pub fn capabilities(&self) -> ResourcesCapability {
+        ResourcesCapability {
+            subscribe: Some(true),
+            list_changed: None,
+        }
+    }
+
+    /// Handle `resources/templates/list`
+    #[must_use]
+    pub fn list_resource_templates(&self) -> ListResourceTemplatesResult {
+        let resource_templates: Vec<ResourceTemplate> = self
+            .sources
+            .iter()
+            .flat_map(|source| source.templates())
+            .map(AnnotateAble::no_annotation)
+            .collect();
+        ListResourceTemplatesResult::with_all_items(resource_templates)
+    }
+
+    /// Handle `resources/list`
+    pub async fn list_resources(&self, context: RequestContext<RoleServer>) -> Result<ListResourcesResult, ErrorData> {
+        let ctx = ToolExecutionContext::from_request_context(&context);
+        let mut resources = Vec::new();
+        for source in &self.sources {
+            let listed = source.list(ctx.clone()).await.map_err(ErrorData::from)?;
+            resources.extend(listed.into_iter().map(AnnotateAble::no_annotation));
+        }
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    /// Handle `resources/read`
+    pub async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        let ctx = ToolExecutionContext::from_request_context(&context);
+        for source in &self.sources {
+            if let Some(result) = source.read(&request.uri, ctx.clone()).await {
+                return result.map(|contents| ReadResourceResult { contents }).map_err(ErrorData::from);
+            }
+        }
+        Err(unknown_resource(&request.uri))
+    }
+
+    /// Handle `resources/subscribe`
+    pub fn subscribe(&self, request: SubscribeRequestParam, context: RequestContext<RoleServer>) -> Result<(), ErrorData> {
+        if !self.sources.iter().any(|source| source.handles(&request.uri)) {
+            return Err(unknown_resource(&request.uri));
+        }
+
+        let connection_id = ToolExecutionContext::from_request_context(&context)
+            .connection_id()
+            .map(str::to_string);
+        let mut subscriptions = self.subscriptions.lock();
+        let subscribers = subscriptions.entry(request.uri).or_default();
+        subscribers.retain(|s| s.connection_id != connection_id && !s.peer.is_transport_closed());
+        subscribers.push(Subscriber {
+            connection_id,
+            peer: context.peer,
+        });
+        Ok(())
+    }
+
+    /// Handle `resources/unsubscribe`
+    pub fn unsubscribe(&self, request: UnsubscribeRequestParam, context: RequestContext<RoleServer>) -> Result<(), ErrorData> {
+        let ctx = ToolExecutionContext::from_request_context(&context);
+        let connection_id = ctx.connection_id();
+        let mut subscriptions = self.subscriptions.lock();
+        if let Some(subscribers) = subscriptions.get_mut(&request.uri) {
+            subscribers.retain(|s| s.connection_id.as_deref() != connection_id);
+            if subscribers.is_empty() {
+                subscriptions.remove(&request.uri);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether any client is subscribed to `uri`
+    #[must_use]
+    pub fn is_subscribed(&self, uri: &str) -> bool {
+        self.subscriptions.lock().contains_key(uri)
+    }
+
+    /// Tell every client subscribed to `uri` that it changed.
+    ///
+    /// Returns how many clients were notified; disconnected ones are dropped.
+    pub async fn notify_updated(&self, uri: &str) -> usize {
+        let peers: Vec<Peer<RoleServer>> = {
+            let mut subscriptions = self.subscriptions.lock();
+            let Some(subscribers) = subscriptions.get_mut(uri) else {
+                return 0;
+            };
+            subscribers.retain(|s| !s.peer.is_transport_closed());
+            subscribers.iter().map(|s| s.peer.clone()).collect()
+        };
+
+        let mut notified = 0;
+        for peer in peers {
+            let param = ResourceUpdatedNotificationParam { uri: uri.to_string() };
+            if peer.notify_resource_updated(param).await.is_ok() {
+                notified += 1;
+            }
+        }
+        notified
+    }
+}
+
+fn unknown_resource(uri: &str) -> ErrorData {
+    ErrorData::resource_not_found(format!("no resource provider handles {}", uri), None)
+}
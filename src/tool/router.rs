@@ -0,0 +1,218 @@
+//! Router builder applying a shared middleware stack and response mode to every tool route
+//! and collecting the argument completers of the tools it routes
+
+use rmcp::handler::server::router::tool::ToolRouter;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::completion::{ArgumentCompleter, CompletionRouter};
+use super::confirmation::ConfirmationPolicy;
+use super::middleware::{MiddlewareStack, ToolMiddleware};
+use super::traits::{ResponseMode, Tool};
+
+/// Per-route configuration shared by every route a [`ToolRouterBuilder`] builds.
+#[derive(Clone, Default)]
+pub struct RouteConfig {
+    /// Middlewares wrapping every call
+    pub middleware: MiddlewareStack,
+
+    /// Where typed metadata is placed in the result
+    pub response_mode: ResponseMode,
+
+    /// Confirmation required before destructive calls (None runs them unconfirmed)
+    pub confirmation: Option<ConfirmationPolicy>,
+
+    /// Minimum time between progress notifications of one call; updates in
+    /// between are coalesced (default None: every update is sent immediately)
+    pub progress_interval: Option<Duration>,
+
+    /// Fix trivially mismatched arguments (`"5000"` for a number, `"exec"` for
+    /// `EXEC`) against the input schema before deserializing them
+    pub coerce_arguments: bool,
+}
+
+/// Builds an rmcp `ToolRouter` whose routes all share one [`RouteConfig`].
+///
+/// Middlewares and the response mode must be configured before the tools
+/// they should apply to. Every added tool's help prompt is registered for
+/// completion; tools added with [`completing_tool`](Self::completing_tool)
+/// also complete their arguments (see [`build_with_completions`](Self::build_with_completions)).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let router = ToolRouterBuilder::<MyServer>::new()
+///     .middleware(TimingMiddleware)
+///     .middleware(BrandingMiddleware)
+///     .response_mode(ResponseMode::Auto)
+///     .confirmation(ConfirmationPolicy::default())
+///     .tool(ReadFileTool::new())
+///     .arc_tool(shared_terminal_tool)
+///     .build();
+/// ```
+pub struct ToolRouterBuilder<S> {
+    router: ToolRouter<S>,
+    layers: Vec<Arc<dyn ToolMiddleware>>,
+    response_mode: ResponseMode,
+    confirmation: Option<ConfirmationPolicy>,
+    progress_interval: Option<Duration>,
+    coerce_arguments: bool,
+    config: Option<RouteConfig>,
+    completions: CompletionRouter,
+}
+
+impl<S> Default for ToolRouterBuilder<S>
+where
+    S: Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> ToolRouterBuilder<S>
+where
+    S: Send + Sync + 'static,
+{
+    /// Create an empty builder
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            router: ToolRouter::new(),
+            layers: Vec::new(),
+            response_mode: ResponseMode::default(),
+            confirmation: None,
+            progress_interval: None,
+            coerce_arguments: false,
+            config: None,
+            completions: CompletionRouter::new(),
+        }
+    }
+
+    /// Append a middleware (outermost first).
+    ///
+    /// # Panics
+    /// Panics if called after a tool was added, since earlier routes would
+    /// silently miss the middleware.
+    #[must_use]
+    pub fn middleware(mut self, middleware: impl ToolMiddleware) -> Self {
+        self.assert_unfrozen("middleware");
+        self.layers.push(Arc::new(middleware));
+        self
+    }
+
+    /// Set where typed metadata is placed in results (default: [`ResponseMode::Text`]).
+    ///
+    /// # Panics
+    /// Panics if called after a tool was added.
+    #[must_use]
+    pub fn response_mode(mut self, mode: ResponseMode) -> Self {
+        self.assert_unfrozen("response_mode");
+        self.response_mode = mode;
+        self
+    }
+
+    /// Require confirmation (via MCP elicitation) before destructive calls
+    ///
+    /// # Panics
+    /// Panics if called after a tool was added.
+    #[must_use]
+    pub fn confirmation(mut self, policy: ConfirmationPolicy) -> Self {
+        self.assert_unfrozen("confirmation");
+        self.confirmation = Some(policy);
+        self
+    }
+
+    /// Throttle progress notifications of one call to one per `interval`,
+    /// e.g. [`DEFAULT_PROGRESS_INTERVAL`](super::DEFAULT_PROGRESS_INTERVAL)
+    /// (default: None, every update is sent immediately)
+    ///
+    /// # Panics
+    /// Panics if called after a tool was added.
+    #[must_use]
+    pub fn progress_interval(mut self, interval: Option<Duration>) -> Self {
+        self.assert_unfrozen("progress_interval");
+        self.progress_interval = interval;
+        self
+    }
+
+    /// Coerce LLM-produced arguments against each tool's input schema before
+    /// deserializing them (default: off; see [`coercion`](super::coercion))
+    ///
+    /// # Panics
+    /// Panics if called after a tool was added.
+    #[must_use]
+    pub fn coerce_arguments(mut self, enabled: bool) -> Self {
+        self.assert_unfrozen("coerce_arguments");
+        self.coerce_arguments = enabled;
+        self
+    }
+
+    /// Add a tool route using the shared config
+    #[must_use]
+    pub fn tool<T: Tool>(self, tool: T) -> Self {
+        self.arc_tool(Arc::new(tool))
+    }
+
+    /// Add an Arc-wrapped tool route using the shared config
+    #[must_use]
+    pub fn arc_tool<T: Tool>(mut self, tool: Arc<T>) -> Self {
+        let layers = &mut self.layers;
+        let response_mode = self.response_mode;
+        let confirmation = &mut self.confirmation;
+        let progress_interval = self.progress_interval;
+        let coerce_arguments = self.coerce_arguments;
+        let config = self
+            .config
+            .get_or_insert_with(|| RouteConfig {
+                middleware: MiddlewareStack::new(std::mem::take(layers)),
+                response_mode,
+                confirmation: confirmation.take(),
+                progress_interval,
+                coerce_arguments,
+            })
+            .clone();
+        self.completions.add_prompt::<T>();
+        self.router.add_route(tool.arc_into_tool_route_with(config));
+        self
+    }
+
+    /// Add a tool route whose arguments also complete via [`ArgumentCompleter`]
+    ///
+    /// # Panics
+    /// Panics if a completable argument is not a property of the tool's Args.
+    #[must_use]
+    pub fn completing_tool<T: ArgumentCompleter>(self, tool: T) -> Self {
+        self.arc_completing_tool(Arc::new(tool))
+    }
+
+    /// Add an Arc-wrapped tool route whose arguments also complete via [`ArgumentCompleter`]
+    ///
+    /// # Panics
+    /// Panics if a completable argument is not a property of the tool's Args.
+    #[must_use]
+    pub fn arc_completing_tool<T: ArgumentCompleter>(mut self, tool: Arc<T>) -> Self {
+        self.completions.add_tool(tool.clone());
+        self.arc_tool(tool)
+    }
+
+    /// Finish building the router
+    #[must_use]
+    pub fn build(self) -> ToolRouter<S> {
+        self.router
+    }
+
+    /// Finish building the router, also returning the completions for its tools
+    #[must_use]
+    pub fn build_with_completions(self) -> (ToolRouter<S>, CompletionRouter) {
+        (self.router, self.completions)
+    }
+
+    fn assert_unfrozen(&self, method: &str) {
+        assert!(
+            self.config.is_none(),
+            "ToolRouterBuilder::{} must be called before adding tools",
+            method
+        );
+    }
+}
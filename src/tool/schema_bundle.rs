@@ -0,0 +1,361 @@
+//! Static schema bundle for every tool registered via `#[tool_metadata]`
+//!
+//! A [`SchemaBundle`] is the offline counterpart of `tools/list`: it collects
+//! every `ToolMetadata` from `inventory` and writes a versioned directory that
+//! docs sites and integrators can consume without running a server:
+//!
+//! ```text
+//! {out}/{crate version}/
+//!     index.json              name, category, icon, description, annotations per tool
+//!     tools/{name}.schema.json  JSON Schema (draft 2020-12) with the tool's input and output
+//!     openapi.json            optional OpenAPI 3.1 document, one POST operation per tool
+//! ```
+//!
+//! Schemas use the same dialect the server advertises (see [`SchemaRegistry`]).
+//! Each schema's `$defs` are hoisted next to it and their `$ref`s rewritten, so
+//! the files are self-contained.
+//!
+//! Every index entry carries the behavior annotations declared with the tool's
+//! Args ([`ToolArgs::BEHAVIOR`]). Servers whose `Tool` implementations override
+//! them record the overrides with [`SchemaBundle::annotate`].
+
+use rmcp::model::{PromptArgument, ToolAnnotations};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::schema_registry::SchemaRegistry;
+use super::traits::{Tool, ToolArgs};
+use crate::ToolMetadata;
+
+/// Version of the bundle layout (bumped on incompatible changes to the files)
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// JSON Schema dialect of the per-tool files
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// `index.json` of a bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleIndex {
+    /// Layout version ([`BUNDLE_FORMAT_VERSION`])
+    pub format_version: u32,
+
+    /// Version of kodegen-mcp-schema the schemas were generated from
+    pub schema_version: String,
+
+    /// Every tool, sorted by name
+    pub tools: Vec<BundleTool>,
+}
+
+/// One tool in the bundle index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleTool {
+    /// Tool name (unique identifier)
+    pub name: String,
+
+    /// Category name
+    pub category: String,
+
+    /// Tool icon (the category icon)
+    pub icon: char,
+
+    /// Human-readable description
+    pub description: String,
+
+    /// Path of the tool's schema file, relative to the index
+    pub schema: String,
+
+    /// Arguments of the tool's `{name}_help` prompt
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_arguments: Vec<PromptArgument>,
+
+    /// Behavior hints (read-only, destructive, idempotent, open-world)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+}
+
+/// Input and output schemas of one tool
+#[derive(Debug, Clone)]
+struct BundleSchemas {
+    input: Value,
+    output: Value,
+}
+
+/// Every registered tool's schemas, ready to be written to disk.
+#[derive(Debug, Clone)]
+pub struct SchemaBundle {
+    index: BundleIndex,
+    schemas: Vec<BundleSchemas>,
+}
+
+impl SchemaBundle {
+    /// Collect every tool registered via `#[tool_metadata]`
+    #[must_use]
+    pub fn from_inventory() -> Self {
+        let mut metadata: Vec<&ToolMetadata> = inventory::iter::<ToolMetadata>.into_iter().collect();
+        metadata.sort_by_key(|m| m.name);
+        metadata.dedup_by_key(|m| m.name);
+
+        let mut tools = Vec::with_capacity(metadata.len());
+        let mut schemas = Vec::with_capacity(metadata.len());
+        for m in metadata {
+            let (input, output) = match SchemaRegistry::get(m.name) {
+                Some(s) => (Value::Object((*s.input).clone()), Value::Object((*s.output).clone())),
+                None => ((m.args_schema)(), (m.output_schema)()),
+            };
+            tools.push(BundleTool {
+                name: m.name.to_string(),
+                category: m.category.name.to_string(),
+                icon: m.category.icon,
+                description: m.description.to_string(),
+                schema: format!("tools/{}.schema.json", m.name),
+                prompt_arguments: (m.prompt_arguments)(),
+                annotations: Some(m.behavior.annotations()),
+            });
+            schemas.push(BundleSchemas { input, output });
+        }
+
+        Self {
+            index: BundleIndex {
+                format_version: BUNDLE_FORMAT_VERSION,
+                schema_version: env!("CARGO_PKG_VERSION").to_string(),
+                tools,
+            },
+            schemas,
+        }
+    }
+
+    /// Read a bundle written by [`write_to`](Self::write_to) from its version
+    /// directory (the one holding `index.json`), e.g. a committed baseline
+    ///
+    /// # Errors
+    /// Returns any I/O error, or `InvalidData` if a file is not a bundle file.
+    pub fn read_from(dir: &Path) -> io::Result<Self> {
+        let read = |path: PathBuf| -> io::Result<Value> {
+            let text = std::fs::read_to_string(&path)?;
+            serde_json::from_str(&text)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+        };
+
+        let index: BundleIndex = serde_json::from_value(read(dir.join("index.json"))?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("index.json: {}", e)))?;
+        let schemas = index
+            .tools
+            .iter()
+            .map(|tool| {
+                let document = read(dir.join(&tool.schema))?;
+                let defs = document.get("$defs").and_then(Value::as_object).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("{}: missing $defs", tool.schema))
+                })?;
+                Ok(BundleSchemas {
+                    input: unhoist_defs(defs, "input"),
+                    output: unhoist_defs(defs, "output"),
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self { index, schemas })
+    }
+
+    /// Replace the declared behavior annotations with those of tool implementation `T`
+    ///
+    /// Does nothing if `T`'s Args are not in the bundle.
+    pub fn annotate<T: Tool>(&mut self) {
+        let name = <T::Args as ToolArgs>::NAME;
+        if let Some(tool) = self.index.tools.iter_mut().find(|tool| tool.name == name) {
+            tool.annotations = Some(T::annotations());
+        }
+    }
+
+    /// The bundle index
+    #[must_use]
+    pub fn index(&self) -> &BundleIndex {
+        &self.index
+    }
+
+    /// Every tool with its raw input and output schemas (with their own `$defs`)
+    pub fn schemas(&self) -> impl Iterator<Item = (&BundleTool, &Value, &Value)> {
+        self.index.tools.iter().zip(&self.schemas).map(|(tool, schemas)| (tool, &schemas.input, &schemas.output))
+    }
+
+    /// Self-contained JSON Schema of tool `name`: a call's `input` and `output`
+    #[must_use]
+    pub fn tool_schema(&self, name: &str) -> Option<Value> {
+        let position = self.index.tools.iter().position(|tool| tool.name == name)?;
+        let tool = &self.index.tools[position];
+        let schemas = &self.schemas[position];
+
+        let mut defs = Map::new();
+        let input = hoist_defs(schemas.input.clone(), "input_", "#/$defs/", &mut defs);
+        let output = hoist_defs(schemas.output.clone(), "output_", "#/$defs/", &mut defs);
+        defs.insert("input".to_string(), input);
+        defs.insert("output".to_string(), output);
+
+        Some(json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "$id": format!("urn:kodegen:schema:{}:{}", self.index.schema_version, tool.name),
+            "title": tool.name,
+            "description": tool.description,
+            "type": "object",
+            "properties": {
+                "input": { "$ref": "#/$defs/input" },
+                "output": { "$ref": "#/$defs/output" },
+            },
+            "$defs": defs,
+        }))
+    }
+
+    /// OpenAPI 3.1 document with one `POST /tools/{name}` operation per tool
+    #[must_use]
+    pub fn openapi(&self) -> Value {
+        let mut paths = Map::new();
+        let mut components = Map::new();
+        let mut tags = Vec::new();
+
+        for (tool, schemas) in self.index.tools.iter().zip(&self.schemas) {
+            let input = hoist_defs(schemas.input.clone(), &format!("{}_", tool.name), "#/components/schemas/", &mut components);
+            let output = hoist_defs(schemas.output.clone(), &format!("{}_", tool.name), "#/components/schemas/", &mut components);
+            let input_name = format!("{}_input", tool.name);
+            let output_name = format!("{}_output", tool.name);
+            components.insert(input_name.clone(), input);
+            components.insert(output_name.clone(), output);
+
+            if !tags.contains(&tool.category) {
+                tags.push(tool.category.clone());
+            }
+
+            let mut operation = json!({
+                "operationId": tool.name,
+                "summary": tool.name,
+                "description": tool.description,
+                "tags": [tool.category],
+                "requestBody": {
+                    "required": true,
+                    "content": { "application/json": { "schema": { "$ref": format!("#/components/schemas/{}", input_name) } } },
+                },
+                "responses": {
+                    "200": {
+                        "description": "Typed tool output",
+                        "content": { "application/json": { "schema": { "$ref": format!("#/components/schemas/{}", output_name) } } },
+                    },
+                },
+                "x-kodegen-icon": tool.icon.to_string(),
+            });
+            if let Some(annotations) = &tool.annotations {
+                operation["x-kodegen-annotations"] = serde_json::to_value(annotations).unwrap_or(Value::Null);
+            }
+            paths.insert(format!("/tools/{}", tool.name), json!({ "post": operation }));
+        }
+
+        tags.sort();
+        json!({
+            "openapi": "3.1.0",
+            "jsonSchemaDialect": JSON_SCHEMA_DIALECT,
+            "info": {
+                "title": "kodegen MCP tools",
+                "version": self.index.schema_version,
+            },
+            "tags": tags.into_iter().map(|name| json!({ "name": name })).collect::<Vec<_>>(),
+            "paths": paths,
+            "components": { "schemas": components },
+        })
+    }
+
+    /// Write the bundle to `{out}/{schema_version}/`, returning the files written
+    ///
+    /// # Errors
+    /// Returns any I/O error from creating directories or writing files.
+    pub fn write_to(&self, out: &Path, openapi: bool) -> io::Result<Vec<PathBuf>> {
+        let root = out.join(&self.index.schema_version);
+        std::fs::create_dir_all(root.join("tools"))?;
+
+        let mut written = Vec::new();
+        let mut write = |path: PathBuf, value: &Value| -> io::Result<()> {
+            let mut text = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+            text.push('\n');
+            std::fs::write(&path, text)?;
+            written.push(path);
+            Ok(())
+        };
+
+        for tool in &self.index.tools {
+            if let Some(schema) = self.tool_schema(&tool.name) {
+                write(root.join(&tool.schema), &schema)?;
+            }
+        }
+        if openapi {
+            write(root.join("openapi.json"), &self.openapi())?;
+        }
+        write(root.join("index.json"), &serde_json::to_value(&self.index).map_err(io::Error::other)?)?;
+
+        Ok(written)
+    }
+}
+
+/// Move `schema`'s `$defs` into `target` as `{prefix}{name}` and point its refs there.
+///
+/// Drops `$schema`, which only belongs on a document root.
+fn hoist_defs(mut schema: Value, prefix: &str, ref_base: &str, target: &mut Map<String, Value>) -> Value {
+    let defs = schema.as_object_mut().and_then(|object| {
+        object.remove("$schema");
+        object.remove("$defs")
+    });
+
+    if let Some(Value::Object(defs)) = defs {
+        for (name, mut def) in defs {
+            rewrite_refs(&mut def, prefix, ref_base);
+            target.insert(format!("{}{}", prefix, name), def);
+        }
+    }
+    rewrite_refs(&mut schema, prefix, ref_base);
+    schema
+}
+
+/// Inverse of [`hoist_defs`] for the `key` (`input`/`output`) schema of a tool file
+fn unhoist_defs(defs: &Map<String, Value>, key: &str) -> Value {
+    let prefix = format!("{}_", key);
+    let hoisted_base = format!("#/$defs/{}", prefix);
+
+    let mut schema = defs.get(key).cloned().unwrap_or_else(|| json!({}));
+    let mut own = Map::new();
+    for (name, def) in defs {
+        if let Some(name) = name.strip_prefix(&prefix) {
+            let mut def = def.clone();
+            replace_ref_base(&mut def, &hoisted_base, "#/$defs/");
+            own.insert(name.to_string(), def);
+        }
+    }
+    replace_ref_base(&mut schema, &hoisted_base, "#/$defs/");
+    if !own.is_empty()
+        && let Some(object) = schema.as_object_mut()
+    {
+        object.insert("$defs".to_string(), Value::Object(own));
+    }
+    schema
+}
+
+/// Rewrite every `#/$defs/{name}` reference to `{ref_base}{prefix}{name}`
+fn rewrite_refs(value: &mut Value, prefix: &str, ref_base: &str) {
+    replace_ref_base(value, "#/$defs/", &format!("{}{}", ref_base, prefix));
+}
+
+/// Replace the `from` prefix of every `$ref` starting with it by `to`
+fn replace_ref_base(value: &mut Value, from: &str, to: &str) {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                if key == "$ref"
+                    && let Value::String(reference) = child
+                    && let Some(name) = reference.strip_prefix(from)
+                {
+                    *reference = format!("{}{}", to, name);
+                } else {
+                    replace_ref_base(child, from, to);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| replace_ref_base(item, from, to)),
+        _ => {}
+    }
+}
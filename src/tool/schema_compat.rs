@@ -0,0 +1,563 @@
+//! Compatibility check between two schema bundles
+//!
+//! Deployed agents send Args shaped by the schemas they were built against, and
+//! stored transcripts hold outputs shaped by the release that produced them. A
+//! renamed field or a new required field breaks both without failing a build.
+//! [`compare`] diffs two [`SchemaBundle`]s, tool by tool, and classifies every
+//! change:
+//!
+//! | Change | Input (Args) | Output |
+//! |---|---|---|
+//! | tool removed | breaking | breaking |
+//! | field removed | breaking | breaking |
+//! | field newly required (or added as required) | breaking | breaking |
+//! | field no longer required | compatible | breaking |
+//! | enum value / union variant removed | breaking | breaking |
+//! | enum value / union variant added | compatible | breaking |
+//! | type changed | compatible if widened | breaking |
+//! | tool or optional field added | compatible | compatible |
+//!
+//! Outputs are read by older clients *and* replayed from older transcripts, so
+//! they must stay stable in both directions.
+//!
+//! Checked from a test against a committed baseline (see
+//! [`SchemaBundle::read_from`]):
+//!
+//! ```rust,ignore
+//! let baseline = SchemaBundle::read_from(Path::new("schemas/0.10.14")).unwrap();
+//! compare(&baseline, &SchemaBundle::from_inventory())
+//!     .allow("git_diff", "input.from") // intended, announced in the changelog
+//!     .assert_compatible();
+//! ```
+//!
+//! The `kodegen-schema` binary runs the same check with `--check` and `--diff`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+
+use super::schema_bundle::SchemaBundle;
+
+/// Whether a change breaks existing callers or data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    /// Existing agents and transcripts keep working
+    Compatible,
+
+    /// Existing agents or transcripts may fail to (de)serialize
+    Breaking,
+}
+
+/// What changed at a schema location
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    ToolAdded,
+    ToolRemoved,
+    FieldAdded,
+    FieldRemoved,
+    NewlyRequired,
+    NoLongerRequired,
+    EnumWidened,
+    EnumNarrowed,
+    TypeChanged,
+}
+
+/// One difference between the old and new schema of a tool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaChange {
+    /// Tool name
+    pub tool: String,
+
+    /// Location, e.g. `input.action` or `output.files[].path` (empty for the tool itself)
+    pub path: String,
+
+    /// What changed
+    pub kind: ChangeKind,
+
+    /// Whether it breaks existing callers or data
+    pub compatibility: Compatibility,
+
+    /// Human-readable detail, e.g. the removed enum values
+    pub detail: String,
+
+    /// Acknowledged with [`CompatReport::allow`]
+    #[serde(default)]
+    pub allowed: bool,
+}
+
+impl SchemaChange {
+    /// Breaking and not acknowledged
+    #[must_use]
+    pub fn is_unintended_break(&self) -> bool {
+        self.compatibility == Compatibility::Breaking && !self.allowed
+    }
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match (self.compatibility, self.allowed) {
+            (Compatibility::Breaking, false) => "BREAKING",
+            (Compatibility::Breaking, true) => "allowed",
+            (Compatibility::Compatible, _) => "ok",
+        };
+        write!(f, "[{}] {}", label, self.tool)?;
+        if !self.path.is_empty() {
+            write!(f, " {}", self.path)?;
+        }
+        write!(f, ": {}", self.detail)
+    }
+}
+
+/// Every change between two bundles
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompatReport {
+    /// Schema version of the old bundle
+    pub old_version: String,
+
+    /// Schema version of the new bundle
+    pub new_version: String,
+
+    /// Changes, ordered by tool then path
+    pub changes: Vec<SchemaChange>,
+}
+
+impl CompatReport {
+    /// Acknowledge the breaking changes of `tool` at or below `path`
+    ///
+    /// An empty `path` acknowledges every change of the tool.
+    #[must_use]
+    pub fn allow(mut self, tool: &str, path: &str) -> Self {
+        for change in &mut self.changes {
+            if change.tool == tool && is_at_or_below(&change.path, path) {
+                change.allowed = true;
+            }
+        }
+        self
+    }
+
+    /// Breaking changes that were not acknowledged
+    pub fn unintended_breaks(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes.iter().filter(|change| change.is_unintended_break())
+    }
+
+    /// No unacknowledged breaking change
+    #[must_use]
+    pub fn is_compatible(&self) -> bool {
+        self.unintended_breaks().next().is_none()
+    }
+
+    /// Panic with the list of unacknowledged breaking changes, for use in tests
+    ///
+    /// # Panics
+    /// Panics if the report is not [compatible](Self::is_compatible).
+    #[track_caller]
+    pub fn assert_compatible(&self) {
+        if !self.is_compatible() {
+            let breaks: Vec<String> = self.unintended_breaks().map(ToString::to_string).collect();
+            panic!(
+                "{} breaking schema change(s) from {} to {}:\n  {}\n\
+                 Acknowledge intended breaks with CompatReport::allow(tool, path).",
+                breaks.len(),
+                self.old_version,
+                self.new_version,
+                breaks.join("\n  ")
+            );
+        }
+    }
+}
+
+impl fmt::Display for CompatReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let breaking = self.unintended_breaks().count();
+        writeln!(
+            f,
+            "Schema changes {} -> {}: {} change(s), {} breaking",
+            self.old_version,
+            self.new_version,
+            self.changes.len(),
+            breaking
+        )?;
+        for change in &self.changes {
+            writeln!(f, "  {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Diff `old` against `new`
+#[must_use]
+pub fn compare(old: &SchemaBundle, new: &SchemaBundle) -> CompatReport {
+    let old_tools: BTreeMap<&str, (&Value, &Value)> =
+        old.schemas().map(|(tool, input, output)| (tool.name.as_str(), (input, output))).collect();
+    let new_tools: BTreeMap<&str, (&Value, &Value)> =
+        new.schemas().map(|(tool, input, output)| (tool.name.as_str(), (input, output))).collect();
+
+    let mut changes = Vec::new();
+    for (&name, &(old_input, old_output)) in &old_tools {
+        let Some(&(new_input, new_output)) = new_tools.get(name) else {
+            changes.push(change(name, String::new(), ChangeKind::ToolRemoved, Compatibility::Breaking, "tool removed".to_string()));
+            continue;
+        };
+        for (side, old_schema, new_schema) in [(Side::Input, old_input, new_input), (Side::Output, old_output, new_output)] {
+            let mut diff = Diff {
+                tool: name,
+                side,
+                old_root: old_schema,
+                new_root: new_schema,
+                in_progress: HashSet::new(),
+                changes: &mut changes,
+            };
+            diff.schema(side.path(), old_schema, new_schema);
+        }
+    }
+    for &name in new_tools.keys().filter(|name| !old_tools.contains_key(*name)) {
+        changes.push(change(name, String::new(), ChangeKind::ToolAdded, Compatibility::Compatible, "tool added".to_string()));
+    }
+    changes.sort_by(|a, b| (&a.tool, &a.path).cmp(&(&b.tool, &b.path)));
+
+    CompatReport {
+        old_version: old.index().schema_version.clone(),
+        new_version: new.index().schema_version.clone(),
+        changes,
+    }
+}
+
+fn change(tool: &str, path: String, kind: ChangeKind, compatibility: Compatibility, detail: String) -> SchemaChange {
+    SchemaChange {
+        tool: tool.to_string(),
+        path,
+        kind,
+        compatibility,
+        detail,
+        allowed: false,
+    }
+}
+
+/// `path` equals `prefix` or lies below it (`input.a` is below `input`, `input.ab` is not)
+fn is_at_or_below(path: &str, prefix: &str) -> bool {
+    prefix.is_empty()
+        || path
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('['))
+}
+
+/// Which half of a tool call a schema describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    /// Written by agents: may accept more, never less
+    Input,
+    /// Read by clients and replayed from transcripts: must not change
+    Output,
+}
+
+impl Side {
+    fn path(self) -> String {
+        match self {
+            Side::Input => "input".to_string(),
+            Side::Output => "output".to_string(),
+        }
+    }
+
+    /// Classification of a change that only widens what is valid
+    fn widening(self) -> Compatibility {
+        match self {
+            Side::Input => Compatibility::Compatible,
+            Side::Output => Compatibility::Breaking,
+        }
+    }
+}
+
+/// A schema with its `$ref`s resolved and its unions split into parts
+#[derive(Debug, Default)]
+struct Node<'a> {
+    /// JSON types, without `null`
+    types: BTreeSet<&'static str>,
+    nullable: bool,
+    /// Closed set of allowed values (enum, const, or a union of consts)
+    values: Option<BTreeSet<String>>,
+    /// Non-const union variants, keyed by their tag or position
+    variants: Option<BTreeMap<String, &'a Value>>,
+    properties: Option<&'a Map<String, Value>>,
+    required: BTreeSet<&'a str>,
+    items: Option<&'a Value>,
+    additional: Option<&'a Value>,
+}
+
+struct Diff<'a, 'c> {
+    tool: &'a str,
+    side: Side,
+    old_root: &'a Value,
+    new_root: &'a Value,
+    /// `(old ref, new ref)` pairs being compared, to stop at recursive types
+    in_progress: HashSet<(&'a str, &'a str)>,
+    changes: &'c mut Vec<SchemaChange>,
+}
+
+impl<'a> Diff<'a, '_> {
+    fn push(&mut self, path: &str, kind: ChangeKind, compatibility: Compatibility, detail: String) {
+        self.changes.push(change(self.tool, path.to_string(), kind, compatibility, detail));
+    }
+
+    fn schema(&mut self, path: String, old: &'a Value, new: &'a Value) {
+        if old == new && !contains_ref(old) {
+            return;
+        }
+        let refs = reference(old).zip(reference(new));
+        if let Some(pair) = refs
+            && !self.in_progress.insert(pair)
+        {
+            return;
+        }
+        self.compare(&path, old, new);
+        if let Some(pair) = refs {
+            self.in_progress.remove(&pair);
+        }
+    }
+
+    fn compare(&mut self, path: &str, old: &'a Value, new: &'a Value) {
+        let path = path.to_string();
+        let old = node(self.old_root, old);
+        let new = node(self.new_root, new);
+
+        if old.nullable != new.nullable {
+            let (detail, compatibility) = if new.nullable {
+                ("now nullable".to_string(), self.side.widening())
+            } else {
+                ("no longer nullable".to_string(), Compatibility::Breaking)
+            };
+            self.push(&path, ChangeKind::TypeChanged, compatibility, detail);
+        }
+
+        if old.types != new.types {
+            let widened = old.types.iter().all(|ty| {
+                new.types.contains(ty) || (*ty == "integer" && new.types.contains("number"))
+            }) || new.types.is_empty();
+            let compatibility = if widened { self.side.widening() } else { Compatibility::Breaking };
+            self.push(
+                &path,
+                ChangeKind::TypeChanged,
+                compatibility,
+                format!("type {} -> {}", type_label(&old.types), type_label(&new.types)),
+            );
+            if !widened {
+                return;
+            }
+        }
+
+        if let (Some(old_values), Some(new_values)) = (&old.values, &new.values) {
+            let removed: Vec<&str> = old_values.difference(new_values).map(String::as_str).collect();
+            let added: Vec<&str> = new_values.difference(old_values).map(String::as_str).collect();
+            if !removed.is_empty() {
+                self.push(&path, ChangeKind::EnumNarrowed, Compatibility::Breaking, format!("removed {}", removed.join(", ")));
+            }
+            if !added.is_empty() {
+                self.push(&path, ChangeKind::EnumWidened, self.side.widening(), format!("added {}", added.join(", ")));
+            }
+        } else if old.values.is_some() != new.values.is_some() {
+            let compatibility = if new.values.is_none() { self.side.widening() } else { Compatibility::Breaking };
+            let detail = if new.values.is_none() { "no longer restricted to fixed values" } else { "now restricted to fixed values" };
+            self.push(&path, ChangeKind::TypeChanged, compatibility, detail.to_string());
+        }
+
+        if let (Some(old_variants), Some(new_variants)) = (&old.variants, &new.variants) {
+            for (key, &old_variant) in old_variants {
+                match new_variants.get(key) {
+                    Some(&new_variant) => self.schema(format!("{}<{}>", path, key), old_variant, new_variant),
+                    None => self.push(&path, ChangeKind::EnumNarrowed, Compatibility::Breaking, format!("removed variant {}", key)),
+                }
+            }
+            for key in new_variants.keys().filter(|key| !old_variants.contains_key(*key)) {
+                self.push(&path, ChangeKind::EnumWidened, self.side.widening(), format!("added variant {}", key));
+            }
+        }
+
+        if let (Some(old_properties), Some(new_properties)) = (old.properties, new.properties) {
+            self.properties(&path, (old_properties, &old.required), (new_properties, &new.required));
+        }
+
+        if let (Some(old_items), Some(new_items)) = (old.items, new.items) {
+            self.schema(format!("{}[]", path), old_items, new_items);
+        }
+        if let (Some(old_values), Some(new_values)) = (old.additional, new.additional) {
+            self.schema(format!("{}{{}}", path), old_values, new_values);
+        }
+    }
+
+    fn properties(
+        &mut self,
+        path: &str,
+        (old, old_required): (&'a Map<String, Value>, &BTreeSet<&'a str>),
+        (new, new_required): (&'a Map<String, Value>, &BTreeSet<&'a str>),
+    ) {
+        for (name, old_property) in old {
+            let field_path = format!("{}.{}", path, name);
+            let Some(new_property) = new.get(name) else {
+                self.push(&field_path, ChangeKind::FieldRemoved, Compatibility::Breaking, "field removed".to_string());
+                continue;
+            };
+            match (old_required.contains(name.as_str()), new_required.contains(name.as_str())) {
+                (false, true) => {
+                    self.push(&field_path, ChangeKind::NewlyRequired, Compatibility::Breaking, "now required".to_string());
+                }
+                (true, false) => {
+                    self.push(&field_path, ChangeKind::NoLongerRequired, self.side.widening(), "no longer required".to_string());
+                }
+                _ => {}
+            }
+            self.schema(field_path, old_property, new_property);
+        }
+
+        for name in new.keys().filter(|name| !old.contains_key(*name)) {
+            let field_path = format!("{}.{}", path, name);
+            if new_required.contains(name.as_str()) {
+                self.push(&field_path, ChangeKind::NewlyRequired, Compatibility::Breaking, "added as required".to_string());
+            } else {
+                self.push(&field_path, ChangeKind::FieldAdded, Compatibility::Compatible, "optional field added".to_string());
+            }
+        }
+    }
+}
+
+fn reference(schema: &Value) -> Option<&str> {
+    schema.get("$ref").and_then(Value::as_str)
+}
+
+fn contains_ref(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object.iter().any(|(key, child)| key == "$ref" || contains_ref(child)),
+        Value::Array(items) => items.iter().any(contains_ref),
+        _ => false,
+    }
+}
+
+/// Follow `schema`'s `$ref`s into `root`'s `$defs`
+fn resolve<'a>(root: &'a Value, mut schema: &'a Value) -> &'a Value {
+    for _ in 0..32 {
+        let Some(target) = reference(schema)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| root.pointer(pointer))
+        else {
+            break;
+        };
+        schema = target;
+    }
+    schema
+}
+
+fn node<'a>(root: &'a Value, schema: &'a Value) -> Node<'a> {
+    let schema = resolve(root, schema);
+    let mut node = Node::default();
+    let Some(object) = schema.as_object() else {
+        return node;
+    };
+    node.nullable = object.get("nullable").and_then(Value::as_bool) == Some(true);
+
+    if let Some(Value::Array(variants)) = object.get("oneOf").or_else(|| object.get("anyOf")) {
+        let mut values = BTreeSet::new();
+        let mut others = Vec::new();
+        for variant in variants {
+            let variant = resolve(root, variant);
+            match variant.get("const") {
+                Some(Value::Null) => node.nullable = true,
+                Some(constant) => {
+                    values.insert(constant.to_string());
+                    if let Some(kind) = variant.get("type").and_then(Value::as_str) {
+                        node.types.insert(json_type(kind));
+                    }
+                }
+                None if variant.get("type").and_then(Value::as_str) == Some("null") => node.nullable = true,
+                None => others.push(variant),
+            }
+        }
+
+        // `Option<T>` and friends: a single real variant stands for itself
+        if let ([single], true) = (others.as_slice(), values.is_empty()) {
+            let mut inner = self::node(root, single);
+            inner.nullable |= node.nullable;
+            return inner;
+        }
+        if !values.is_empty() {
+            node.values = Some(values);
+        }
+        if !others.is_empty() {
+            node.types.insert("object");
+            node.variants = Some(
+                others
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, variant)| (variant_key(variant, index), variant))
+                    .collect(),
+            );
+        }
+        return node;
+    }
+
+    if let Some(constant) = object.get("const") {
+        node.values = Some(BTreeSet::from([constant.to_string()]));
+    } else if let Some(Value::Array(values)) = object.get("enum") {
+        node.values = Some(values.iter().filter(|value| !value.is_null()).map(Value::to_string).collect());
+        node.nullable |= values.iter().any(Value::is_null);
+    }
+
+    match object.get("type") {
+        Some(Value::String(kind)) if kind == "null" => node.nullable = true,
+        Some(Value::String(kind)) => {
+            node.types.insert(json_type(kind));
+        }
+        Some(Value::Array(kinds)) => {
+            for kind in kinds.iter().filter_map(Value::as_str) {
+                if kind == "null" {
+                    node.nullable = true;
+                } else {
+                    node.types.insert(json_type(kind));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(Value::Object(properties)) = object.get("properties") {
+        node.types.insert("object");
+        node.properties = Some(properties);
+        node.required = object
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+    }
+    node.items = object.get("items");
+    node.additional = object.get("additionalProperties").filter(|values| values.is_object());
+    node
+}
+
+/// Key of a union variant: the value of its string tag, else its position
+fn variant_key(variant: &Value, index: usize) -> String {
+    variant
+        .get("properties")
+        .and_then(Value::as_object)
+        .and_then(|properties| properties.values().find_map(|property| property.get("const").and_then(Value::as_str)))
+        .map_or_else(|| format!("#{}", index), str::to_string)
+}
+
+fn json_type(kind: &str) -> &'static str {
+    match kind {
+        "string" => "string",
+        "integer" => "integer",
+        "number" => "number",
+        "boolean" => "boolean",
+        "array" => "array",
+        "object" => "object",
+        _ => "unknown",
+    }
+}
+
+fn type_label(types: &BTreeSet<&str>) -> String {
+    if types.is_empty() {
+        "any".to_string()
+    } else {
+        types.iter().copied().collect::<Vec<_>>().join("|")
+    }
+}
@@ -0,0 +1,134 @@
+//! Precomputed schema registry for all tools registered via `#[tool_metadata]`
+//!
+//! Every `ToolMetadata` collected by `inventory` contributes one entry whose
+//! input and output schemas are generated exactly once, the first time the
+//! registry is touched (or eagerly via [`SchemaRegistry::init`]). Lookups are
+//! keyed by the tool's `&'static str` name and never allocate.
+
+use schemars::transform::{AddNullable, Transform};
+use schemars::{JsonSchema, Schema};
+use serde_json::{Map, Value};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+
+use log::{debug, warn};
+
+use crate::ToolMetadata;
+
+/// JSON object form of a schema, as consumed by `rmcp::model::Tool`.
+pub type JsonObject = Map<String, Value>;
+
+/// Input and output schemas for a single tool.
+#[derive(Debug, Clone)]
+pub struct ToolSchemas {
+    /// Tool name (unique identifier)
+    pub name: &'static str,
+
+    /// Schema of the tool's Args type
+    pub input: Arc<JsonObject>,
+
+    /// Schema of `<Args as ToolArgs>::Output`
+    pub output: Arc<JsonObject>,
+}
+
+/// Read-only registry of tool schemas built from `inventory::iter::<ToolMetadata>`.
+pub struct SchemaRegistry {
+    by_name: HashMap<&'static str, ToolSchemas>,
+}
+
+static REGISTRY: LazyLock<SchemaRegistry> = LazyLock::new(SchemaRegistry::build);
+
+/// Fallback cache for Args types that are not registered in inventory.
+///
+/// Keyed by `TypeId` of the Args (input) or Output type so no key allocation is needed.
+type TypeSchemaCache = parking_lot::RwLock<HashMap<TypeId, Arc<JsonObject>>>;
+
+static TYPE_SCHEMA_CACHE: LazyLock<TypeSchemaCache> =
+    LazyLock::new(|| parking_lot::RwLock::new(HashMap::new()));
+
+impl SchemaRegistry {
+    fn build() -> Self {
+        let mut by_name = HashMap::new();
+
+        for metadata in inventory::iter::<ToolMetadata> {
+            let schemas = ToolSchemas {
+                name: metadata.name,
+                input: Arc::new(to_mcp_schema((metadata.args_schema)())),
+                output: Arc::new(to_mcp_schema((metadata.output_schema)())),
+            };
+
+            if by_name.insert(metadata.name, schemas).is_some() {
+                warn!("Duplicate ToolMetadata registration for tool: {}", metadata.name);
+            }
+        }
+
+        debug!("Schema registry built with {} tools", by_name.len());
+        Self { by_name }
+    }
+
+    /// Force the registry to be built now (e.g. during server startup).
+    ///
+    /// Calling this is optional - the registry builds itself on first access.
+    pub fn init() {
+        LazyLock::force(&REGISTRY);
+    }
+
+    /// Look up the precomputed schemas for a tool by name.
+    #[must_use]
+    pub fn get(name: &str) -> Option<&'static ToolSchemas> {
+        REGISTRY.by_name.get(name)
+    }
+
+    /// Iterate over every registered tool's schemas (unordered).
+    pub fn iter() -> impl Iterator<Item = &'static ToolSchemas> {
+        REGISTRY.by_name.values()
+    }
+
+    /// Number of registered tools.
+    #[must_use]
+    pub fn len() -> usize {
+        REGISTRY.by_name.len()
+    }
+
+    /// Schema for an arbitrary type, cached by `TypeId`.
+    ///
+    /// Used by `Tool::input_schema`/`Tool::output_schema` for Args types that
+    /// are not registered with `#[tool_metadata]`.
+    pub(crate) fn schema_for_type<T: JsonSchema + 'static>() -> Arc<JsonObject> {
+        let key = TypeId::of::<T>();
+
+        if let Some(schema) = TYPE_SCHEMA_CACHE.read().get(&key) {
+            return schema.clone();
+        }
+
+        let schema = Arc::new(rmcp::handler::server::tool::schema_for_type::<T>());
+        TYPE_SCHEMA_CACHE
+            .write()
+            .entry(key)
+            .or_insert(schema)
+            .clone()
+    }
+}
+
+/// Convert a schemars root schema into the dialect rmcp advertises to clients.
+///
+/// Mirrors `rmcp::handler::server::tool::schema_for_type`, which generates
+/// draft 2020-12 schemas with the `AddNullable` transform applied.
+fn to_mcp_schema(value: Value) -> JsonObject {
+    let mut schema = match Schema::try_from(value) {
+        Ok(schema) => schema,
+        Err(e) => {
+            warn!("Tool schema is not a valid JSON Schema: {}", e);
+            return JsonObject::new();
+        }
+    };
+
+    AddNullable::default().transform(&mut schema);
+
+    match schema.to_value() {
+        Value::Object(object) => object,
+        // `true`/`false` schemas accept anything/nothing; represent as empty object
+        _ => JsonObject::new(),
+    }
+}
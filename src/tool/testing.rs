@@ -0,0 +1,387 @@
+//! In-process test support for tool implementations (feature = "testing")
+//!
+//! `ToolExecutionContext` needs a live `Peer<RoleServer>`, which rmcp only
+//! hands out for a running service. [`TestContext`] runs a real server over an
+//! in-memory duplex stream against a scripted loopback client, so tools can be
+//! executed and their notifications inspected without any transport setup.
+//!
+//! The loopback client:
+//! - records every progress notification (`stream`, `progress`, `update`, `notify`)
+//! - records elicitation requests and answers them with a configured reply
+//! - answers `roots/list`, which [`TestContext`] uses as a delivery barrier
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use kodegen_mcp_schema::tool::testing::TestContext;
+//!
+//! #[tokio::test]
+//! async fn test_streams_output() {
+//!     let test = TestContext::builder().pwd("/repo").build().await;
+//!
+//!     let result = test.call_tool(TerminalTool::new(), json!({"command": "echo hi"})).await.unwrap();
+//!     assert_eq!(result.is_error, Some(false));
+//!     assert_eq!(test.messages().await, vec!["hi\n"]);
+//! }
+//! ```
+
+use parking_lot::Mutex;
+use rmcp::handler::server::router::tool::ToolRouter;
+use rmcp::handler::server::tool::ToolCallContext;
+use rmcp::model::{
+    CallToolRequestParam, CallToolResult, CreateElicitationRequestParam, CreateElicitationResult, Extensions, Meta,
+    NumberOrString, ProgressNotificationParam, ProtocolVersion, RequestId,
+};
+use rmcp::service::{RequestContext, RunningService};
+use rmcp::{RoleServer, ServerHandler};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream};
+use tokio_util::sync::CancellationToken;
+
+use super::router::RouteConfig;
+use super::traits::{Tool, ToolExecutionContext};
+
+/// Server side of the loopback connection (default handler for everything)
+#[derive(Clone)]
+struct LoopbackServer;
+
+impl ServerHandler for LoopbackServer {}
+
+/// What the loopback client has received
+#[derive(Default)]
+struct Captured {
+    progress: Vec<ProgressNotificationParam>,
+    elicitations: Vec<CreateElicitationRequestParam>,
+}
+
+// ============================================================================
+// BUILDER
+// ============================================================================
+
+/// Configures a [`TestContext`].
+#[derive(Debug, Clone)]
+pub struct TestContextBuilder {
+    pwd: Option<PathBuf>,
+    git_root: Option<PathBuf>,
+    connection_id: Option<String>,
+    request_id: RequestId,
+    protocol_version: ProtocolVersion,
+    elicitation: Option<CreateElicitationResult>,
+    meta: Meta,
+}
+
+impl Default for TestContextBuilder {
+    fn default() -> Self {
+        Self {
+            pwd: None,
+            git_root: None,
+            connection_id: None,
+            request_id: NumberOrString::Number(1),
+            protocol_version: ProtocolVersion::LATEST,
+            elicitation: None,
+            meta: Meta::new(),
+        }
+    }
+}
+
+impl TestContextBuilder {
+    /// Client working directory reported by [`ToolExecutionContext::pwd`]
+    #[must_use]
+    pub fn pwd(mut self, pwd: impl Into<PathBuf>) -> Self {
+        self.pwd = Some(pwd.into());
+        self
+    }
+
+    /// Repository root reported by [`ToolExecutionContext::git_root`]
+    #[must_use]
+    pub fn git_root(mut self, git_root: impl Into<PathBuf>) -> Self {
+        self.git_root = Some(git_root.into());
+        self
+    }
+
+    /// Connection ID reported by [`ToolExecutionContext::connection_id`]
+    #[must_use]
+    pub fn connection_id(mut self, connection_id: impl Into<String>) -> Self {
+        self.connection_id = Some(connection_id.into());
+        self
+    }
+
+    /// Request ID of the contexts handed out (default: 1)
+    #[must_use]
+    pub fn request_id(mut self, request_id: RequestId) -> Self {
+        self.request_id = request_id;
+        self
+    }
+
+    /// Protocol version the loopback client negotiates (default: latest)
+    #[must_use]
+    pub fn protocol_version(mut self, version: ProtocolVersion) -> Self {
+        self.protocol_version = version;
+        self
+    }
+
+    /// Advertise elicitation support and answer every elicitation with `reply`
+    #[must_use]
+    pub fn elicitation(mut self, reply: CreateElicitationResult) -> Self {
+        self.elicitation = Some(reply);
+        self
+    }
+
+    /// Request `_meta` sent with every tool call (e.g. `timeoutMs`)
+    #[must_use]
+    pub fn meta(mut self, meta: Meta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    /// Start the loopback server and client
+    ///
+    /// # Panics
+    /// Panics if the in-memory handshake fails, which indicates a bug.
+    pub async fn build(self) -> TestContext {
+        let (server_io, client_io) = tokio::io::duplex(64 * 1024);
+        let captured = Arc::new(Mutex::new(Captured::default()));
+
+        tokio::spawn(run_client(
+            client_io,
+            self.protocol_version.clone(),
+            self.elicitation.clone(),
+            captured.clone(),
+        ));
+
+        let service = rmcp::serve_server(LoopbackServer, server_io)
+            .await
+            .expect("loopback MCP handshake failed");
+
+        TestContext {
+            service,
+            captured,
+            ct: CancellationToken::new(),
+            config: self,
+        }
+    }
+}
+
+// ============================================================================
+// TEST CONTEXT
+// ============================================================================
+
+/// A connected loopback client handing out [`ToolExecutionContext`]s.
+pub struct TestContext {
+    service: RunningService<RoleServer, LoopbackServer>,
+    captured: Arc<Mutex<Captured>>,
+    ct: CancellationToken,
+    config: TestContextBuilder,
+}
+
+impl TestContext {
+    /// Configure a test context
+    #[must_use]
+    pub fn builder() -> TestContextBuilder {
+        TestContextBuilder::default()
+    }
+
+    /// Test context with default settings (no pwd, no elicitation support)
+    pub async fn new() -> Self {
+        Self::builder().build().await
+    }
+
+    /// Execution context to pass to `Tool::execute`
+    #[must_use]
+    pub fn context(&self) -> ToolExecutionContext {
+        ToolExecutionContext::from_request_context(&self.request_context())
+    }
+
+    /// Cancellation token shared by every context handed out
+    #[must_use]
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.ct
+    }
+
+    /// Cancel the current call (contexts report `is_cancelled() == true`)
+    pub fn cancel(&self) {
+        self.ct.cancel();
+    }
+
+    /// Every progress notification received so far, in order
+    pub async fn notifications(&self) -> Vec<ProgressNotificationParam> {
+        self.flush().await;
+        self.captured.lock().progress.clone()
+    }
+
+    /// Messages of the progress notifications received so far
+    pub async fn messages(&self) -> Vec<String> {
+        self.notifications()
+            .await
+            .into_iter()
+            .filter_map(|notification| notification.message)
+            .collect()
+    }
+
+    /// Every elicitation request received so far, in order
+    pub async fn elicitations(&self) -> Vec<CreateElicitationRequestParam> {
+        self.flush().await;
+        self.captured.lock().elicitations.clone()
+    }
+
+    /// Forget the notifications and elicitations received so far
+    pub async fn clear(&self) {
+        self.flush().await;
+        let mut captured = self.captured.lock();
+        captured.progress.clear();
+        captured.elicitations.clear();
+    }
+
+    /// Call `tool` with JSON `arguments` through its MCP route.
+    ///
+    /// Runs exactly what a server would: argument deserialization, the route
+    /// config and result conversion. Arguments that do not match the Args
+    /// schema come back as `invalid_arguments` tool errors; the JSON-RPC
+    /// error is returned only for protocol failures.
+    pub async fn call_tool<T: Tool>(&self, tool: T, arguments: Value) -> Result<CallToolResult, rmcp::ErrorData> {
+        self.call_tool_with(Arc::new(tool), RouteConfig::default(), arguments).await
+    }
+
+    /// Call an Arc-wrapped `tool` through a route built with `config`
+    /// (middleware, response mode, confirmation policy, progress interval).
+    pub async fn call_tool_with<T: Tool>(
+        &self,
+        tool: Arc<T>,
+        config: RouteConfig,
+        arguments: Value,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let mut router = ToolRouter::<()>::new();
+        router.add_route(tool.arc_into_tool_route_with(config));
+
+        let arguments = match arguments {
+            Value::Object(map) => Some(map),
+            Value::Null => None,
+            other => {
+                return Err(rmcp::ErrorData::invalid_params(
+                    format!("tool arguments must be an object, found {}", other),
+                    None,
+                ));
+            }
+        };
+        let request = CallToolRequestParam {
+            name: T::name().into(),
+            arguments,
+        };
+
+        router
+            .call(ToolCallContext::new(&(), request, self.request_context()))
+            .await
+    }
+
+    /// Request context carrying the configured kodegen headers
+    fn request_context(&self) -> RequestContext<RoleServer> {
+        use kodegen_config::{X_KODEGEN_CONNECTION_ID, X_KODEGEN_GITROOT, X_KODEGEN_PWD};
+
+        let mut request = http::Request::builder();
+        if let Some(connection_id) = &self.config.connection_id {
+            request = request.header(X_KODEGEN_CONNECTION_ID, connection_id.as_str());
+        }
+        if let Some(pwd) = &self.config.pwd {
+            request = request.header(X_KODEGEN_PWD, pwd.to_string_lossy().as_ref());
+        }
+        if let Some(git_root) = &self.config.git_root {
+            request = request.header(X_KODEGEN_GITROOT, git_root.to_string_lossy().as_ref());
+        }
+
+        let mut extensions = Extensions::new();
+        if let Ok(request) = request.body(()) {
+            extensions.insert(request.into_parts().0);
+        }
+
+        RequestContext {
+            ct: self.ct.clone(),
+            id: self.config.request_id.clone(),
+            meta: self.config.meta.clone(),
+            extensions,
+            peer: self.service.peer().clone(),
+        }
+    }
+
+    /// Round-trip a request so every earlier message has reached the client
+    async fn flush(&self) {
+        let _ = self.service.peer().list_roots().await;
+    }
+}
+
+// ============================================================================
+// LOOPBACK CLIENT
+// ============================================================================
+
+/// Scripted MCP client: initializes, then records and answers server messages
+async fn run_client(
+    io: DuplexStream,
+    protocol_version: ProtocolVersion,
+    elicitation: Option<CreateElicitationResult>,
+    captured: Arc<Mutex<Captured>>,
+) -> std::io::Result<()> {
+    let (read, mut write) = tokio::io::split(io);
+    let mut lines = BufReader::new(read).lines();
+
+    let mut capabilities = json!({ "roots": {} });
+    if elicitation.is_some() {
+        capabilities["elicitation"] = json!({});
+    }
+    send(&mut write, &json!({
+        "jsonrpc": "2.0",
+        "id": 0,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": protocol_version,
+            "capabilities": capabilities,
+            "clientInfo": { "name": "kodegen-test-client", "version": env!("CARGO_PKG_VERSION") },
+        },
+    }))
+    .await?;
+
+    while let Some(line) = lines.next_line().await? {
+        let Ok(message) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+
+        let method = message.get("method").and_then(Value::as_str);
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        let reply = match (message.get("id"), method) {
+            // Initialize response
+            (Some(_), None) => {
+                send(&mut write, &json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await?;
+                continue;
+            }
+            (None, Some("notifications/progress")) => {
+                if let Ok(progress) = serde_json::from_value(params) {
+                    captured.lock().progress.push(progress);
+                }
+                continue;
+            }
+            (Some(_), Some("roots/list")) => json!({ "roots": [] }),
+            (Some(_), Some("elicitation/create")) => {
+                if let Ok(request) = serde_json::from_value(params) {
+                    captured.lock().elicitations.push(request);
+                }
+                match &elicitation {
+                    Some(reply) => serde_json::to_value(reply).unwrap_or(Value::Null),
+                    None => json!({ "action": "cancel" }),
+                }
+            }
+            (Some(_), Some("ping")) => json!({}),
+            _ => continue,
+        };
+
+        send(&mut write, &json!({ "jsonrpc": "2.0", "id": message["id"], "result": reply })).await?;
+    }
+
+    Ok(())
+}
+
+async fn send(write: &mut (impl AsyncWriteExt + Unpin), message: &Value) -> std::io::Result<()> {
+    let mut line = message.to_string();
+    line.push('\n');
+    write.write_all(line.as_bytes()).await?;
+    write.flush().await
+}
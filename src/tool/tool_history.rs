@@ -1,4 +1,20 @@
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use log::warn;
+use sha2::{Digest, Sha256};
+
+use super::error::McpError;
+use super::middleware::ToolHistorySink;
+use crate::introspection::{
+    InspectToolCallsArgs, InspectToolCallsOutput, InspectUsageOutput, ToolUsageStats,
+};
 
 /// Single tool call record
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,4 +34,419 @@ pub struct ToolCallRecord {
     /// Execution duration in milliseconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration_ms: Option<u64>,
+
+    /// Whether the call succeeded (records written before this field existed count as successful)
+    #[serde(default = "default_success")]
+    pub success: bool,
+}
+
+fn default_success() -> bool {
+    true
+}
+
+impl From<&ToolCallRecord> for crate::introspection::ToolCallRecord {
+    fn from(record: &ToolCallRecord) -> Self {
+        Self {
+            tool_name: record.tool_name.clone(),
+            timestamp: record.timestamp.clone(),
+            duration_ms: record.duration_ms,
+            args_json: record.args_json.clone(),
+            output_json: record.output_json.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// TOOL HISTORY STORE
+// ============================================================================
+
+/// Connection key used when a call has no connection ID (non-HTTP transports)
+pub const DEFAULT_CONNECTION_ID: &str = "default";
+
+/// Default number of records kept in memory per connection
+pub const DEFAULT_HISTORY_CAPACITY: usize = 1000;
+
+/// Default number of connections kept open before the least recently used is closed
+pub const DEFAULT_MAX_CONNECTIONS: usize = 256;
+
+/// Upper bound for `InspectToolCallsArgs::max_results`
+const MAX_RESULTS_LIMIT: usize = 1000;
+
+/// Aggregated counters for one tool (lifetime of the connection, not just the ring buffer)
+#[derive(Debug, Clone, Default)]
+struct ToolTotals {
+    call_count: usize,
+    total_duration_ms: u64,
+}
+
+/// History of one connection
+#[derive(Debug)]
+struct ConnectionHistory {
+    /// Most recent records, oldest first
+    records: VecDeque<ToolCallRecord>,
+    /// When this connection's history started
+    started: DateTime<Utc>,
+    /// Last time a call was recorded or queried (for LRU eviction)
+    last_used: Instant,
+    successful_calls: usize,
+    failed_calls: usize,
+    /// Per-tool totals, sorted by tool name
+    per_tool: BTreeMap<String, ToolTotals>,
+    /// JSONL file records are appended to (when persistence is enabled)
+    file: Option<File>,
+}
+
+impl ConnectionHistory {
+    fn new(file: Option<File>) -> Self {
+        Self {
+            records: VecDeque::new(),
+            started: Utc::now(),
+            last_used: Instant::now(),
+            successful_calls: 0,
+            failed_calls: 0,
+            per_tool: BTreeMap::new(),
+            file,
+        }
+    }
+
+    fn push(&mut self, record: ToolCallRecord, capacity: usize) {
+        if record.success {
+            self.successful_calls += 1;
+        } else {
+            self.failed_calls += 1;
+        }
+
+        let totals = self.per_tool.entry(record.tool_name.clone()).or_default();
+        totals.call_count += 1;
+        totals.total_duration_ms += record.duration_ms.unwrap_or(0);
+
+        if self.records.len() == capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+}
+
+/// Bounded, per-connection tool call history.
+///
+/// Keeps the most recent `capacity` records of each connection in a ring
+/// buffer, optionally appending every record to
+/// `<data_dir>/tool_history/<connection_id>-<hash>.jsonl`. The query methods
+/// produce the exact output structures of the `inspect_tool_calls` and
+/// `inspect_usage_stats` tools.
+///
+/// Servers should call [`close_connection`](Self::close_connection) when a
+/// client disconnects. Beyond that, at most `max_connections` histories are
+/// kept open; the least recently used one is closed to make room for a new one.
+///
+/// Implements [`ToolHistorySink`], so it can be plugged directly into
+/// [`HistoryMiddleware`](super::middleware::HistoryMiddleware).
+pub struct ToolHistory {
+    capacity: usize,
+    max_connections: usize,
+    persist_dir: Option<PathBuf>,
+    connections: Mutex<HashMap<String, ConnectionHistory>>,
+}
+
+impl Default for ToolHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_CAPACITY)
+    }
+}
+
+impl ToolHistory {
+    /// In-memory history keeping up to `capacity` records per connection.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            persist_dir: None,
+            connections: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// History persisted as JSONL under the kodegen data directory
+    /// (`~/.config/kodegen/data/tool_history/`).
+    pub fn persistent(capacity: usize) -> Result<Self, McpError> {
+        let dir = kodegen_config::KodegenConfig::data_dir()?.join("tool_history");
+        Self::persistent_in(capacity, dir)
+    }
+
+    /// History persisted as JSONL files in `dir` (one file per connection).
+    ///
+    /// Existing files are loaded the first time their connection is seen,
+    /// so history survives server restarts.
+    pub fn persistent_in(capacity: usize, dir: impl Into<PathBuf>) -> Result<Self, McpError> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            persist_dir: Some(dir),
+            ..Self::new(capacity)
+        })
+    }
+
+    /// Keep at most `max_connections` connection histories open (default 256).
+    #[must_use]
+    pub fn with_max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections.max(1);
+        self
+    }
+
+    /// Record a tool call for a connection (`None` = [`DEFAULT_CONNECTION_ID`]).
+    pub fn record(&self, connection_id: Option<&str>, record: ToolCallRecord) {
+        let key = connection_id.unwrap_or(DEFAULT_CONNECTION_ID);
+        let mut connections = self.connections.lock();
+        if !connections.contains_key(key) {
+            self.evict_least_recently_used(&mut connections);
+        }
+        let history = connections
+            .entry(key.to_string())
+            .or_insert_with(|| self.open_connection(key));
+        history.last_used = Instant::now();
+
+        if let Some(file) = history.file.as_mut()
+            && let Err(e) = append_jsonl(file, &record)
+        {
+            warn!("Failed to persist tool call record for '{}': {}", key, e);
+        }
+
+        history.push(record, self.capacity);
+    }
+
+    /// Query recorded calls, producing the `inspect_tool_calls` output.
+    ///
+    /// - `tool_name` keeps only calls to that tool
+    /// - `since` (ISO 8601) keeps only calls at or after that instant
+    /// - non-negative `offset` skips that many matching calls (oldest first)
+    ///   and returns up to `max_results` (capped at 1000)
+    /// - negative `offset` returns the newest `|offset|` matching calls,
+    ///   capped at the newest `max_results`
+    ///
+    /// Unknown connections have no calls.
+    pub fn inspect_tool_calls(
+        &self,
+        connection_id: Option<&str>,
+        args: &InspectToolCallsArgs,
+    ) -> Result<InspectToolCallsOutput, McpError> {
+        let since = args
+            .since
+            .as_deref()
+            .map(|s| {
+                DateTime::parse_from_rfc3339(s)
+                    .map(|t| t.with_timezone(&Utc))
+                    .map_err(|e| McpError::invalid_arguments(format!("Invalid 'since' timestamp '{}': {}", s, e)))
+            })
+            .transpose()?;
+
+        let empty = ConnectionHistory::new(None);
+        let mut connections = self.connections.lock();
+        let history = self.lookup(&mut connections, connection_id).unwrap_or(&empty);
+
+        let matching: Vec<&ToolCallRecord> = history
+            .records
+            .iter()
+            .filter(|r| args.tool_name.as_deref().is_none_or(|name| r.tool_name == name))
+            .filter(|r| {
+                since.is_none_or(|since| {
+                    DateTime::parse_from_rfc3339(&r.timestamp).is_ok_and(|t| t >= since)
+                })
+            })
+            .collect();
+
+        let max_results = args.max_results.min(MAX_RESULTS_LIMIT);
+        let selected: Vec<&ToolCallRecord> = if args.offset < 0 {
+            let tail = (args.offset.unsigned_abs() as usize).min(max_results).min(matching.len());
+            matching[matching.len() - tail..].to_vec()
+        } else {
+            matching
+                .into_iter()
+                .skip(args.offset as usize)
+                .take(max_results)
+                .collect()
+        };
+
+        let calls: Vec<crate::introspection::ToolCallRecord> =
+            selected.into_iter().map(Into::into).collect();
+
+        Ok(InspectToolCallsOutput {
+            success: true,
+            count: calls.len(),
+            total_entries_in_memory: history.records.len(),
+            calls,
+            filter_tool_name: args.tool_name.clone(),
+            filter_since: args.since.clone(),
+            offset: args.offset,
+            max_results,
+        })
+    }
+
+    /// Aggregate usage statistics, producing the `inspect_usage_stats` output.
+    ///
+    /// Statistics cover every call recorded for the connection, including
+    /// calls already evicted from the ring buffer. Unknown connections have
+    /// no calls.
+    pub fn usage_stats(&self, connection_id: Option<&str>) -> InspectUsageOutput {
+        let empty = ConnectionHistory::new(None);
+        let mut connections = self.connections.lock();
+        let history = self.lookup(&mut connections, connection_id).unwrap_or(&empty);
+
+        let total_calls = history.successful_calls + history.failed_calls;
+        let tool_usage: Vec<ToolUsageStats> = history
+            .per_tool
+            .iter()
+            .map(|(name, totals)| ToolUsageStats {
+                tool_name: name.clone(),
+                call_count: totals.call_count,
+                total_duration_ms: totals.total_duration_ms,
+                avg_duration_ms: totals.total_duration_ms / totals.call_count.max(1) as u64,
+            })
+            .collect();
+
+        let success_rate = if total_calls == 0 {
+            0.0
+        } else {
+            history.successful_calls as f64 * 100.0 / total_calls as f64
+        };
+
+        InspectUsageOutput {
+            success: true,
+            total_calls,
+            tools_used: tool_usage.len(),
+            tool_usage,
+            session_duration_ms: (Utc::now() - history.started).num_milliseconds().max(0) as u64,
+            success_rate,
+            successful_calls: history.successful_calls,
+            failed_calls: history.failed_calls,
+        }
+    }
+
+    /// Close a connection: drop its in-memory history and its open history
+    /// file (the persisted file is kept). Call this when a client disconnects.
+    pub fn close_connection(&self, connection_id: Option<&str>) {
+        self.connections
+            .lock()
+            .remove(connection_id.unwrap_or(DEFAULT_CONNECTION_ID));
+    }
+
+    /// History of a connection for read-only queries.
+    ///
+    /// Connections not seen yet are only opened if a persisted file exists,
+    /// so queries for unknown ids neither allocate an entry nor create a file.
+    fn lookup<'a>(
+        &self,
+        connections: &'a mut HashMap<String, ConnectionHistory>,
+        connection_id: Option<&str>,
+    ) -> Option<&'a ConnectionHistory> {
+        let key = connection_id.unwrap_or(DEFAULT_CONNECTION_ID);
+        if !connections.contains_key(key) {
+            let persisted = self.persist_dir.as_deref().is_some_and(|dir| history_path(dir, key).is_file());
+            if !persisted {
+                return None;
+            }
+            self.evict_least_recently_used(connections);
+            connections.insert(key.to_string(), self.open_connection(key));
+        }
+        let history = connections.get_mut(key)?;
+        history.last_used = Instant::now();
+        Some(history)
+    }
+
+    /// Close least recently used connections until there is room for one more.
+    fn evict_least_recently_used(&self, connections: &mut HashMap<String, ConnectionHistory>) {
+        while connections.len() >= self.max_connections {
+            let Some(oldest) = connections
+                .iter()
+                .min_by_key(|(_, history)| history.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            connections.remove(&oldest);
+        }
+    }
+
+    /// Create the history for a newly seen connection, loading persisted records.
+    fn open_connection(&self, key: &str) -> ConnectionHistory {
+        let Some(dir) = &self.persist_dir else {
+            return ConnectionHistory::new(None);
+        };
+
+        let path = history_path(dir, key);
+        let loaded = load_jsonl(&path);
+
+        let file = match OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                warn!("Failed to open tool history file {}: {}", path.display(), e);
+                None
+            }
+        };
+
+        let mut history = ConnectionHistory::new(file);
+        if let Some(started) = loaded
+            .first()
+            .and_then(|r| DateTime::parse_from_rfc3339(&r.timestamp).ok())
+        {
+            history.started = started.with_timezone(&Utc);
+        }
+        for record in loaded {
+            history.push(record, self.capacity);
+        }
+        history
+    }
+}
+
+impl ToolHistorySink for ToolHistory {
+    fn record(&self, connection_id: Option<&str>, record: ToolCallRecord) {
+        ToolHistory::record(self, connection_id, record);
+    }
+}
+
+/// Append one record as a JSON line
+fn append_jsonl(file: &mut File, record: &ToolCallRecord) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    file.write_all(&line)
+}
+
+/// Load records from a JSONL file, skipping malformed lines
+fn load_jsonl(path: &Path) -> Vec<ToolCallRecord> {
+    let Ok(file) = File::open(path) else {
+        return Vec::new();
+    };
+
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(&line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!("Skipping malformed tool history line in {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Persisted history file of a connection in `dir`.
+///
+/// The sanitized ID keeps the name readable; the hash of the raw ID keeps
+/// IDs that sanitize alike (`conn/1`, `conn_1`) in separate files.
+fn history_path(dir: &Path, connection_id: &str) -> PathBuf {
+    let digest = Sha256::digest(connection_id.as_bytes());
+    dir.join(format!(
+        "{}-{}.jsonl",
+        sanitize_file_stem(connection_id),
+        hex::encode(&digest[..4])
+    ))
+}
+
+/// Make a connection ID safe to use as a file name
+fn sanitize_file_stem(connection_id: &str) -> String {
+    connection_id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
 }
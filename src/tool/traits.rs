@@ -1,16 +1,22 @@
 use rmcp::handler::server::tool::schema_for_type;
-use rmcp::model::{CallToolResult, Content, Meta, PromptArgument, PromptMessage};
+use rmcp::model::{CallToolResult, Content, JsonObject, Meta, PromptArgument, PromptMessage, RawResource};
 use schemars::JsonSchema;
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
 use std::borrow::Cow;
-use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
-use log::{debug, error, info, warn};
+use log::{debug, error, warn};
 
+use super::coercion::{ARGUMENT_COERCIONS_META_KEY, Coercion, coerce_arguments};
+use super::content::ContentPart;
 use super::error::McpError;
+use super::middleware::ToolCall;
+use super::progress::{PROGRESS_STATS_META_KEY, ProgressStats, ProgressUpdate, Push, Throttle};
+use super::router::RouteConfig;
+use super::schema_registry::SchemaRegistry;
 
 // Re-export ToolArgs from parent crate
 pub use crate::ToolArgs;
@@ -48,6 +54,19 @@ pub trait PromptProvider: sealed::Sealed + Send + Sync + 'static {
     /// These let agents customize what they want to learn about.
     /// Example: "repo" (which repo?), "shallow" (learn about shallow clones?)
     fn prompt_arguments() -> Vec<PromptArgument>;
+
+    /// Complete a prompt argument value for MCP `completion/complete`.
+    ///
+    /// Defaults to the enum values `PromptArgs` declares for `argument`
+    /// (e.g. every [`PromptScenario`](super::PromptScenario) name) that start
+    /// with `prefix`, compared case-insensitively.
+    fn complete_prompt_argument(argument: &str, prefix: &str) -> Vec<String> {
+        let prefix = prefix.to_lowercase();
+        super::prompt_scenario::argument_values::<Self::PromptArgs>(argument)
+            .into_iter()
+            .filter(|value| value.to_lowercase().starts_with(&prefix))
+            .collect()
+    }
 }
 
 /// Re-export sealed trait for schema package to implement
@@ -115,6 +134,37 @@ pub fn add_branded_line_to_result(
     call_result.content.insert(0, branded_content);
 }
 
+/// Where a tool's typed metadata is placed in the `CallToolResult`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseMode {
+    /// Metadata as pretty-printed JSON in `content[1]`, no `structured_content` (legacy layout)
+    #[default]
+    Text,
+    /// Metadata in `structured_content`; `content` holds only the display text
+    Structured,
+    /// Metadata in `structured_content` AND as JSON text in `content[1]`
+    /// (the layout MCP recommends for backwards compatibility)
+    Both,
+    /// `Both` for clients that negotiated protocol 2025-06-18 or later, `Text` otherwise
+    Auto,
+}
+
+impl ResponseMode {
+    /// Resolve `Auto` against the client's negotiated protocol version.
+    ///
+    /// Other modes are returned unchanged.
+    #[must_use]
+    pub fn resolve(self, protocol_version: Option<&rmcp::model::ProtocolVersion>) -> Self {
+        match self {
+            Self::Auto => match protocol_version {
+                Some(v) if *v >= rmcp::model::ProtocolVersion::V_2025_06_18 => Self::Both,
+                _ => Self::Text,
+            },
+            mode => mode,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ToolResponse<M> {
     /// Human-readable display output - goes to Content[0].
@@ -135,6 +185,13 @@ pub struct ToolResponse<M> {
     /// Contains only structured data (exit_code, duration_ms, etc.)
     /// NOT the display content.
     pub metadata: M,
+
+    /// Images, audio, embedded resources and resource links - go after the metadata.
+    ///
+    /// Empty for text-only tools. Added with [`with_part`](Self::with_part) and
+    /// friends; build resource links from the schema's typed URIs (see
+    /// [`ResourceUri::link`](super::ResourceUri::link)).
+    parts: Vec<ContentPart>,
 }
 
 impl<M> ToolResponse<M> {
@@ -148,6 +205,7 @@ impl<M> ToolResponse<M> {
         Self {
             display: display.into(),
             metadata,
+            parts: Vec::new(),
         }
     }
 
@@ -159,8 +217,42 @@ impl<M> ToolResponse<M> {
         Self {
             display: String::new(),
             metadata,
+            parts: Vec::new(),
         }
     }
+
+    /// Add a content part (image, audio, embedded resource or resource link).
+    #[must_use]
+    pub fn with_part(mut self, part: ContentPart) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// Add a base64-encoded image (e.g. a screenshot) for vision-capable clients.
+    #[must_use]
+    pub fn with_image(self, data: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        self.with_part(ContentPart::image(data, mime_type))
+    }
+
+    /// Add a link to a resource produced by this call.
+    #[must_use]
+    pub fn with_link(self, link: RawResource) -> Self {
+        self.with_part(ContentPart::link(link))
+    }
+
+    /// Content parts added to the response, in order
+    #[must_use]
+    pub fn parts(&self) -> &[ContentPart] {
+        &self.parts
+    }
+
+    /// Content blocks of the parts, in order
+    fn part_contents(&mut self) -> Vec<Content> {
+        std::mem::take(&mut self.parts)
+            .into_iter()
+            .map(ContentPart::into_content)
+            .collect()
+    }
 }
 
 impl<M: Serialize> ToolResponse<M> {
@@ -169,21 +261,62 @@ impl<M: Serialize> ToolResponse<M> {
     /// # Content Layout
     /// - `content[0]`: Human-readable display (always present, may be empty)
     /// - `content[1]`: Typed metadata as pretty-printed JSON
+    /// - `content[2..]`: Content parts (images, audio, resources), if any
     ///
     /// Both display and metadata are in the content Vec - no structured_content.
-    pub fn into_call_tool_result(self) -> Result<CallToolResult, serde_json::Error> {
+    pub fn into_call_tool_result(mut self) -> Result<CallToolResult, serde_json::Error> {
+        let parts = self.part_contents();
         let display_content = Content::text(self.display);
         let json = serde_json::to_string_pretty(&self.metadata)?;
         let metadata_content = Content::text(json);
 
         Ok(CallToolResult {
-            content: vec![display_content, metadata_content],
+            content: [display_content, metadata_content].into_iter().chain(parts).collect(),
             structured_content: None,
             is_error: None,
             meta: None,
         })
     }
 
+    /// Convert to CallToolResult using the given [`ResponseMode`].
+    ///
+    /// # Content Layout
+    /// - `Text`: `content[0]` display, `content[1]` metadata JSON
+    /// - `Structured`: `content[0]` display, `structured_content` metadata
+    /// - `Both`: `content[0]` display, `content[1]` metadata JSON, `structured_content` metadata
+    ///
+    /// Content parts follow the display/metadata content in every mode.
+    ///
+    /// `Auto` must be resolved first (see [`ResponseMode::resolve`]); if passed
+    /// here unresolved it behaves like `Text`.
+    pub fn into_call_tool_result_with_mode(
+        mut self,
+        mode: ResponseMode,
+    ) -> Result<CallToolResult, serde_json::Error> {
+        match mode {
+            ResponseMode::Text | ResponseMode::Auto => self.into_call_tool_result(),
+            ResponseMode::Structured => Ok(CallToolResult {
+                content: std::iter::once(Content::text(std::mem::take(&mut self.display)))
+                    .chain(self.part_contents())
+                    .collect(),
+                structured_content: Some(serde_json::to_value(&self.metadata)?),
+                is_error: None,
+                meta: None,
+            }),
+            ResponseMode::Both => {
+                let structured = serde_json::to_value(&self.metadata)?;
+                let json = serde_json::to_string_pretty(&structured)?;
+                let parts = self.part_contents();
+                Ok(CallToolResult {
+                    content: [Content::text(self.display), Content::text(json)].into_iter().chain(parts).collect(),
+                    structured_content: Some(structured),
+                    is_error: None,
+                    meta: None,
+                })
+            }
+        }
+    }
+
     /// Get metadata as JSON Value (for history recording).
     pub fn metadata_as_json(&self) -> serde_json::Value {
         serde_json::to_value(&self.metadata).unwrap_or_else(|_| serde_json::json!({}))
@@ -195,6 +328,7 @@ impl<M: Serialize> ToolResponse<M> {
     /// - `content[0]`: Branded line (ⓚ icon tool_name duration)
     /// - `content[1]`: Human-readable display (was content[0])
     /// - `content[2]`: Typed metadata as pretty-printed JSON (was content[1])
+    /// - `content[3..]`: Content parts, if any
     ///
     /// # Arguments
     /// - `tool_name`: Name of the tool being executed
@@ -202,7 +336,7 @@ impl<M: Serialize> ToolResponse<M> {
     /// - `duration_ms`: Execution duration in milliseconds
     /// - `status`: Success or Error status for coloring
     pub fn into_call_tool_result_with_branding(
-        self,
+        mut self,
         tool_name: &str,
         icon: char,
         duration_ms: u64,
@@ -230,12 +364,13 @@ impl<M: Serialize> ToolResponse<M> {
 
         // Create content vector with branded line first
         let branded_content = Content::text(branded_line);
+        let parts = self.part_contents();
         let display_content = Content::text(self.display);
         let json = serde_json::to_string_pretty(&self.metadata)?;
         let metadata_content = Content::text(json);
 
         Ok(CallToolResult {
-            content: vec![branded_content, display_content, metadata_content],
+            content: [branded_content, display_content, metadata_content].into_iter().chain(parts).collect(),
             structured_content: None,
             is_error: None,
             meta: None,
@@ -243,18 +378,6 @@ impl<M: Serialize> ToolResponse<M> {
     }
 }
 
-// ============================================================================
-// PERFORMANCE OPTIMIZATIONS
-// ============================================================================
-
-/// Type alias for the schema cache to reduce complexity
-type SchemaCache =
-    parking_lot::RwLock<HashMap<&'static str, std::sync::Arc<serde_json::Map<String, Value>>>>;
-
-/// Schema cache to avoid repeated serialization
-static SCHEMA_CACHE: std::sync::LazyLock<SchemaCache> =
-    std::sync::LazyLock::new(|| parking_lot::RwLock::new(HashMap::new()));
-
 // ============================================================================
 // CORE TRAIT
 // ============================================================================
@@ -327,32 +450,28 @@ pub trait Tool: Send + Sync + Sized + 'static {
     }
 
     /// Input schema - AUTO-GENERATED from Args type via `JsonSchema` derive
-    /// Cached for performance - schema is computed once and reused
+    ///
+    /// Served from the precomputed [`SchemaRegistry`] when the Args type is
+    /// registered with `#[tool_metadata]`; otherwise generated once and cached
+    /// by `TypeId`.
     #[inline]
     fn input_schema() -> std::sync::Arc<serde_json::Map<String, Value>> {
-        let name = Self::name();
-
-        // Fast path: read from cache
-        if let Some(schema) = SCHEMA_CACHE.read().get(name) {
-            return schema.clone();
+        // Fast path: precomputed registry (no allocation)
+        if let Some(schemas) = SchemaRegistry::get(<Self::Args as ToolArgs>::NAME) {
+            return schemas.input.clone();
         }
 
-        // Slow path: generate and cache
-        // Log schema generation attempt
-        debug!("Generating schema for tool: {}", name);
-        
+        // Slow path: Args not in inventory - generate and cache by TypeId
+        debug!("Generating schema for unregistered tool: {}", Self::name());
+
         // Validate schema generation with panic catching
         if let Err(e) = Self::validate_schema() {
             error!("{}", e);
             // For now, still proceed but with warning - could be made fatal in future
-            warn!("Tool '{}' registered with potentially invalid schema", name);
-        } else {
-            info!("✓ Schema generated successfully for tool: {}", name);
+            warn!("Tool '{}' registered with potentially invalid schema", Self::name());
         }
-        
-        let schema = std::sync::Arc::new(schema_for_type::<Self::Args>());
-        SCHEMA_CACHE.write().insert(name, schema.clone());
-        schema
+
+        SchemaRegistry::schema_for_type::<Self::Args>()
     }
 
     /// Output schema - AUTO-GENERATED from `<Args as ToolArgs>::Output`.
@@ -363,24 +482,11 @@ pub trait Tool: Send + Sync + Sized + 'static {
     #[must_use]
     #[inline]
     fn output_schema() -> std::sync::Arc<serde_json::Map<String, Value>> {
-        // Use a separate cache namespace for output schemas
-        static OUTPUT_SCHEMA_CACHE: std::sync::LazyLock<SchemaCache> =
-            std::sync::LazyLock::new(|| parking_lot::RwLock::new(HashMap::new()));
-
-        let name = Self::name();
-        let cache_key = Box::leak(format!("{}_output", name).into_boxed_str());
-
-        // Fast path: read from cache
-        if let Some(schema) = OUTPUT_SCHEMA_CACHE.read().get(cache_key) {
-            return schema.clone();
+        if let Some(schemas) = SchemaRegistry::get(<Self::Args as ToolArgs>::NAME) {
+            return schemas.output.clone();
         }
 
-        // Slow path: generate and cache from Args::Output
-        let schema = std::sync::Arc::new(
-            schema_for_type::<<Self::Args as ToolArgs>::Output>()
-        );
-        OUTPUT_SCHEMA_CACHE.write().insert(cache_key, schema.clone());
-        schema
+        SchemaRegistry::schema_for_type::<<Self::Args as ToolArgs>::Output>()
     }
 
     // ========================================================================
@@ -392,11 +498,11 @@ pub trait Tool: Send + Sync + Sized + 'static {
     /// true = read-only (safe, can't break things)
     /// false = writes/modifies state (requires caution)
     ///
-    /// Default: true (assumes read-only by default - safe default)
+    /// Default: the Args' [`ToolArgs::BEHAVIOR`]
     #[must_use]
     #[inline]
     fn read_only() -> bool {
-        true
+        <Self::Args as ToolArgs>::BEHAVIOR.read_only
     }
 
     /// Can this tool delete or overwrite existing data?
@@ -405,11 +511,11 @@ pub trait Tool: Send + Sync + Sized + 'static {
     /// true = can delete/overwrite (dangerous)
     /// false = only adds/creates (safer)
     ///
-    /// Default: false (assumes non-destructive by default - safe default)
+    /// Default: the Args' [`ToolArgs::BEHAVIOR`]
     #[must_use]
     #[inline]
     fn destructive() -> bool {
-        false
+        <Self::Args as ToolArgs>::BEHAVIOR.destructive
     }
 
     /// Is calling this tool repeatedly with same args safe/idempotent?
@@ -418,11 +524,11 @@ pub trait Tool: Send + Sync + Sized + 'static {
     /// true = safe to retry (same result every time)
     /// false = each call has different effect
     ///
-    /// Default: true (assumes idempotent by default - safe default)
+    /// Default: the Args' [`ToolArgs::BEHAVIOR`]
     #[must_use]
     #[inline]
     fn idempotent() -> bool {
-        true
+        <Self::Args as ToolArgs>::BEHAVIOR.idempotent
     }
 
     /// Does this tool interact with external systems (network, filesystem outside repo)?
@@ -430,11 +536,37 @@ pub trait Tool: Send + Sync + Sized + 'static {
     /// true = open world (network calls, external APIs, can fail due to external factors)
     /// false = closed world (only local operations, deterministic)
     ///
-    /// Default: false (assumes local operations by default - safe default)
+    /// Default: the Args' [`ToolArgs::BEHAVIOR`]
     #[must_use]
     #[inline]
     fn open_world() -> bool {
-        false
+        <Self::Args as ToolArgs>::BEHAVIOR.open_world
+    }
+
+    /// MCP annotations built from the behavior methods above
+    #[must_use]
+    fn annotations() -> rmcp::model::ToolAnnotations {
+        rmcp::model::ToolAnnotations::new()
+            .read_only(Self::read_only())
+            .destructive(Self::destructive())
+            .idempotent(Self::idempotent())
+            .open_world(Self::open_world())
+    }
+
+    // ========================================================================
+    // LIMITS
+    // ========================================================================
+
+    /// Longest a call may run before it is stopped with [`McpError::TimedOut`].
+    ///
+    /// Clients can override it per call with `timeoutMs` in the request
+    /// `_meta` (see [`TIMEOUT_META_KEY`]).
+    ///
+    /// Default: None (no timeout; the call still stops when cancelled)
+    #[must_use]
+    #[inline]
+    fn default_timeout() -> Option<Duration> {
+        None
     }
 
     // ========================================================================
@@ -496,40 +628,7 @@ pub trait Tool: Send + Sync + Sized + 'static {
     where
         S: Send + Sync + 'static,
     {
-        use rmcp::handler::server::router::tool::ToolRoute;
-        use rmcp::model::{Tool as RmcpTool, ToolAnnotations, Meta};
-        use std::sync::Arc;
-
-        // Build annotations from trait methods
-        let annotations = ToolAnnotations::new()
-            .read_only(Self::read_only())
-            .destructive(Self::destructive())
-            .idempotent(Self::idempotent())
-            .open_world(Self::open_world());
-
-        // Store icon in meta field
-        let mut meta = Meta::new();
-        meta.0.insert("icon".to_string(), serde_json::json!(<Self::Args as ToolArgs>::icon().to_string()));
-
-        // Build RMCP Tool metadata
-        let metadata = RmcpTool {
-            name: Self::name().into(),
-            title: None,
-            description: Some(Self::description().into()),
-            input_schema: Self::input_schema(),
-            output_schema: Some(Self::output_schema()),
-            annotations: Some(annotations),
-            icons: None,
-            meta: Some(meta),
-        };
-
-        // Create handler with ToolHandler wrapper (HRTB-compatible, zero-cost)
-        let handler = ToolHandler {
-            tool: Arc::new(self),
-        };
-
-        // Use ToolRoute::new() - handles HRTB internally
-        ToolRoute::new(metadata, handler)
+        Arc::new(self).arc_into_tool_route_with(RouteConfig::default())
     }
 
     /// Convert this tool into an RMCP `PromptRoute`
@@ -579,18 +678,29 @@ pub trait Tool: Send + Sync + Sized + 'static {
     /// This is more efficient than `into_tool_route(self)` when the tool is already wrapped in Arc.
     /// The tool is used directly without creating an additional Arc wrapper.
     fn arc_into_tool_route<S>(self: Arc<Self>) -> rmcp::handler::server::router::tool::ToolRoute<S>
+    where
+        S: Send + Sync + 'static,
+    {
+        self.arc_into_tool_route_with(RouteConfig::default())
+    }
+
+    /// Convert Arc-wrapped tool into an RMCP `ToolRoute` using `config`
+    /// (middleware stack and response mode).
+    ///
+    /// Usually called by [`ToolRouterBuilder`](super::router::ToolRouterBuilder),
+    /// which shares one config across every route it builds.
+    fn arc_into_tool_route_with<S>(
+        self: Arc<Self>,
+        config: RouteConfig,
+    ) -> rmcp::handler::server::router::tool::ToolRoute<S>
     where
         S: Send + Sync + 'static,
     {
         use rmcp::handler::server::router::tool::ToolRoute;
-        use rmcp::model::{Tool as RmcpTool, ToolAnnotations, Meta};
+        use rmcp::model::{Tool as RmcpTool, Meta};
 
         // Build annotations from trait methods
-        let annotations = ToolAnnotations::new()
-            .read_only(Self::read_only())
-            .destructive(Self::destructive())
-            .idempotent(Self::idempotent())
-            .open_world(Self::open_world());
+        let annotations = Self::annotations();
 
         // Store icon in meta field
         let mut meta = Meta::new();
@@ -612,6 +722,7 @@ pub trait Tool: Send + Sync + Sized + 'static {
         // Use self directly (already Arc<Self>) - no extra Arc allocation
         let handler = ToolHandler {
             tool: self,
+            config,
         };
 
         // Use ToolRoute::new() - handles HRTB internally
@@ -689,6 +800,12 @@ pub trait ToolPrompts: Tool {
     fn prompt(args: <Self::Prompts as PromptProvider>::PromptArgs) -> Result<Vec<PromptMessage>, McpError> {
         Ok(<Self::Prompts as PromptProvider>::generate_prompts(&args))
     }
+
+    /// Complete a prompt argument value (calls schema's PromptProvider)
+    #[inline]
+    fn complete_prompt_argument(argument: &str, prefix: &str) -> Vec<String> {
+        <Self::Prompts as PromptProvider>::complete_prompt_argument(argument, prefix)
+    }
 }
 
 /// Blanket implementation - all Tools automatically get ToolPrompts
@@ -698,6 +815,12 @@ impl<T: Tool> ToolPrompts for T {}
 // PROGRESS NOTIFICATION CONTEXT
 // ============================================================================
 
+/// Most streamed text kept for the partial output of a stopped call
+pub const STREAMED_OUTPUT_LIMIT: usize = 64 * 1024;
+
+/// Request `_meta` key overriding [`Tool::default_timeout`] for one call, in milliseconds
+pub const TIMEOUT_META_KEY: &str = "timeoutMs";
+
 /// Execution context provided to tools for progress notifications and cancellation.
 ///
 /// Supports three patterns:
@@ -726,6 +849,15 @@ pub struct ToolExecutionContext {
 
     /// Git repository root from client environment
     git_root: Option<PathBuf>,
+
+    /// Tail of the text sent with `stream()`, reported if the call is stopped
+    output: Arc<parking_lot::Mutex<String>>,
+
+    /// Pending progress updates and their rate limit
+    throttle: Arc<parking_lot::Mutex<Throttle>>,
+
+    /// Held while sending so batches reach the client in order
+    send_lock: Arc<tokio::sync::Mutex<()>>,
 }
 
 impl ToolExecutionContext {
@@ -752,6 +884,9 @@ impl ToolExecutionContext {
             connection_id: None,
             pwd: None,
             git_root: None,
+            output: Arc::default(),
+            throttle: Arc::new(parking_lot::Mutex::new(Throttle::new(Duration::ZERO))),
+            send_lock: Arc::default(),
         }
     }
 
@@ -785,6 +920,17 @@ impl ToolExecutionContext {
         &self.request_id
     }
 
+    /// Peer of the client that sent this request
+    pub(crate) fn peer(&self) -> &rmcp::service::Peer<rmcp::RoleServer> {
+        &self.peer
+    }
+
+    /// Protocol version the client negotiated during initialization, if known
+    #[must_use]
+    pub fn client_protocol_version(&self) -> Option<rmcp::model::ProtocolVersion> {
+        self.peer.peer_info().map(|info| info.protocol_version.clone())
+    }
+
     /// Stream a text message (for terminal output, logs, status updates).
     ///
     /// Use this for incrementally streaming text output as it becomes available.
@@ -796,7 +942,27 @@ impl ToolExecutionContext {
     /// ctx.stream("added 234 packages in 15s\n").await.ok();
     /// ```
     pub async fn stream(&self, message: impl Into<String>) -> Result<(), McpError> {
-        self.notify_internal(0.0, None, Some(message.into())).await
+        let message = message.into();
+        self.record_output(&message);
+        self.notify_internal(ProgressUpdate::Text(message)).await
+    }
+
+    /// Text streamed so far (at most the last [`STREAMED_OUTPUT_LIMIT`] bytes)
+    #[must_use]
+    pub fn streamed_output(&self) -> String {
+        self.output.lock().clone()
+    }
+
+    fn record_output(&self, message: &str) {
+        let mut output = self.output.lock();
+        output.push_str(message);
+        if output.len() > STREAMED_OUTPUT_LIMIT {
+            let mut cut = output.len() - STREAMED_OUTPUT_LIMIT;
+            while !output.is_char_boundary(cut) {
+                cut += 1;
+            }
+            output.drain(..cut);
+        }
     }
 
     /// Report numeric progress (for progress bars, counters).
@@ -809,7 +975,7 @@ impl ToolExecutionContext {
     /// ctx.progress(50.0, 100.0).await.ok();
     /// ```
     pub async fn progress(&self, current: f64, total: f64) -> Result<(), McpError> {
-        self.notify_internal(current, Some(total), None).await
+        self.notify_internal(ProgressUpdate::Progress { progress: current, total: Some(total), message: None }).await
     }
 
     /// Report both numeric progress and a descriptive message.
@@ -826,7 +992,12 @@ impl ToolExecutionContext {
         total: f64,
         message: impl Into<String>
     ) -> Result<(), McpError> {
-        self.notify_internal(current, Some(total), Some(message.into())).await
+        self.notify_internal(ProgressUpdate::Progress {
+            progress: current,
+            total: Some(total),
+            message: Some(message.into()),
+        })
+        .await
     }
 
     /// Advanced: Full control over progress notification fields.
@@ -844,18 +1015,61 @@ impl ToolExecutionContext {
         total: Option<f64>,
         message: Option<String>
     ) -> Result<(), McpError> {
-        self.notify_internal(progress, total, message).await
+        self.notify_internal(ProgressUpdate::Progress { progress, total, message }).await
     }
 
-    /// Internal implementation - sends the actual notification
-    async fn notify_internal(
-        &self,
-        progress: f64,
-        total: Option<f64>,
-        message: Option<String>
-    ) -> Result<(), McpError> {
+    /// Limit progress notifications to one batch per `interval`
+    /// (see [`progress`](super::progress)); zero sends every update immediately.
+    #[must_use]
+    pub fn with_progress_interval(self, interval: Duration) -> Self {
+        *self.throttle.lock() = Throttle::new(interval);
+        self
+    }
+
+    /// Counts of progress updates sent, merged and dropped so far
+    #[must_use]
+    pub fn progress_stats(&self) -> ProgressStats {
+        self.throttle.lock().stats()
+    }
+
+    /// Send every pending (throttled) progress update now
+    pub async fn flush_progress(&self) -> Result<(), McpError> {
+        let _sending = self.send_lock.lock().await;
+        let batch = self.throttle.lock().take(std::time::Instant::now());
+        for update in batch {
+            self.send_progress(update).await?;
+        }
+        Ok(())
+    }
+
+    /// Internal implementation - buffers the update and sends when the throttle allows
+    async fn notify_internal(&self, update: ProgressUpdate) -> Result<(), McpError> {
+        let push = self.throttle.lock().push(update, std::time::Instant::now());
+        match push {
+            Push::Flush => self.flush_progress().await,
+            Push::Schedule(at) => {
+                let ctx = self.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep_until(at.into()).await;
+                    if let Err(e) = ctx.flush_progress().await {
+                        debug!("Dropped throttled progress notification: {}", e);
+                    }
+                });
+                Ok(())
+            }
+            Push::Buffered => Ok(()),
+        }
+    }
+
+    /// Sends one progress notification
+    async fn send_progress(&self, update: ProgressUpdate) -> Result<(), McpError> {
         use rmcp::model::{ProgressNotificationParam, ProgressToken, NumberOrString};
 
+        let (progress, total, message) = match update {
+            ProgressUpdate::Text(text) => (0.0, None, Some(text)),
+            ProgressUpdate::Progress { progress, total, message } => (progress, total, message),
+        };
+
         // Generate unique progress token from request ID
         let progress_token = ProgressToken(NumberOrString::String(
             format!("tool_{}", match &self.request_id {
@@ -914,8 +1128,19 @@ where
     fn from_context_part(
         context: &mut rmcp::handler::server::tool::ToolCallContext<'_, S>
     ) -> Result<Self, rmcp::ErrorData> {
+        Ok(ToolExecutionContext::from_request_context(&context.request_context))
+    }
+}
+
+impl ToolExecutionContext {
+    /// Build a context from any server request (tool calls, completions, ...).
+    ///
+    /// Reads the kodegen infrastructure headers from the HTTP request parts
+    /// rmcp injects; they are None for non-HTTP transports.
+    #[must_use]
+    pub fn from_request_context(request_context: &rmcp::service::RequestContext<rmcp::RoleServer>) -> Self {
         // Extract HTTP request Parts (automatically injected by rmcp)
-        let parts = context.request_context.extensions.get::<http::request::Parts>();
+        let parts = request_context.extensions.get::<http::request::Parts>();
 
         // Extract kodegen headers from Parts
         use kodegen_config::{X_KODEGEN_CONNECTION_ID, X_KODEGEN_PWD, X_KODEGEN_GITROOT};
@@ -939,14 +1164,17 @@ where
             (None, None, None)
         };
 
-        Ok(ToolExecutionContext {
-            peer: context.request_context.peer.clone(),
-            ct: context.request_context.ct.clone(),
-            request_id: context.request_context.id.clone(),
+        ToolExecutionContext {
+            peer: request_context.peer.clone(),
+            ct: request_context.ct.clone(),
+            request_id: request_context.id.clone(),
             connection_id,
             pwd,
             git_root,
-        })
+            output: Arc::default(),
+            throttle: Arc::new(parking_lot::Mutex::new(Throttle::new(Duration::ZERO))),
+            send_lock: Arc::default(),
+        }
     }
 }
 
@@ -958,16 +1186,66 @@ where
 /// This enables HRTB-compatible tool routing without closure lifetime issues.
 struct ToolHandler<T: Tool> {
     tool: Arc<T>,
+    config: RouteConfig,
 }
 
 impl<T: Tool> Clone for ToolHandler<T> {
     fn clone(&self) -> Self {
         Self {
             tool: self.tool.clone(),
+            config: self.config.clone(),
         }
     }
 }
 
+impl<T: Tool> ToolHandler<T> {
+    /// Execute the tool and convert its response, keeping the metadata JSON
+    /// for middlewares (only computed when a middleware stack is present).
+    async fn execute(
+        &self,
+        args: T::Args,
+        exec_ctx: ToolExecutionContext,
+        mode: ResponseMode,
+        timeout: Option<Duration>,
+    ) -> Result<(Value, CallToolResult), McpError> {
+        // Cross-field requirements the schema cannot express
+        args.validate()?;
+
+        // Destructive calls only run once confirmed
+        if let Some(policy) = &self.config.confirmation
+            && let Some(action) = args.destructive_action()
+        {
+            policy.confirm(T::name(), &action, &exec_ctx).await?;
+        }
+
+        let outcome = run_stoppable(self.tool.execute(args, exec_ctx.clone()), &exec_ctx, timeout).await;
+
+        // Deliver throttled updates before the result
+        if let Err(e) = exec_ctx.flush_progress().await {
+            debug!("Failed to flush progress for {}: {}", T::name(), e);
+        }
+        let response = outcome?;
+
+        // Opt-in: check serialized metadata against the advertised output schema
+        #[cfg(feature = "validate-output")]
+        super::output_validation::check_tool_output(
+            T::name(),
+            &T::output_schema(),
+            &response.metadata_as_json(),
+        )?;
+
+        let metadata = if self.config.middleware.is_empty() {
+            Value::Null
+        } else {
+            response.metadata_as_json()
+        };
+
+        // Convert ToolResponse to CallToolResult in the negotiated layout
+        let result = response.into_call_tool_result_with_mode(mode)?;
+        Ok((metadata, result))
+    }
+}
+
 impl<T, S> rmcp::handler::server::tool::CallToolHandler<S, ()> for ToolHandler<T>
 where
     T: Tool,
@@ -977,32 +1255,171 @@ where
         self,
         mut context: rmcp::handler::server::tool::ToolCallContext<'_, S>,
     ) -> futures::future::BoxFuture<'_, Result<rmcp::model::CallToolResult, rmcp::ErrorData>> {
-        use rmcp::handler::server::wrapper::Parameters;
         use rmcp::handler::server::common::FromContextPart;
 
         Box::pin(async move {
-            // Extract arguments and execution context
-            let Parameters(args) = Parameters::<T::Args>::from_context_part(&mut context)?;
-            let exec_ctx = ToolExecutionContext::from_context_part(&mut context)?;
-
-            // Execute tool - returns ToolResponse<<T::Args as ToolArgs>::Output>
-            let result = self.tool.execute(args, exec_ctx).await;
-
-            match result {
-                Ok(response) => {
-                    // Convert ToolResponse to CallToolResult with structured validation
-                    let result = response.into_call_tool_result()
-                        .map_err(|e| rmcp::ErrorData::internal_error(
-                            format!("Failed to serialize tool output: {}", e),
-                            None
-                        ))?;
-
-                    Ok(result)
-                }
-                Err(e) => {
-                    Err(rmcp::ErrorData::from(e))
-                }
+            // Opt-in: fix safe mismatches before strict deserialization
+            let coercions = match context.arguments.as_mut() {
+                Some(arguments) if self.config.coerce_arguments => coerce_arguments(&T::input_schema(), arguments),
+                _ => Vec::new(),
+            };
+            if !coercions.is_empty() {
+                debug!("Coerced {} argument(s) of {}", coercions.len(), T::name());
+            }
+
+            // Extract execution context
+            let exec_ctx = ToolExecutionContext::from_context_part(&mut context)?
+                .with_progress_interval(self.config.progress_interval.unwrap_or(Duration::ZERO));
+            let timeout = call_timeout::<T>(&context.request_context.meta);
+            let progress = exec_ctx.clone();
+
+            let mode = self
+                .config
+                .response_mode
+                .resolve(exec_ctx.client_protocol_version().as_ref());
+
+            let timeout = match timeout {
+                Ok(timeout) => timeout,
+                Err(e) => return into_tool_result(Err(e), mode),
+            };
+
+            // Extract arguments; a mismatch is a tool error the model can correct
+            let args = match parse_arguments::<T::Args>(context.arguments.take()) {
+                Ok(args) => args,
+                Err(e) => return into_tool_result(Err(e), mode).map(|r| with_coercions(r, coercions)),
+            };
+
+            // Fast path: no middleware
+            if self.config.middleware.is_empty() {
+                let result = into_tool_result(self.execute(args, exec_ctx, mode, timeout).await.map(|(_, r)| r), mode);
+                return result
+                    .map(|r| with_progress_stats(r, progress.progress_stats()))
+                    .map(|r| with_coercions(r, coercions));
             }
+
+            let call = ToolCall {
+                tool_name: T::name(),
+                icon: <T::Args as ToolArgs>::icon(),
+                arguments: serde_json::to_value(&args).unwrap_or(Value::Null),
+                request_id: exec_ctx.request_id().clone(),
+                connection_id: exec_ctx.connection_id().map(str::to_string),
+                started: std::time::Instant::now(),
+                response_mode: mode,
+            };
+
+            let outcome = self.config
+                .middleware
+                .run(&call, self.execute(args, exec_ctx, mode, timeout))
+                .await;
+            into_tool_result(outcome, mode)
+                .map(|r| with_progress_stats(r, progress.progress_stats()))
+                .map(|r| with_coercions(r, coercions))
         })
     }
 }
+
+/// Deserialize call arguments, naming the offending argument on failure
+fn parse_arguments<A: DeserializeOwned>(arguments: Option<JsonObject>) -> Result<A, McpError> {
+    serde_path_to_error::deserialize(Value::Object(arguments.unwrap_or_default())).map_err(|e| {
+        let path = e.path().to_string();
+        let message = e.into_inner().to_string();
+        // Missing fields are reported on the enclosing object
+        let missing = message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+            .map(|field| match path.as_str() {
+                "." => field.to_string(),
+                parent => format!("{}.{}", parent, field),
+            });
+        match missing {
+            Some(argument) => McpError::invalid_argument(argument, "missing required argument"),
+            None if path == "." => McpError::invalid_arguments(format!("failed to deserialize parameters: {}", message)),
+            None => McpError::invalid_argument(path, message),
+        }
+    })
+}
+
+/// Timeout for one call: `timeoutMs` from the request `_meta`, else [`Tool::default_timeout`]
+fn call_timeout<T: Tool>(meta: &Meta) -> Result<Option<Duration>, McpError> {
+    match meta.get(TIMEOUT_META_KEY) {
+        None | Some(Value::Null) => Ok(T::default_timeout()),
+        Some(value) => value
+            .as_u64()
+            .filter(|ms| *ms > 0)
+            .map(|ms| Some(Duration::from_millis(ms)))
+            .ok_or_else(|| {
+                McpError::invalid_argument(
+                    format!("_meta.{}", TIMEOUT_META_KEY),
+                    format!("must be a positive number of milliseconds, found {}", value),
+                )
+            }),
+    }
+}
+
+/// Race `execution` against the call's cancellation token and `timeout`.
+///
+/// Dropping the future stops tools that never check `is_cancelled()`; the
+/// error carries whatever the tool streamed before it was stopped.
+async fn run_stoppable<O>(
+    execution: impl std::future::Future<Output = Result<O, McpError>>,
+    ctx: &ToolExecutionContext,
+    timeout: Option<Duration>,
+) -> Result<O, McpError> {
+    let deadline = async {
+        match timeout {
+            Some(timeout) => tokio::time::sleep(timeout).await,
+            None => std::future::pending().await,
+        }
+    };
+
+    let stopped = tokio::select! {
+        result = execution => return result,
+        () = ctx.cancellation_token().cancelled() => McpError::cancelled("the client cancelled the call"),
+        () = deadline => McpError::timed_out(timeout.unwrap_or_default()),
+    };
+    Err(stopped.with_partial_output(Some(ctx.streamed_output())))
+}
+
+/// Report merged or dropped progress updates in the result `_meta`
+fn with_progress_stats(mut result: CallToolResult, stats: ProgressStats) -> CallToolResult {
+    if stats.coalesced()
+        && let Ok(value) = serde_json::to_value(stats)
+    {
+        result
+            .meta
+            .get_or_insert_with(Meta::new)
+            .0
+            .insert(PROGRESS_STATS_META_KEY.to_string(), value);
+    }
+    result
+}
+
+/// Report the coercions applied to the call's arguments in the result `_meta`
+fn with_coercions(mut result: CallToolResult, coercions: Vec<Coercion>) -> CallToolResult {
+    if !coercions.is_empty()
+        && let Ok(value) = serde_json::to_value(coercions)
+    {
+        result
+            .meta
+            .get_or_insert_with(Meta::new)
+            .0
+            .insert(ARGUMENT_COERCIONS_META_KEY.to_string(), value);
+    }
+    result
+}
+
+/// Map a tool outcome to the MCP response.
+///
+/// Tool failures become `CallToolResult { is_error: Some(true) }` carrying a
+/// typed `ToolErrorData` payload; only protocol errors (see
+/// [`McpError::is_protocol_error`]) are returned as JSON-RPC errors.
+fn into_tool_result(
+    outcome: Result<CallToolResult, McpError>,
+    mode: ResponseMode,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    match outcome {
+        Ok(result) => Ok(result),
+        Err(e) if e.is_protocol_error() => Err(rmcp::ErrorData::from(e)),
+        Err(e) => Ok(e.into_call_tool_result_with_mode(mode)),
+    }
+}
@@ -0,0 +1,761 @@
+//! TypeScript and Python types generated from a [`SchemaBundle`]
+//!
+//! Dashboards and evaluation harnesses consume tool outputs from other
+//! languages. Rather than hand-writing `TerminalOutput` and friends there, they
+//! generate them from the same schemas the server advertises:
+//!
+//! - [`typescript`] emits a `.d.ts` module of interfaces and type aliases
+//! - [`python`] emits `TypedDict`s or pydantic models ([`PythonStyle`])
+//!
+//! Every tool's Args and Output schema becomes a named type, as does every
+//! definition and inline object they reference. Definitions shared by several
+//! tools are emitted once; a name that means different shapes in different
+//! tools is prefixed with the tool name (`GitShowFile` vs `FsReadFile...`).
+//!
+//! Both outputs end with a discriminated union mirroring
+//! [`AnyToolOutput`](crate::AnyToolOutput), tagged by tool name:
+//!
+//! ```text
+//! AnyToolOutput = { tool: "terminal", output: TerminalOutput } | { tool: "fs_search", ... } | ...
+//! ```
+//!
+//! The `kodegen-schema` binary writes them with `--typescript`, `--python` and
+//! `--pydantic`.
+
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Write as _;
+
+use super::schema_bundle::SchemaBundle;
+
+/// Names of the generated union and maps, never used for schema types
+const RESERVED_NAMES: &[&str] = &["AnyToolOutput", "ToolArgsMap", "ToolOutputMap", "ToolName"];
+
+/// Python keywords that cannot be used as attribute names
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+    "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "nonlocal",
+    "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+/// `BaseModel` attributes a pydantic field must not shadow
+const PYDANTIC_RESERVED: &[&str] = &[
+    "construct", "copy", "dict", "fields", "from_orm", "json", "parse_file", "parse_obj", "parse_raw", "schema",
+    "schema_json", "update_forward_refs", "validate",
+];
+
+/// Flavor of the generated Python module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PythonStyle {
+    /// `typing.TypedDict` classes over plain JSON dicts (no dependencies)
+    #[default]
+    TypedDict,
+
+    /// pydantic v2 `BaseModel`s that validate on construction
+    Pydantic,
+}
+
+/// TypeScript declarations for every tool in `bundle`
+#[must_use]
+pub fn typescript(bundle: &SchemaBundle) -> String {
+    let types = TypeSet::from_bundle(bundle);
+    let mut out = String::new();
+
+    let _ = writeln!(out, "// Generated by kodegen-schema {}. Do not edit.", types.version);
+    for decl in &types.decls {
+        out.push('\n');
+        write_ts_doc(&mut out, "", decl.doc.as_deref());
+        match &decl.shape {
+            Shape::Object(fields) => {
+                let _ = writeln!(out, "export interface {} {{", decl.name);
+                for field in fields {
+                    write_ts_doc(&mut out, "  ", field.doc.as_deref());
+                    let optional = if field.required { "" } else { "?" };
+                    let _ = writeln!(out, "  {}{}: {};", ts_property(&field.name), optional, ts_type(&field.ty));
+                }
+                out.push_str("}\n");
+            }
+            Shape::Alias(ty) => {
+                let _ = writeln!(out, "export type {} = {};", decl.name, ts_type(ty));
+            }
+        }
+    }
+
+    out.push_str("\n/** Arguments of each tool, by tool name */\nexport interface ToolArgsMap {\n");
+    for tool in &types.tools {
+        let _ = writeln!(out, "  {}: {};", ts_property(&tool.name), tool.args);
+    }
+    out.push_str("}\n\n/** Typed output of each tool, by tool name */\nexport interface ToolOutputMap {\n");
+    for tool in &types.tools {
+        let _ = writeln!(out, "  {}: {};", ts_property(&tool.name), tool.output);
+    }
+    out.push_str("}\n\nexport type ToolName = keyof ToolOutputMap;\n");
+
+    out.push_str("\n/** Output of any tool, discriminated by `tool` */\nexport type AnyToolOutput =");
+    if types.tools.is_empty() {
+        out.push_str(" never");
+    }
+    for tool in &types.tools {
+        let _ = write!(out, "\n  | {{ tool: {}; output: {} }}", json_literal(&tool.name), tool.output);
+    }
+    out.push_str(";\n");
+    out
+}
+
+/// Python module for every tool in `bundle`
+#[must_use]
+pub fn python(bundle: &SchemaBundle, style: PythonStyle) -> String {
+    let types = TypeSet::from_bundle(bundle);
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# Generated by kodegen-schema {}. Do not edit.", types.version);
+    out.push_str("from __future__ import annotations\n\n");
+    match style {
+        PythonStyle::TypedDict => {
+            out.push_str("from typing import Any, Literal, NotRequired, Optional, TypedDict, Union\n");
+        }
+        PythonStyle::Pydantic => {
+            out.push_str("from typing import Annotated, Any, Literal, Optional, Union\n\n");
+            out.push_str("from pydantic import BaseModel, ConfigDict, Field\n");
+        }
+    }
+
+    for decl in types.python_order() {
+        out.push_str("\n\n");
+        match &decl.shape {
+            Shape::Object(fields) => write_py_class(&mut out, decl, fields, style),
+            Shape::Alias(ty) => {
+                write_py_comment(&mut out, "", decl.doc.as_deref());
+                let _ = writeln!(out, "{} = {}", decl.name, py_type(ty));
+            }
+        }
+    }
+
+    for tool in &types.tools {
+        let base = match style {
+            PythonStyle::TypedDict => "TypedDict",
+            PythonStyle::Pydantic => "BaseModel",
+        };
+        let _ = write!(
+            out,
+            "\n\nclass {}({}):\n    tool: Literal[{}]\n    output: {}\n",
+            tool.entry,
+            base,
+            json_literal(&tool.name),
+            tool.output
+        );
+    }
+
+    let entries: Vec<&str> = types.tools.iter().map(|tool| tool.entry.as_str()).collect();
+    let union = match entries.as_slice() {
+        [] => "Any".to_string(),
+        [single] => (*single).to_string(),
+        _ => format!("Union[\n    {},\n]", entries.join(",\n    ")),
+    };
+    out.push_str("\n\n# Output of any tool, discriminated by `tool`\n");
+    match style {
+        PythonStyle::TypedDict => {
+            let _ = writeln!(out, "AnyToolOutput = {}", union);
+        }
+        PythonStyle::Pydantic => {
+            let _ = writeln!(out, "AnyToolOutput = Annotated[{}, Field(discriminator=\"tool\")]", union);
+        }
+    }
+
+    out.push_str("\nTOOL_ARGS: dict[str, Any] = {\n");
+    for tool in &types.tools {
+        let _ = writeln!(out, "    {}: {},", json_literal(&tool.name), tool.args);
+    }
+    out.push_str("}\n\nTOOL_OUTPUTS: dict[str, Any] = {\n");
+    for tool in &types.tools {
+        let _ = writeln!(out, "    {}: {},", json_literal(&tool.name), tool.output);
+    }
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// Language-neutral model of the schemas
+// ============================================================================
+
+/// Type expression
+#[derive(Debug, Clone, PartialEq)]
+enum Ty {
+    Named(String),
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Null,
+    Unknown,
+    Literal(Value),
+    Array(Box<Ty>),
+    Map(Box<Ty>),
+    Union(Vec<Ty>),
+}
+
+impl Ty {
+    /// Union of `members`, flattened and deduplicated
+    fn union(members: Vec<Ty>) -> Ty {
+        let mut flat: Vec<Ty> = Vec::new();
+        for member in members {
+            let nested = match member {
+                Ty::Union(nested) => nested,
+                other => vec![other],
+            };
+            for ty in nested {
+                if !flat.contains(&ty) {
+                    flat.push(ty);
+                }
+            }
+        }
+        match flat.len() {
+            0 => Ty::Unknown,
+            1 => flat.remove(0),
+            _ => Ty::Union(flat),
+        }
+    }
+
+    /// Names this type refers to
+    fn collect_names<'a>(&'a self, out: &mut Vec<&'a str>) {
+        match self {
+            Ty::Named(name) => out.push(name),
+            Ty::Array(inner) | Ty::Map(inner) => inner.collect_names(out),
+            Ty::Union(members) => members.iter().for_each(|member| member.collect_names(out)),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    ty: Ty,
+    required: bool,
+    doc: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Shape {
+    Object(Vec<Field>),
+    Alias(Ty),
+}
+
+/// One named type
+#[derive(Debug, Clone)]
+struct Decl {
+    name: String,
+    doc: Option<String>,
+    shape: Shape,
+}
+
+/// Type names of one tool
+#[derive(Debug, Clone)]
+struct ToolTypes {
+    name: String,
+    args: String,
+    output: String,
+    /// Name of the tool's `AnyToolOutput` member class (Python)
+    entry: String,
+}
+
+/// Every named type of a bundle
+struct TypeSet {
+    version: String,
+    decls: Vec<Decl>,
+    tools: Vec<ToolTypes>,
+    /// Schema each claimed name stands for (`None` for reserved names)
+    names: HashMap<String, Option<Value>>,
+}
+
+impl TypeSet {
+    fn from_bundle(bundle: &SchemaBundle) -> Self {
+        let mut types = Self {
+            version: bundle.index().schema_version.clone(),
+            decls: Vec::new(),
+            tools: Vec::new(),
+            names: RESERVED_NAMES.iter().map(|name| (name.to_string(), None)).collect(),
+        };
+
+        for (tool, input, output) in bundle.schemas() {
+            let prefix = pascal_case(&tool.name);
+            let args = types.root(input, &format!("{}Args", prefix), &prefix);
+            let output = types.root(output, &format!("{}Output", prefix), &prefix);
+            let entry = types.claim_reserved(&format!("{}ToolOutput", prefix));
+            types.tools.push(ToolTypes {
+                name: tool.name.clone(),
+                args,
+                output,
+                entry,
+            });
+        }
+        types
+    }
+
+    /// Declare a root schema and its `$defs`, returning the root's type name
+    fn root(&mut self, schema: &Value, fallback: &str, prefix: &str) -> String {
+        let mut body = schema.clone();
+        let defs = body.as_object_mut().and_then(|object| {
+            object.remove("$schema");
+            object.remove("$defs")
+        });
+
+        let mut local = HashMap::new();
+        let mut pending = Vec::new();
+        if let Some(Value::Object(defs)) = defs {
+            for (name, def) in defs {
+                let (claimed, new) = self.claim(&identifier(&name), prefix, &def);
+                local.insert(name, claimed.clone());
+                if new {
+                    pending.push((claimed, def));
+                }
+            }
+        }
+        for (name, def) in pending {
+            self.declare(name, &def, &local);
+        }
+
+        let title = body.get("title").and_then(Value::as_str).map(identifier);
+        let (name, new) = self.claim(title.as_deref().unwrap_or(fallback), prefix, &body);
+        if new {
+            self.declare(name.clone(), &body, &local);
+        }
+        name
+    }
+
+    /// Claim a name for `schema`: `base`, then `{prefix}{base}`, then numbered
+    ///
+    /// Returns the name and whether it is new (false when `schema` already has it).
+    fn claim(&mut self, base: &str, prefix: &str, schema: &Value) -> (String, bool) {
+        let prefixed = if base.starts_with(prefix) {
+            base.to_string()
+        } else {
+            format!("{}{}", prefix, base)
+        };
+        let candidates = [base.to_string(), prefixed.clone()].into_iter().chain((2..).map(|n| format!("{}{}", prefixed, n)));
+        for candidate in candidates {
+            match self.names.get(&candidate) {
+                Some(Some(existing)) if existing == schema => return (candidate, false),
+                Some(_) => {}
+                None => {
+                    self.names.insert(candidate.clone(), Some(schema.clone()));
+                    return (candidate, true);
+                }
+            }
+        }
+        unreachable!("candidate names are unbounded")
+    }
+
+    /// Claim a name that does not stand for a schema
+    fn claim_reserved(&mut self, base: &str) -> String {
+        let name = std::iter::once(base.to_string())
+            .chain((2..).map(|n| format!("{}{}", base, n)))
+            .find(|candidate| !self.names.contains_key(candidate))
+            .unwrap_or_else(|| base.to_string());
+        self.names.insert(name.clone(), None);
+        name
+    }
+
+    /// Add the declaration of `schema` under an already claimed `name`
+    fn declare(&mut self, name: String, schema: &Value, local: &HashMap<String, String>) {
+        let doc = description(schema);
+        let shape = match object_properties(schema) {
+            Some((properties, required)) => Shape::Object(
+                properties
+                    .iter()
+                    .map(|(field, property)| Field {
+                        name: field.clone(),
+                        ty: self.ty(property, &format!("{}{}", name, pascal_case(field)), local),
+                        required: required.contains(field.as_str()),
+                        doc: description(property),
+                    })
+                    .collect(),
+            ),
+            None => Shape::Alias(self.ty(schema, &name, local)),
+        };
+        self.decls.push(Decl { name, doc, shape });
+    }
+
+    /// Type expression of `schema`; inline objects are declared as `hint`
+    fn ty(&mut self, schema: &Value, hint: &str, local: &HashMap<String, String>) -> Ty {
+        let Some(object) = schema.as_object() else {
+            return Ty::Unknown;
+        };
+
+        let base = if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            let def = reference.rsplit('/').next().unwrap_or(reference);
+            Ty::Named(local.get(def).cloned().unwrap_or_else(|| identifier(def)))
+        } else if let Some(constant) = object.get("const") {
+            if constant.is_null() { Ty::Null } else { Ty::Literal(constant.clone()) }
+        } else if let Some(Value::Array(values)) = object.get("enum") {
+            Ty::union(
+                values
+                    .iter()
+                    .map(|value| if value.is_null() { Ty::Null } else { Ty::Literal(value.clone()) })
+                    .collect(),
+            )
+        } else if let Some(Value::Array(variants)) = object.get("oneOf").or_else(|| object.get("anyOf")) {
+            let members = variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| self.ty(variant, &variant_name(hint, variant, index), local))
+                .collect();
+            Ty::union(members)
+        } else if let Some(Value::Array(parts)) = object.get("allOf")
+            && let [single] = parts.as_slice()
+        {
+            self.ty(single, hint, local)
+        } else if object_properties(schema).is_some() {
+            let (name, new) = self.claim(hint, "", schema);
+            if new {
+                self.declare(name.clone(), schema, local);
+            }
+            Ty::Named(name)
+        } else {
+            match object.get("type") {
+                Some(Value::String(kind)) => self.primitive(kind, object, hint, local),
+                Some(Value::Array(kinds)) => {
+                    let members = kinds
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(|kind| self.primitive(kind, object, hint, local))
+                        .collect();
+                    Ty::union(members)
+                }
+                _ => Ty::Unknown,
+            }
+        };
+
+        if object.get("nullable").and_then(Value::as_bool) == Some(true) {
+            Ty::union(vec![base, Ty::Null])
+        } else {
+            base
+        }
+    }
+
+    fn primitive(&mut self, kind: &str, object: &Map<String, Value>, hint: &str, local: &HashMap<String, String>) -> Ty {
+        match kind {
+            "string" => Ty::String,
+            "integer" => Ty::Integer,
+            "number" => Ty::Number,
+            "boolean" => Ty::Boolean,
+            "null" => Ty::Null,
+            "array" => {
+                let items = object.get("items").map_or(Ty::Unknown, |items| self.ty(items, &format!("{}Item", hint), local));
+                Ty::Array(Box::new(items))
+            }
+            "object" => {
+                let values = match object.get("additionalProperties") {
+                    Some(values @ Value::Object(_)) => self.ty(values, &format!("{}Value", hint), local),
+                    _ => Ty::Unknown,
+                };
+                Ty::Map(Box::new(values))
+            }
+            _ => Ty::Unknown,
+        }
+    }
+
+    /// Declarations ordered so that every alias follows the types it names
+    ///
+    /// Python evaluates alias right-hand sides at import time; class
+    /// annotations are deferred by `from __future__ import annotations`.
+    fn python_order(&self) -> Vec<&Decl> {
+        let index: HashMap<&str, &Decl> = self.decls.iter().map(|decl| (decl.name.as_str(), decl)).collect();
+        let mut visited = HashSet::new();
+        let mut ordered = Vec::with_capacity(self.decls.len());
+
+        fn visit<'a>(decl: &'a Decl, index: &HashMap<&str, &'a Decl>, visited: &mut HashSet<&'a str>, ordered: &mut Vec<&'a Decl>) {
+            if !visited.insert(decl.name.as_str()) {
+                return;
+            }
+            let mut names = Vec::new();
+            match &decl.shape {
+                Shape::Object(fields) => fields.iter().for_each(|field| field.ty.collect_names(&mut names)),
+                Shape::Alias(ty) => ty.collect_names(&mut names),
+            }
+            for name in names {
+                if let Some(dependency) = index.get(name) {
+                    visit(dependency, index, visited, ordered);
+                }
+            }
+            ordered.push(decl);
+        }
+
+        for decl in &self.decls {
+            visit(decl, &index, &mut visited, &mut ordered);
+        }
+        ordered
+    }
+}
+
+/// Properties and required names of an object schema with declared properties
+fn object_properties(schema: &Value) -> Option<(&Map<String, Value>, BTreeSet<&str>)> {
+    let object = schema.as_object()?;
+    if object.contains_key("oneOf") || object.contains_key("anyOf") || object.contains_key("$ref") {
+        return None;
+    }
+    let properties = object.get("properties")?.as_object()?;
+    let required = object
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    Some((properties, required))
+}
+
+/// Name of union variant `index` of `hint`: its string tag if it has one
+fn variant_name(hint: &str, variant: &Value, index: usize) -> String {
+    let tag = object_properties(variant).and_then(|(properties, _)| {
+        properties
+            .values()
+            .find_map(|property| property.get("const").and_then(Value::as_str))
+            .map(pascal_case)
+    });
+    match tag {
+        Some(tag) if !tag.is_empty() => format!("{}{}", hint, tag),
+        _ => format!("{}Variant{}", hint, index + 1),
+    }
+}
+
+fn description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+/// `fs_search` → `FsSearch`
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `name` with every character that cannot appear in an identifier replaced by `_`
+fn identifier(name: &str) -> String {
+    let mut out: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if out.is_empty() || out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// JSON string literal, valid in both TypeScript and Python
+fn json_literal(text: &str) -> String {
+    Value::String(text.to_string()).to_string()
+}
+
+// ============================================================================
+// TypeScript
+// ============================================================================
+
+fn ts_type(ty: &Ty) -> String {
+    match ty {
+        Ty::Named(name) => name.clone(),
+        Ty::String => "string".to_string(),
+        Ty::Integer | Ty::Number => "number".to_string(),
+        Ty::Boolean => "boolean".to_string(),
+        Ty::Null => "null".to_string(),
+        Ty::Unknown => "unknown".to_string(),
+        Ty::Literal(value) => value.to_string(),
+        Ty::Array(inner) => match inner.as_ref() {
+            Ty::Union(_) => format!("Array<{}>", ts_type(inner)),
+            _ => format!("{}[]", ts_type(inner)),
+        },
+        Ty::Map(inner) => format!("Record<string, {}>", ts_type(inner)),
+        Ty::Union(members) => members.iter().map(ts_type).collect::<Vec<_>>().join(" | "),
+    }
+}
+
+fn ts_property(name: &str) -> String {
+    if is_identifier(name) { name.to_string() } else { json_literal(name) }
+}
+
+fn write_ts_doc(out: &mut String, indent: &str, doc: Option<&str>) {
+    let Some(doc) = doc else { return };
+    let doc = doc.replace("*/", "*\\/");
+    let lines: Vec<&str> = doc.lines().collect();
+    if let [line] = lines.as_slice() {
+        let _ = writeln!(out, "{}/** {} */", indent, line);
+        return;
+    }
+    let _ = writeln!(out, "{}/**", indent);
+    for line in lines {
+        if line.is_empty() {
+            let _ = writeln!(out, "{} *", indent);
+        } else {
+            let _ = writeln!(out, "{} * {}", indent, line);
+        }
+    }
+    let _ = writeln!(out, "{} */", indent);
+}
+
+// ============================================================================
+// Python
+// ============================================================================
+
+fn py_type(ty: &Ty) -> String {
+    match ty {
+        Ty::Named(name) => name.clone(),
+        Ty::String => "str".to_string(),
+        Ty::Integer => "int".to_string(),
+        Ty::Number => "float".to_string(),
+        Ty::Boolean => "bool".to_string(),
+        Ty::Null => "None".to_string(),
+        Ty::Unknown => "Any".to_string(),
+        Ty::Literal(value) => py_literal_type(std::slice::from_ref(value)),
+        Ty::Array(inner) => format!("list[{}]", py_type(inner)),
+        Ty::Map(inner) => format!("dict[str, {}]", py_type(inner)),
+        Ty::Union(members) => {
+            let nullable = members.contains(&Ty::Null);
+            let mut literals = Vec::new();
+            let mut others = Vec::new();
+            for member in members {
+                match member {
+                    Ty::Literal(value) => literals.push(value.clone()),
+                    Ty::Null => {}
+                    other => others.push(py_type(other)),
+                }
+            }
+            if !literals.is_empty() {
+                others.insert(0, py_literal_type(&literals));
+            }
+            let inner = match others.len() {
+                0 => "None".to_string(),
+                1 => others.remove(0),
+                _ => format!("Union[{}]", others.join(", ")),
+            };
+            if nullable && inner != "None" { format!("Optional[{}]", inner) } else { inner }
+        }
+    }
+}
+
+fn py_literal_type(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::Bool(true) => "True".to_string(),
+            Value::Bool(false) => "False".to_string(),
+            other => other.to_string(),
+        })
+        .collect();
+    format!("Literal[{}]", values.join(", "))
+}
+
+fn is_python_attribute(name: &str) -> bool {
+    is_identifier(name) && !PYTHON_KEYWORDS.contains(&name)
+}
+
+fn write_py_class(out: &mut String, decl: &Decl, fields: &[Field], style: PythonStyle) {
+    match style {
+        PythonStyle::TypedDict if !fields.iter().all(|field| is_python_attribute(&field.name)) => {
+            // Keywords such as `from` need the functional syntax
+            write_py_comment(out, "", decl.doc.as_deref());
+            let _ = writeln!(out, "{} = TypedDict(\n    {},\n    {{", decl.name, json_literal(&decl.name));
+            for field in fields {
+                let _ = writeln!(out, "        {}: {},", json_literal(&field.name), py_typed_dict_field(field));
+            }
+            out.push_str("    },\n)\n");
+        }
+        PythonStyle::TypedDict => {
+            let _ = writeln!(out, "class {}(TypedDict):", decl.name);
+            write_py_docstring(out, decl.doc.as_deref());
+            for field in fields {
+                write_py_comment(out, "    ", field.doc.as_deref());
+                let _ = writeln!(out, "    {}: {}", field.name, py_typed_dict_field(field));
+            }
+            if fields.is_empty() && decl.doc.is_none() {
+                out.push_str("    pass\n");
+            }
+        }
+        PythonStyle::Pydantic => {
+            let _ = writeln!(out, "class {}(BaseModel):", decl.name);
+            write_py_docstring(out, decl.doc.as_deref());
+            if !fields.iter().all(|field| is_pydantic_attribute(&field.name)) {
+                out.push_str("    model_config = ConfigDict(populate_by_name=True)\n\n");
+            }
+            for field in fields {
+                write_py_comment(out, "    ", field.doc.as_deref());
+                let mut ty = py_type(&field.ty);
+                if !field.required && !ty.starts_with("Optional[") && ty != "None" {
+                    ty = format!("Optional[{}]", ty);
+                }
+                let default = if field.required { None } else { Some("None") };
+                if is_pydantic_attribute(&field.name) {
+                    match default {
+                        Some(default) => {
+                            let _ = writeln!(out, "    {}: {} = {}", field.name, ty, default);
+                        }
+                        None => {
+                            let _ = writeln!(out, "    {}: {}", field.name, ty);
+                        }
+                    }
+                } else {
+                    let attribute = format!("{}_", identifier(&field.name));
+                    let default = default.map(|default| format!("default={}, ", default)).unwrap_or_default();
+                    let _ = writeln!(
+                        out,
+                        "    {}: {} = Field({}alias={})",
+                        attribute,
+                        ty,
+                        default,
+                        json_literal(&field.name)
+                    );
+                }
+            }
+            if fields.is_empty() && decl.doc.is_none() {
+                out.push_str("    pass\n");
+            }
+        }
+    }
+}
+
+fn is_pydantic_attribute(name: &str) -> bool {
+    is_python_attribute(name) && !PYDANTIC_RESERVED.contains(&name) && !name.starts_with("model_")
+}
+
+fn py_typed_dict_field(field: &Field) -> String {
+    let ty = py_type(&field.ty);
+    if field.required { ty } else { format!("NotRequired[{}]", ty) }
+}
+
+fn write_py_docstring(out: &mut String, doc: Option<&str>) {
+    let Some(doc) = doc else { return };
+    let doc = doc.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+    let mut lines = doc.lines();
+    let _ = write!(out, "    \"\"\"{}", lines.next().unwrap_or_default());
+    for line in lines {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            let _ = write!(out, "\n    {}", line);
+        }
+    }
+    out.push_str("\"\"\"\n\n");
+}
+
+fn write_py_comment(out: &mut String, indent: &str, doc: Option<&str>) {
+    let Some(doc) = doc else { return };
+    for line in doc.lines() {
+        if line.is_empty() {
+            let _ = writeln!(out, "{}#:", indent);
+        } else {
+            let _ = writeln!(out, "{}#: {}", indent, line);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Cross-field validation of tool arguments
+//!
+//! JSON Schema describes each field on its own; requirements such as "`command`
+//! is required for EXEC" only lived in doc comments and surfaced as failures
+//! deep inside the tool. [`ValidateArgs`] states them on the Args type:
+//!
+//! ```rust,ignore
+//! #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
+//! pub struct ClaudeAgentArgs {
+//!     pub action: ClaudeAgentAction,
+//!
+//!     #[required_if(action = "Spawn" | "Send")]
+//!     pub prompt: Option<String>,
+//!
+//!     #[range(min = 1)]
+//!     pub max_turns: Option<u32>,
+//! }
+//! ```
+//!
+//! The derive also registers an [`ArgsValidator`], which the default
+//! [`ToolArgs::validate`](crate::ToolArgs::validate) looks up by type, so
+//! deriving is all an Args type needs. The tool handler calls `validate`
+//! inside the middleware stack, before confirmation and execution, and
+//! returns failures as `InvalidArguments` tool errors naming the field.
+//!
+//! "Present" means set and non-empty: `Some` of a present value, a non-blank
+//! string, a non-empty `Vec`, a `true` flag ([`Presence`]).
+
+use serde::Serialize;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use super::error::McpError;
+
+/// Cross-field requirements of an Args type, usually derived with
+/// `#[derive(ValidateArgs)]` (see the [module docs](self))
+pub trait ValidateArgs {
+    /// Check the requirements, naming the offending field on failure
+    ///
+    /// # Errors
+    /// Returns `McpError::InvalidArgument` for the first unmet requirement.
+    fn validate_args(&self) -> Result<(), McpError>;
+}
+
+/// `validate_args` of one Args type, registered with inventory by
+/// `#[derive(ValidateArgs)]`
+pub struct ArgsValidator {
+    type_id: fn() -> TypeId,
+    validate: ErasedValidate,
+}
+
+/// `validate_args` taking the Args as `&dyn Any`
+type ErasedValidate = fn(&dyn Any) -> Result<(), McpError>;
+
+impl ArgsValidator {
+    /// Validator running `T::validate_args`
+    #[must_use]
+    pub const fn new<T: ValidateArgs + 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>,
+            validate: validate_erased::<T>,
+        }
+    }
+}
+
+inventory::collect!(ArgsValidator);
+
+fn validate_erased<T: ValidateArgs + 'static>(args: &dyn Any) -> Result<(), McpError> {
+    args.downcast_ref::<T>().map_or(Ok(()), ValidateArgs::validate_args)
+}
+
+/// Run the registered [`ArgsValidator`] of `T`, if `T` derives `ValidateArgs`
+///
+/// # Errors
+/// Returns `McpError::InvalidArgument` for the first unmet requirement.
+pub fn validate_registered<T: 'static>(args: &T) -> Result<(), McpError> {
+    static VALIDATORS: LazyLock<HashMap<TypeId, ErasedValidate>> = LazyLock::new(|| {
+        inventory::iter::<ArgsValidator>
+            .into_iter()
+            .map(|validator| ((validator.type_id)(), validator.validate))
+            .collect()
+    });
+
+    match VALIDATORS.get(&TypeId::of::<T>()) {
+        Some(validate) => validate(args),
+        None => Ok(()),
+    }
+}
+
+/// Whether a field counts as provided for `required_if` and `exclusive_with`
+pub trait Presence {
+    /// The field was provided
+    fn is_present(&self) -> bool;
+}
+
+impl<T: Presence> Presence for Option<T> {
+    fn is_present(&self) -> bool {
+        self.as_ref().is_some_and(Presence::is_present)
+    }
+}
+
+impl Presence for String {
+    fn is_present(&self) -> bool {
+        !self.trim().is_empty()
+    }
+}
+
+impl<T> Presence for Vec<T> {
+    fn is_present(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl Presence for bool {
+    fn is_present(&self) -> bool {
+        *self
+    }
+}
+
+/// Numeric value of a field checked with `range`; `None` skips the check
+pub trait Bounded {
+    /// The value, if set
+    fn bound_value(&self) -> Option<f64>;
+}
+
+impl<T: Bounded> Bounded for Option<T> {
+    fn bound_value(&self) -> Option<f64> {
+        self.as_ref().and_then(Bounded::bound_value)
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($ty:ty),*) => {
+        $(
+            impl Presence for $ty {
+                fn is_present(&self) -> bool {
+                    true
+                }
+            }
+
+            impl Bounded for $ty {
+                #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+                fn bound_value(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+/// A value as the client wrote it, e.g. `EXEC` for `TerminalAction::Exec`
+#[must_use]
+pub fn describe(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(text)) => text,
+        Ok(other) => other.to_string(),
+        Err(_) => "?".to_string(),
+    }
+}
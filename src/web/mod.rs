@@ -1,16 +1,25 @@
 //! Web tools
+//!
+//! `web_search` and `scrape_url` are served by the citescrape category; this
+//! module aliases the canonical [`crate::citescrape`] schemas so both paths
+//! resolve to the same types.
 
-pub mod scrape_url;
-pub mod web_search;
+pub use crate::citescrape::{WEB_SEARCH, SCRAPE_URL};
+pub use crate::citescrape::{scrape_url, web_search};
 
 // Re-export scrape_url tool types
 pub use scrape_url::{
+    ScrapeUrlArgs,
+    ScrapeUrlOutput,
     ScrapeUrlPromptArgs,
     ScrapeUrlPrompts,
 };
 
 // Re-export web_search tool types
 pub use web_search::{
+    WebSearchArgs,
+    WebSearchOutput,
+    WebSearchResultItem,
     WebSearchPromptArgs,
     WebSearchPrompts,
 };
@@ -1,8 +0,0 @@
-//! Web search tool prompt arguments and prompts
-
-pub mod prompt_args;
-pub mod prompts;
-
-// Re-export for convenient access
-pub use prompt_args::*;
-pub use prompts::*;
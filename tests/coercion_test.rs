@@ -0,0 +1,82 @@
+//! Tests for lenient argument coercion
+
+use kodegen_mcp_schema::tool::coercion::coerce_arguments;
+use kodegen_mcp_schema::tool::{CoercionKind, SchemaRegistry};
+use serde_json::{Map, Value, json};
+
+fn coerce(tool: &str, arguments: Value) -> (Value, Vec<(String, CoercionKind)>) {
+    let schemas = SchemaRegistry::get(tool).expect("registered tool");
+    let Value::Object(mut arguments) = arguments else {
+        panic!("arguments must be an object");
+    };
+    let coercions = coerce_arguments(&schemas.input, &mut arguments);
+    let applied = coercions.into_iter().map(|c| (c.path, c.kind)).collect();
+    (Value::Object(arguments), applied)
+}
+
+#[test]
+fn test_coerces_scalars_and_enum_case() {
+    let (arguments, applied) = coerce(
+        "terminal",
+        json!({
+            "action": "exec",
+            "command": "ls",
+            "await_completion_ms": "5000",
+            "terminal": " 0 ",
+            "clear": "TRUE",
+        }),
+    );
+
+    assert_eq!(
+        arguments,
+        json!({
+            "action": "EXEC",
+            "command": "ls",
+            "await_completion_ms": 5000,
+            "terminal": 0,
+            "clear": true,
+        })
+    );
+    assert_eq!(
+        applied,
+        vec![
+            ("action".to_string(), CoercionKind::EnumCase),
+            ("await_completion_ms".to_string(), CoercionKind::StringToNumber),
+            ("clear".to_string(), CoercionKind::StringToBoolean),
+            ("terminal".to_string(), CoercionKind::StringToNumber),
+        ]
+    );
+}
+
+#[test]
+fn test_wraps_single_value_in_array() {
+    let (arguments, applied) = coerce("fs_read_multiple_files", json!({ "paths": "src/lib.rs" }));
+    assert_eq!(arguments["paths"], json!(["src/lib.rs"]));
+    assert_eq!(applied, vec![("paths".to_string(), CoercionKind::SingleToArray)]);
+}
+
+#[test]
+fn test_leaves_valid_and_unfixable_values_alone() {
+    let valid = json!({ "action": "READ", "terminal": 1, "clear": false, "command": null });
+    assert_eq!(coerce("terminal", valid.clone()), (valid, Vec::new()));
+
+    // Not an exact number or boolean, not an action: strict parsing reports them
+    let unfixable = json!({ "action": "run", "await_completion_ms": "5s", "clear": "yes", "terminal": "-1x" });
+    assert_eq!(coerce("terminal", unfixable.clone()), (unfixable, Vec::new()));
+}
+
+#[test]
+fn test_ignores_unknown_properties() {
+    let schema: Map<String, Value> = serde_json::from_value(json!({
+        "type": "object",
+        "properties": { "count": { "type": "integer" } },
+    }))
+    .unwrap();
+    let mut arguments: Map<String, Value> = serde_json::from_value(json!({ "count": "3", "extra": "4" })).unwrap();
+
+    let coercions = coerce_arguments(&schema, &mut arguments);
+    assert_eq!(coercions.len(), 1);
+    assert_eq!(coercions[0].from, json!("3"));
+    assert_eq!(coercions[0].to, json!(3));
+    assert_eq!(Value::Object(arguments), json!({ "count": 3, "extra": "4" }));
+}
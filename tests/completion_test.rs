@@ -0,0 +1,129 @@
+//! Tests for argument completion of prompts and tools
+
+use kodegen_mcp_schema::git::{GitCheckoutArgs, GitCheckoutOutput, GitCheckoutPrompts};
+use kodegen_mcp_schema::tool::{
+    ArgumentCompleter, CompletionQuery, CompletionRouter, ToolRouterBuilder, complete_path,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use std::sync::Arc;
+
+struct CheckoutTool;
+
+impl Tool for CheckoutTool {
+    type Args = GitCheckoutArgs;
+    type Prompts = GitCheckoutPrompts;
+
+    fn name() -> &'static str {
+        "git_checkout"
+    }
+
+    fn description() -> &'static str {
+        "Switch branches"
+    }
+
+    async fn execute(
+        &self,
+        _args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<GitCheckoutOutput>, McpError> {
+        Err(McpError::Other(anyhow::anyhow!("not executed in these tests")))
+    }
+}
+
+impl ArgumentCompleter for CheckoutTool {
+    fn completable_arguments() -> &'static [&'static str] {
+        &["/path", "/target"]
+    }
+
+    async fn complete_argument(
+        &self,
+        query: CompletionQuery,
+        _ctx: ToolExecutionContext,
+    ) -> Result<Vec<String>, McpError> {
+        Ok(["main", "feature/login"]
+            .into_iter()
+            .filter(|branch| branch.starts_with(&query.prefix))
+            .map(String::from)
+            .collect())
+    }
+}
+
+/// Tool claiming to complete an argument its Args do not have
+struct BrokenTool;
+
+impl Tool for BrokenTool {
+    type Args = GitCheckoutArgs;
+    type Prompts = GitCheckoutPrompts;
+
+    fn name() -> &'static str {
+        "broken"
+    }
+
+    fn description() -> &'static str {
+        "Broken"
+    }
+
+    async fn execute(
+        &self,
+        _args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<GitCheckoutOutput>, McpError> {
+        Err(McpError::Other(anyhow::anyhow!("not executed in these tests")))
+    }
+}
+
+impl ArgumentCompleter for BrokenTool {
+    fn completable_arguments() -> &'static [&'static str] {
+        &["/branch"]
+    }
+
+    async fn complete_argument(
+        &self,
+        _query: CompletionQuery,
+        _ctx: ToolExecutionContext,
+    ) -> Result<Vec<String>, McpError> {
+        Ok(vec![])
+    }
+}
+
+#[test]
+fn test_router_builder_registers_prompt_completions() {
+    let (_router, completions) = ToolRouterBuilder::<()>::new()
+        .completing_tool(CheckoutTool)
+        .build_with_completions();
+
+    assert_eq!(
+        completions.complete_prompt("git_checkout_help", "scenario", "sw"),
+        vec!["switch_branch".to_string()]
+    );
+    assert_eq!(completions.complete_prompt("git_checkout_help", "scenario", "").len(), 2);
+    assert!(completions.complete_prompt("git_checkout_help", "unknown", "").is_empty());
+    assert!(completions.complete_prompt("other_help", "scenario", "").is_empty());
+}
+
+#[test]
+#[should_panic(expected = "not a property of its Args")]
+fn test_unknown_completable_argument_panics() {
+    let _ = CompletionRouter::new().tool(Arc::new(BrokenTool));
+}
+
+#[tokio::test]
+async fn test_complete_path() {
+    let dir = std::env::temp_dir().join(format!("kodegen_complete_path_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+    std::fs::write(dir.join("Cargo.toml"), "").unwrap();
+    std::fs::write(dir.join(".hidden"), "").unwrap();
+
+    assert_eq!(complete_path("", Some(&dir)).await, vec!["Cargo.toml", "src/"]);
+    assert_eq!(complete_path("s", Some(&dir)).await, vec!["src/"]);
+    assert_eq!(complete_path("src/", Some(&dir)).await, vec!["src/lib.rs"]);
+    assert_eq!(complete_path(".h", Some(&dir)).await, vec![".hidden"]);
+
+    let absolute = format!("{}/C", dir.display());
+    assert_eq!(complete_path(&absolute, None).await, vec![format!("{}/Cargo.toml", dir.display())]);
+
+    assert!(complete_path("missing/", Some(&dir)).await.is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,94 @@
+//! Tests for the destructive-call confirmation gate
+
+use kodegen_mcp_schema::database::ExecuteSQLArgs;
+use kodegen_mcp_schema::git::{GitResetArgs, ResetMode};
+use kodegen_mcp_schema::process::ProcessKillArgs;
+use kodegen_mcp_schema::tool::{ConfirmationFallback, ConfirmationPolicy, DestructiveAction};
+use kodegen_mcp_schema::{McpError, ToolArgs, ToolErrorKind};
+
+fn reset(mode: ResetMode) -> GitResetArgs {
+    GitResetArgs {
+        path: "/repo".to_string(),
+        target: "HEAD~3".to_string(),
+        mode,
+    }
+}
+
+fn sql(sql: &str) -> ExecuteSQLArgs {
+    ExecuteSQLArgs { sql: sql.to_string() }
+}
+
+#[test]
+fn test_only_hard_reset_needs_confirmation() {
+    assert!(reset(ResetMode::Soft).destructive_action().is_none());
+    assert!(reset(ResetMode::Mixed).destructive_action().is_none());
+
+    let action = reset(ResetMode::Hard).destructive_action().expect("hard reset is destructive");
+    assert_eq!(action.target, "/repo");
+    assert!(action.summary.contains("HEAD~3"));
+    assert!(!action.reversible);
+}
+
+#[test]
+fn test_only_sql_writes_need_confirmation() {
+    assert!(sql("SELECT * FROM users").destructive_action().is_none());
+    assert!(sql("  -- count\n select count(*) from users; EXPLAIN SELECT 1;").destructive_action().is_none());
+    assert!(sql("WITH recent AS (SELECT id FROM users) SELECT * FROM recent").destructive_action().is_none());
+
+    let action = sql("SELECT 1; DELETE FROM users WHERE id = 1; DROP TABLE logs")
+        .destructive_action()
+        .expect("DELETE and DROP are writes");
+    assert_eq!(action.effects, vec!["DELETE FROM users WHERE id = 1", "DROP TABLE logs"]);
+
+    assert!(sql("WITH old AS (SELECT id FROM users) DELETE FROM users WHERE id IN (SELECT id FROM old)")
+        .destructive_action()
+        .is_some());
+}
+
+#[test]
+fn test_explain_analyze_is_classified_by_wrapped_statement() {
+    // Plain EXPLAIN only plans, even for writes
+    assert!(sql("EXPLAIN DELETE FROM users").destructive_action().is_none());
+    assert!(sql("EXPLAIN ANALYZE SELECT * FROM users").destructive_action().is_none());
+    assert!(sql("EXPLAIN (ANALYZE, FORMAT JSON) SELECT 1").destructive_action().is_none());
+
+    // EXPLAIN ANALYZE runs the statement
+    assert!(sql("EXPLAIN ANALYZE DELETE FROM users").destructive_action().is_some());
+    assert!(sql("explain analyze verbose update users set name = 'x'").destructive_action().is_some());
+    assert!(sql("EXPLAIN (ANALYZE, BUFFERS) INSERT INTO logs VALUES (1)").destructive_action().is_some());
+}
+
+#[test]
+fn test_process_kill_always_needs_confirmation() {
+    let action = ProcessKillArgs { pid: 42 }.destructive_action().unwrap();
+    assert_eq!(action.target, "PID 42");
+}
+
+#[test]
+fn test_message_summarizes_action() {
+    let action = DestructiveAction::new("Delete branch feature", "acme/app").effect("Open pull requests are closed");
+    let message = action.message("github_delete_branch");
+
+    assert!(message.starts_with("github_delete_branch wants to: Delete branch feature"));
+    assert!(message.contains("Target: acme/app"));
+    assert!(message.contains("- Open pull requests are closed"));
+    assert!(message.ends_with("This cannot be undone."));
+
+    assert!(!action.reversible(true).message("x").contains("cannot be undone"));
+}
+
+#[test]
+fn test_policy_defaults_to_deny() {
+    let policy = ConfirmationPolicy::default();
+    assert_eq!(policy.fallback, ConfirmationFallback::Deny);
+    assert_eq!(policy.fallback(ConfirmationFallback::Allow).fallback, ConfirmationFallback::Allow);
+}
+
+#[test]
+fn test_declined_error_kind() {
+    let error = McpError::ConfirmationDeclined("user declined git_reset".to_string());
+    let data = error.error_data();
+    assert_eq!(data.kind, ToolErrorKind::ConfirmationDeclined);
+    assert!(!data.retryable);
+    assert!(data.hint.is_some());
+}
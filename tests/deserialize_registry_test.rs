@@ -0,0 +1,147 @@
+//! Coverage and round-trip tests for the tool output deserialization registry
+
+use kodegen_mcp_schema::deserialize::{ToolOutputDeserializer, is_registered, registered_tool_names};
+use kodegen_mcp_schema::git::GitCherryPickOutput;
+use kodegen_mcp_schema::{ToolMetadata, deserialize_tool_output, deserialize_typed_only};
+use serde_json::{Map, Value, json};
+
+/// Build a minimal instance of a JSON schema: required properties only,
+/// first enum value / `oneOf` branch, zero-ish scalars.
+fn sample(schema: &Value, root: &Value, depth: usize) -> Value {
+    assert!(depth < 32, "schema recursion too deep");
+
+    let Some(obj) = schema.as_object() else {
+        return Value::Null;
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix("#")
+            .and_then(|pointer| root.pointer(pointer))
+            .unwrap_or_else(|| panic!("unresolvable $ref {}", reference));
+        let mut value = sample(target, root, depth + 1);
+
+        // Internally tagged variants put the tag property next to the $ref
+        let mut rest = obj.clone();
+        rest.remove("$ref");
+        if let (Value::Object(value), Value::Object(tag)) = (&mut value, sample(&Value::Object(rest), root, depth + 1)) {
+            value.extend(tag);
+        }
+        return value;
+    }
+    if let Some(value) = obj.get("const") {
+        return value.clone();
+    }
+    if let Some(first) = obj.get("enum").and_then(Value::as_array).and_then(|v| v.first()) {
+        return first.clone();
+    }
+    for key in ["oneOf", "anyOf", "allOf"] {
+        if let Some(first) = obj.get(key).and_then(Value::as_array).and_then(|v| v.first()) {
+            return sample(first, root, depth + 1);
+        }
+    }
+
+    let ty = match obj.get("type") {
+        Some(Value::String(ty)) => ty.as_str(),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        _ if obj.contains_key("properties") => "object",
+        _ => return Value::Null,
+    };
+
+    match ty {
+        "object" => {
+            let properties = obj.get("properties").and_then(Value::as_object);
+            let mut out = Map::new();
+            for name in obj.get("required").and_then(Value::as_array).into_iter().flatten() {
+                let name = name.as_str().expect("required entries are strings");
+                let property = properties.and_then(|p| p.get(name)).unwrap_or(&Value::Null);
+                out.insert(name.to_string(), sample(property, root, depth + 1));
+            }
+            Value::Object(out)
+        }
+        "array" => match obj.get("prefixItems").and_then(Value::as_array) {
+            Some(items) => items.iter().map(|item| sample(item, root, depth + 1)).collect(),
+            None => json!([]),
+        },
+        "string" => match obj.get("format").and_then(Value::as_str) {
+            Some("date-time") => json!("2025-01-01T00:00:00Z"),
+            Some("date") => json!("2025-01-01"),
+            Some("uuid") => json!("00000000-0000-0000-0000-000000000000"),
+            _ => json!(""),
+        },
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        _ => Value::Null,
+    }
+}
+
+#[test]
+fn test_every_tool_is_registered() {
+    let missing: Vec<&str> = inventory::iter::<ToolMetadata>
+        .into_iter()
+        .map(|meta| meta.name)
+        .filter(|name| !is_registered(name))
+        .collect();
+
+    assert!(missing.is_empty(), "tools missing from the deserialize registry: {:?}", missing);
+}
+
+#[test]
+fn test_every_tool_output_round_trips() {
+    for meta in inventory::iter::<ToolMetadata> {
+        let schema = (meta.output_schema)();
+        let original = sample(&schema, &schema, 0);
+        let json = original.to_string();
+
+        let typed = deserialize_typed_only(meta.name, &json)
+            .unwrap_or_else(|e| panic!("{}: {} (sample: {})", meta.name, e, json));
+        assert_eq!(typed.tool_name(), meta.name);
+
+        let reserialized = serde_json::to_string(&typed).expect("output serializes");
+        let again = deserialize_typed_only(meta.name, &reserialized)
+            .unwrap_or_else(|e| panic!("{}: re-deserialize failed: {}", meta.name, e));
+        assert_eq!(
+            serde_json::to_value(&again).expect("output serializes"),
+            serde_json::to_value(&typed).expect("output serializes"),
+            "{} did not round-trip",
+            meta.name
+        );
+
+        // Full response layout routes to the same tool
+        let response = json!([
+            {"type": "text", "text": "display"},
+            {"type": "text", "text": reserialized},
+        ])
+        .to_string();
+        let result = deserialize_tool_output(meta.name, &response)
+            .unwrap_or_else(|e| panic!("{}: {}", meta.name, e));
+        assert_eq!(result.typed.tool_name(), meta.name);
+    }
+}
+
+#[test]
+fn test_registry_names_are_unique_and_known() {
+    // Building the registry panics on a name submitted twice
+    let submitted = inventory::iter::<ToolOutputDeserializer>.into_iter().count();
+    assert_eq!(registered_tool_names().count(), submitted);
+
+    // Tools without ToolArgs are registered by hand
+    assert!(is_registered("browser_eval"));
+    assert!(!is_registered("not_a_tool"));
+}
+
+#[test]
+fn test_output_downcasts_to_tool_output_type() {
+    let schema = schemars::schema_for!(GitCherryPickOutput);
+    let sample = sample(schema.as_value(), schema.as_value(), 0).to_string();
+
+    let typed = deserialize_typed_only("git_cherry_pick", &sample).expect("sample deserializes");
+    assert!(typed.is::<GitCherryPickOutput>());
+    assert!(typed.downcast_ref::<String>().is_none());
+    assert_eq!(format!("{:?}", typed).split('(').next(), Some("git_cherry_pick"));
+}
@@ -1,7 +1,11 @@
 //! Integration tests for MCP tool output deserialization
 
 use kodegen_config::TERMINAL;
-use kodegen_mcp_schema::{deserialize_tool_output, AnyToolOutput};
+use kodegen_mcp_schema::terminal::TerminalOutput;
+use kodegen_mcp_schema::deserialize::Branding;
+use kodegen_mcp_schema::tool::ContentPart;
+use kodegen_mcp_schema::{deserialize_tool_output, ResponseMode, ToolResponse, ToolStatus};
+use rmcp::model::{ProtocolVersion, RawResource, ResourceContents};
 use std::fs;
 
 #[test]
@@ -20,16 +24,12 @@ fn test_deserialize_terminal_output() {
     assert!(result.display.contains("Duration: 1523ms"));
 
     // Verify typed output was deserialized correctly
-    match result.typed {
-        AnyToolOutput::Terminal(output) => {
-            assert_eq!(output.terminal, Some(0));
-            assert_eq!(output.exit_code, Some(0));
-            assert_eq!(output.cwd, "/home/user/project");
-            assert_eq!(output.duration_ms, 1523);
-            assert!(output.completed);
-        }
-        _ => panic!("Expected Terminal variant, got {:?}", result.typed),
-    }
+    let output = result.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+    assert_eq!(output.terminal, Some(0));
+    assert_eq!(output.exit_code, Some(0));
+    assert_eq!(output.cwd, "/home/user/project");
+    assert_eq!(output.duration_ms, 1523);
+    assert!(output.completed);
 }
 
 #[test]
@@ -50,16 +50,12 @@ fn test_deserialize_terminal_output_with_error() {
         .expect("Failed to deserialize terminal output");
 
     // Verify error case
-    match result.typed {
-        AnyToolOutput::Terminal(output) => {
-            assert_eq!(output.terminal, Some(1));
-            assert_eq!(output.exit_code, Some(127)); // Command not found
-            assert_eq!(output.cwd, "/tmp");
-            assert_eq!(output.duration_ms, 250);
-            assert!(output.completed);
-        }
-        _ => panic!("Expected Terminal variant"),
-    }
+    let output = result.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+    assert_eq!(output.terminal, Some(1));
+    assert_eq!(output.exit_code, Some(127)); // Command not found
+    assert_eq!(output.cwd, "/tmp");
+    assert_eq!(output.duration_ms, 250);
+    assert!(output.completed);
 }
 
 #[test]
@@ -79,16 +75,12 @@ fn test_deserialize_terminal_output_still_running() {
     let result = deserialize_tool_output(TERMINAL, mcp_response_json)
         .expect("Failed to deserialize terminal output");
 
-    match result.typed {
-        AnyToolOutput::Terminal(output) => {
-            assert_eq!(output.terminal, Some(0));
-            assert_eq!(output.exit_code, None); // Still running
-            assert_eq!(output.cwd, "/home/user");
-            assert_eq!(output.duration_ms, 5000);
-            assert!(!output.completed);
-        }
-        _ => panic!("Expected Terminal variant"),
-    }
+    let output = result.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+    assert_eq!(output.terminal, Some(0));
+    assert_eq!(output.exit_code, None); // Still running
+    assert_eq!(output.cwd, "/home/user");
+    assert_eq!(output.duration_ms, 5000);
+    assert!(!output.completed);
 }
 
 #[test]
@@ -132,3 +124,153 @@ fn test_deserialize_missing_content() {
     let err = result.unwrap_err();
     assert!(format!("{}", err).contains("Missing content[1]"));
 }
+
+#[test]
+fn test_deserialize_structured_content_layout() {
+    // Full CallToolResult with structuredContent and no JSON text block
+    let mcp_response_json = r#"{
+        "content": [
+            {"type": "text", "text": "📟 Terminal 2: Command completed successfully"}
+        ],
+        "structuredContent": {"terminal":2,"exit_code":0,"cwd":"/srv","duration_ms":42,"completed":true}
+    }"#;
+
+    let result = deserialize_tool_output(TERMINAL, mcp_response_json)
+        .expect("Failed to deserialize structured terminal output");
+
+    assert!(result.display.contains("Terminal 2"));
+    let output = result.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+    assert_eq!(output.terminal, Some(2));
+    assert_eq!(output.cwd, "/srv");
+    assert_eq!(output.duration_ms, 42);
+}
+
+#[test]
+fn test_response_modes_round_trip() {
+    let metadata = TerminalOutput {
+        terminal: Some(3),
+        exit_code: Some(0),
+        cwd: "/work".to_string(),
+        duration_ms: 7,
+        completed: true,
+        terminals: Vec::new(),
+    };
+
+    for mode in [ResponseMode::Text, ResponseMode::Structured, ResponseMode::Both] {
+        let result = ToolResponse::new("done", metadata.clone())
+            .into_call_tool_result_with_mode(mode)
+            .expect("metadata serializes");
+        assert_eq!(result.structured_content.is_some(), mode != ResponseMode::Text);
+
+        let json = serde_json::to_string(&result).expect("result serializes");
+        let parsed = deserialize_tool_output(TERMINAL, &json).expect("round trip succeeds");
+        assert_eq!(parsed.display, "done");
+        let output = parsed.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+        assert_eq!(output.terminal, Some(3));
+    }
+}
+
+#[test]
+fn test_auto_response_mode_follows_protocol_version() {
+    assert_eq!(
+        ResponseMode::Auto.resolve(Some(&ProtocolVersion::V_2025_06_18)),
+        ResponseMode::Both
+    );
+    assert_eq!(
+        ResponseMode::Auto.resolve(Some(&ProtocolVersion::V_2025_03_26)),
+        ResponseMode::Text
+    );
+    assert_eq!(ResponseMode::Auto.resolve(None), ResponseMode::Text);
+    assert_eq!(
+        ResponseMode::Structured.resolve(None),
+        ResponseMode::Structured
+    );
+}
+
+#[test]
+fn test_deserialize_branded_layout() {
+    let metadata = TerminalOutput {
+        terminal: Some(4),
+        exit_code: Some(1),
+        cwd: "/repo".to_string(),
+        duration_ms: 2500,
+        completed: true,
+        terminals: Vec::new(),
+    };
+
+    let result = ToolResponse::new("make: *** [all] Error 1", metadata)
+        .into_call_tool_result_with_branding(TERMINAL, '⌨', 2500, ToolStatus::Error)
+        .expect("metadata serializes");
+    let json = serde_json::to_string(&result.content).expect("content serializes");
+
+    let parsed = deserialize_tool_output(TERMINAL, &json).expect("branded layout deserializes");
+
+    assert_eq!(parsed.display, "make: *** [all] Error 1");
+    assert_eq!(
+        parsed.branding,
+        Some(Branding {
+            tool_name: TERMINAL.to_string(),
+            icon: '⌨',
+            duration_s: 3,
+            status: ToolStatus::Error,
+        })
+    );
+    let output = parsed.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+    assert_eq!(output.exit_code, Some(1));
+}
+
+#[test]
+fn test_unbranded_layout_has_no_branding() {
+    let mcp_response_json = fs::read_to_string("tests/fixtures/terminal_output.json")
+        .expect("Failed to read terminal_output.json fixture");
+
+    let result = deserialize_tool_output(TERMINAL, &mcp_response_json)
+        .expect("Failed to deserialize terminal output");
+
+    assert!(result.branding.is_none());
+}
+
+#[test]
+fn test_content_parts_follow_metadata_and_round_trip() {
+    let metadata = TerminalOutput {
+        terminal: Some(5),
+        exit_code: Some(0),
+        cwd: "/work".to_string(),
+        duration_ms: 12,
+        completed: true,
+        terminals: Vec::new(),
+    };
+    let parts = vec![
+        ContentPart::image("iVBORw0KGgo=", "image/png"),
+        ContentPart::audio("UklGRg==", "audio/wav"),
+        ContentPart::resource(ResourceContents::text("# Report", "kodegen://fetch/report.md")),
+        ContentPart::link(RawResource::new("kodegen://screenshot/page.png", "page.png")),
+    ];
+
+    for mode in [ResponseMode::Text, ResponseMode::Structured, ResponseMode::Both] {
+        let response = parts
+            .iter()
+            .cloned()
+            .fold(ToolResponse::new("done", metadata.clone()), ToolResponse::with_part);
+        assert_eq!(response.parts(), parts.as_slice());
+        let result = response.into_call_tool_result_with_mode(mode).expect("metadata serializes");
+        let json = serde_json::to_string(&result).expect("result serializes");
+
+        let parsed = deserialize_tool_output(TERMINAL, &json).expect("round trip succeeds");
+        assert_eq!(parsed.display, "done");
+        assert_eq!(parsed.parts, parts);
+        let output = parsed.typed.downcast_ref::<TerminalOutput>().expect("Expected terminal output");
+        assert_eq!(output.terminal, Some(5));
+    }
+
+    // Branded layout keeps parts after the metadata too
+    let result = ToolResponse::new("done", metadata)
+        .with_image("iVBORw0KGgo=", "image/png")
+        .into_call_tool_result_with_branding(TERMINAL, '⌨', 12, ToolStatus::Success)
+        .expect("metadata serializes");
+    assert_eq!(result.content.len(), 4);
+    let json = serde_json::to_string(&result.content).expect("content serializes");
+    let parsed = deserialize_tool_output(TERMINAL, &json).expect("branded layout deserializes");
+    assert!(parsed.branding.is_some());
+    assert_eq!(parsed.parts, vec![ContentPart::image("iVBORw0KGgo=", "image/png")]);
+}
@@ -0,0 +1,108 @@
+//! Integration tests for machine-readable tool error results
+
+use kodegen_mcp_schema::tool::TOOL_ERROR_META_KEY;
+use kodegen_mcp_schema::{McpError, ResponseMode, ToolErrorData, ToolErrorKind};
+use rmcp::model::RawContent;
+use std::time::Duration;
+
+fn error_payload(error: McpError) -> ToolErrorData {
+    let result = error.into_call_tool_result();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(result.content.len(), 2);
+    match &*result.content[1] {
+        RawContent::Text(t) => serde_json::from_str(&t.text).expect("content[1] is ToolErrorData"),
+        _ => panic!("content[1] is not text"),
+    }
+}
+
+#[test]
+fn test_rate_limited_is_retryable_with_delay() {
+    let data = error_payload(McpError::rate_limited(Duration::from_secs(30)));
+
+    assert_eq!(data.kind, ToolErrorKind::RateLimited);
+    assert!(data.retryable);
+    assert_eq!(data.retry_after_ms, Some(30_000));
+    assert!(data.hint.is_some());
+
+    // Sub-second delays are not truncated to zero
+    let data = error_payload(McpError::rate_limited(Duration::from_millis(500)));
+    assert_eq!(data.retry_after_ms, Some(500));
+    assert_eq!(data.message, "Rate limit exceeded, retry after 500ms");
+
+    let data = error_payload(McpError::RateLimitExceeded { retry_after: None });
+    assert_eq!(data.kind, ToolErrorKind::RateLimited);
+    assert_eq!(data.retry_after_ms, None);
+    assert_eq!(data.message, "Rate limit exceeded");
+}
+
+#[test]
+fn test_invalid_argument_names_offending_field() {
+    let data = error_payload(McpError::invalid_argument("path", "does not exist"));
+
+    assert_eq!(data.kind, ToolErrorKind::InvalidArguments);
+    assert!(!data.retryable);
+    assert_eq!(data.argument.as_deref(), Some("path"));
+    assert_eq!(data.message, "Invalid argument 'path': does not exist");
+}
+
+#[test]
+fn test_permission_denied_has_no_unauthorized_prefix() {
+    let data = error_payload(McpError::PermissionDenied("outside workspace".to_string()));
+
+    assert_eq!(data.kind, ToolErrorKind::PermissionDenied);
+    assert_eq!(data.message, "Permission denied: outside workspace");
+}
+
+#[test]
+fn test_structured_error_result() {
+    let result = McpError::Network("connection reset".to_string())
+        .into_call_tool_result_with_mode(ResponseMode::Structured);
+
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(result.content.len(), 1);
+    // structuredContent is reserved for output matching the tool's outputSchema
+    assert!(result.structured_content.is_none());
+    let payload = &result.meta.expect("_meta present").0[TOOL_ERROR_META_KEY];
+    assert_eq!(payload["kind"], "network");
+    assert_eq!(payload["retryable"], true);
+}
+
+#[test]
+fn test_both_error_result_keeps_text_payload() {
+    let result = McpError::Network("connection reset".to_string())
+        .into_call_tool_result_with_mode(ResponseMode::Both);
+
+    assert_eq!(result.content.len(), 2);
+    assert!(result.structured_content.is_none());
+    let data: ToolErrorData = match &*result.content[1] {
+        RawContent::Text(t) => serde_json::from_str(&t.text).unwrap(),
+        _ => panic!("content[1] is not text"),
+    };
+    assert_eq!(data.kind, ToolErrorKind::Network);
+    assert_eq!(result.meta.unwrap().0[TOOL_ERROR_META_KEY]["kind"], "network");
+}
+
+#[test]
+fn test_only_tool_not_found_is_protocol_error() {
+    assert!(McpError::ToolNotFound("missing".to_string()).is_protocol_error());
+    assert!(!McpError::invalid_arguments("bad").is_protocol_error());
+    assert!(!McpError::RateLimitExceeded { retry_after: None }.is_protocol_error());
+}
+
+#[test]
+fn test_stopped_calls_carry_partial_output() {
+    let data = error_payload(McpError::timed_out(Duration::from_secs(2)).with_partial_output(Some("line 1\n".to_string())));
+    assert_eq!(data.kind, ToolErrorKind::TimedOut);
+    assert!(data.retryable);
+    assert_eq!(data.timeout_ms, Some(2000));
+    assert_eq!(data.partial_output.as_deref(), Some("line 1\n"));
+
+    let data = error_payload(McpError::cancelled("client went away").with_partial_output(Some(String::new())));
+    assert_eq!(data.kind, ToolErrorKind::Cancelled);
+    assert!(!data.retryable);
+    assert_eq!(data.partial_output, None);
+
+    // Only cancellations and timeouts carry partial output
+    let data = error_payload(McpError::Network("reset".to_string()).with_partial_output(Some("x".to_string())));
+    assert_eq!(data.partial_output, None);
+}
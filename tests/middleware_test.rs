@@ -0,0 +1,190 @@
+//! Integration tests for the tool middleware pipeline
+
+use async_trait::async_trait;
+use kodegen_mcp_schema::tool::{
+    BrandingMiddleware, ErrorResultMiddleware, HistoryMiddleware, MiddlewareStack, ResponseMode, ToolCall,
+    ToolCallRecord, ToolHistorySink, ToolMiddleware,
+};
+use kodegen_mcp_schema::McpError;
+use parking_lot::Mutex;
+use rmcp::model::{CallToolResult, Content, NumberOrString, RawContent};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Records hook invocations into a shared log
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+    reject: bool,
+}
+
+#[async_trait]
+impl ToolMiddleware for Recorder {
+    async fn before(&self, _call: &ToolCall) -> Result<(), McpError> {
+        self.log.lock().push(format!("{}:before", self.name));
+        if self.reject {
+            return Err(McpError::PermissionDenied(self.name.to_string()));
+        }
+        Ok(())
+    }
+
+    async fn after(
+        &self,
+        _call: &ToolCall,
+        _metadata: &Value,
+        _result: &mut CallToolResult,
+    ) -> Result<(), McpError> {
+        self.log.lock().push(format!("{}:after", self.name));
+        Ok(())
+    }
+
+    async fn on_error(&self, _call: &ToolCall, error: McpError) -> Result<CallToolResult, McpError> {
+        self.log.lock().push(format!("{}:on_error", self.name));
+        Err(error)
+    }
+}
+
+fn recorder(name: &'static str, log: &Arc<Mutex<Vec<String>>>, reject: bool) -> Arc<dyn ToolMiddleware> {
+    Arc::new(Recorder {
+        name,
+        log: log.clone(),
+        reject,
+    })
+}
+
+fn test_call() -> ToolCall {
+    ToolCall {
+        tool_name: "test_tool",
+        icon: 'T',
+        arguments: json!({}),
+        request_id: NumberOrString::Number(1),
+        connection_id: None,
+        started: Instant::now(),
+        response_mode: ResponseMode::Text,
+    }
+}
+
+fn ok_result() -> Result<(Value, CallToolResult), McpError> {
+    Ok((
+        json!({"ok": true}),
+        CallToolResult::success(vec![Content::text("display"), Content::text("{}")]),
+    ))
+}
+
+fn text_at(result: &CallToolResult, index: usize) -> String {
+    match &*result.content[index] {
+        RawContent::Text(t) => t.text.clone(),
+        _ => panic!("content[{}] is not text", index),
+    }
+}
+
+#[tokio::test]
+async fn test_hooks_run_in_onion_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let stack = MiddlewareStack::new(vec![
+        recorder("outer", &log, false),
+        recorder("inner", &log, false),
+    ]);
+
+    let result = stack.run(&test_call(), async { ok_result() }).await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        *log.lock(),
+        vec!["outer:before", "inner:before", "inner:after", "outer:after"]
+    );
+}
+
+#[tokio::test]
+async fn test_rejecting_before_skips_execution_and_inner_layers() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let stack = MiddlewareStack::new(vec![
+        recorder("outer", &log, false),
+        recorder("gate", &log, true),
+        recorder("inner", &log, false),
+    ]);
+
+    let executed = Arc::new(Mutex::new(false));
+    let flag = executed.clone();
+    let result = stack
+        .run(&test_call(), async move {
+            *flag.lock() = true;
+            ok_result()
+        })
+        .await;
+
+    assert!(matches!(result, Err(McpError::PermissionDenied(_))));
+    assert!(!*executed.lock());
+    assert_eq!(*log.lock(), vec!["outer:before", "gate:before", "outer:on_error"]);
+}
+
+#[tokio::test]
+async fn test_error_result_middleware_recovers_and_outer_layers_see_result() {
+    let stack = MiddlewareStack::new(vec![
+        Arc::new(BrandingMiddleware),
+        Arc::new(ErrorResultMiddleware),
+    ]);
+
+    let result = stack
+        .run(&test_call(), async {
+            Err(McpError::invalid_arguments("path is required"))
+        })
+        .await
+        .expect("error should be converted into a result");
+
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(result.content.len(), 3);
+    assert!(text_at(&result, 0).contains("test_tool"));
+    assert_eq!(text_at(&result, 1), "Invalid arguments: path is required");
+    assert!(text_at(&result, 2).contains("\"kind\": \"invalid_arguments\""));
+}
+
+/// Collects records in memory
+#[derive(Default)]
+struct MemorySink(Mutex<Vec<ToolCallRecord>>);
+
+impl ToolHistorySink for MemorySink {
+    fn record(&self, _connection_id: Option<&str>, record: ToolCallRecord) {
+        self.0.lock().push(record);
+    }
+}
+
+#[tokio::test]
+async fn test_history_records_recovered_errors_as_failures() {
+    let sink = Arc::new(MemorySink::default());
+    let stack = MiddlewareStack::new(vec![
+        Arc::new(HistoryMiddleware::new(sink.clone())),
+        Arc::new(ErrorResultMiddleware),
+    ]);
+
+    stack.run(&test_call(), async { ok_result() }).await.unwrap();
+    let result = stack
+        .run(&test_call(), async { Err(McpError::invalid_arguments("path is required")) })
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    let successes: Vec<bool> = sink.0.lock().iter().map(|record| record.success).collect();
+    assert_eq!(successes, vec![true, false]);
+}
+
+#[tokio::test]
+async fn test_error_result_middleware_uses_route_response_mode() {
+    let stack = MiddlewareStack::new(vec![Arc::new(ErrorResultMiddleware)]);
+    let call = ToolCall {
+        response_mode: ResponseMode::Structured,
+        ..test_call()
+    };
+
+    let result = stack
+        .run(&call, async { Err(McpError::invalid_arguments("path is required")) })
+        .await
+        .unwrap();
+    let expected = McpError::invalid_arguments("path is required").into_call_tool_result_with_mode(ResponseMode::Structured);
+    assert_eq!(serde_json::to_value(&result).unwrap(), serde_json::to_value(&expected).unwrap());
+    assert_ne!(
+        serde_json::to_value(&result).unwrap(),
+        serde_json::to_value(McpError::invalid_arguments("path is required").into_call_tool_result()).unwrap()
+    );
+}
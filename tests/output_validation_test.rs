@@ -0,0 +1,40 @@
+//! Integration tests for runtime output schema validation
+
+#![cfg(feature = "validate-output")]
+
+use kodegen_config::TERMINAL;
+use kodegen_mcp_schema::SchemaRegistry;
+use kodegen_mcp_schema::tool::output_validation::validate_output;
+use serde_json::json;
+
+#[test]
+fn test_valid_terminal_output_passes() {
+    let schema = &SchemaRegistry::get(TERMINAL).expect("terminal tool is registered").output;
+
+    let metadata = json!({
+        "terminal": 0,
+        "exit_code": 0,
+        "cwd": "/home/user/project",
+        "duration_ms": 1523,
+        "completed": true
+    });
+
+    assert!(validate_output(schema, &metadata).is_ok());
+}
+
+#[test]
+fn test_invalid_terminal_output_reports_violations() {
+    let schema = &SchemaRegistry::get(TERMINAL).expect("terminal tool is registered").output;
+
+    // Missing required `cwd`, wrong type for `duration_ms`
+    let metadata = json!({
+        "terminal": 0,
+        "duration_ms": "slow",
+        "completed": true
+    });
+
+    let violations = validate_output(schema, &metadata).expect_err("output should be rejected");
+    assert_eq!(violations.len(), 2);
+    assert!(violations.iter().any(|v| v.contains("cwd")));
+    assert!(violations.iter().any(|v| v.starts_with("/duration_ms")));
+}
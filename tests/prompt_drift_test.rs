@@ -0,0 +1,125 @@
+//! Tests for the prompt-to-schema drift checker
+
+use std::collections::BTreeSet;
+
+use kodegen_mcp_schema::ToolMetadata;
+use kodegen_mcp_schema::tool::check_all_prompts;
+use kodegen_mcp_schema::tool::prompt_drift::{extract_examples, validate_example};
+use serde_json::{Value, json};
+
+/// Tools whose Args are still taught incorrectly by some prompt.
+///
+/// Remove a tool from this list once its examples are fixed; the conformance
+/// test fails if a listed tool no longer drifts or an unlisted one starts to.
+const KNOWN_DRIFT: &[&str] = &[
+    "browser_click",
+    "config_set",
+    "db_execute_sql",
+    "db_list_tables",
+    "db_stored_procedures",
+    "db_table_schema",
+    "git_branch_create",
+    "git_branch_delete",
+    "git_branch_list",
+    "git_checkout",
+    "git_commit",
+    "git_fetch",
+    "git_log",
+    "git_merge",
+    "git_pull",
+    "git_push",
+    "git_tag",
+    "github_code_scanning_alerts",
+    "github_create_branch",
+    "github_create_issue",
+    "github_create_pull_request_review",
+    "github_create_repository",
+    "github_fork_repository",
+    "github_get_commit",
+    "github_get_issue_comments",
+    "github_get_pull_request_files",
+    "github_get_pull_request_reviews",
+    "github_get_pull_request_status",
+    "github_list_commits",
+    "github_list_pull_requests",
+    "github_merge_pull_request",
+    "github_request_copilot_review",
+    "github_secret_scanning_alerts",
+    "github_update_issue",
+    "github_update_pull_request",
+];
+
+fn args_schema(name: &str) -> Value {
+    let metadata = inventory::iter::<ToolMetadata>
+        .into_iter()
+        .find(|meta| meta.name == name)
+        .unwrap_or_else(|| panic!("{} is not registered", name));
+    (metadata.args_schema)()
+}
+
+#[test]
+fn test_extract_examples() {
+    let text = "Run:\n  git_diff({\"path\": \"/repo\", \"from\": \"{HEAD}\"})\n\
+                then my_git_diff({\"path\": 1}) and git_diff({...}) and git_diff without args";
+
+    let examples = extract_examples(text, &["git_diff"]);
+    assert_eq!(examples.len(), 2);
+
+    assert_eq!(examples[0].tool, "git_diff");
+    assert_eq!(examples[0].arguments, Ok(json!({"path": "/repo", "from": "{HEAD}"})));
+
+    // Placeholder objects are extracted but do not parse
+    assert_eq!(examples[1].source, "{...}");
+    assert!(examples[1].arguments.is_err());
+}
+
+#[test]
+fn test_unknown_fields_are_reported() {
+    let schema = args_schema("git_diff");
+    let example = json!({"path": ".", "staged": true, "stat_only": true, "files": ["a.rs"]});
+
+    let violations = validate_example(&schema, &example).expect_err("git_diff has no staged/stat_only/files");
+    assert!(violations.contains(&"$.staged: unknown field".to_string()), "{:?}", violations);
+    assert!(violations.contains(&"$.stat_only: unknown field".to_string()), "{:?}", violations);
+    assert!(violations.contains(&"$.files: unknown field".to_string()), "{:?}", violations);
+}
+
+#[test]
+fn test_partial_and_valid_examples_pass() {
+    let schema = args_schema("git_diff");
+    assert!(validate_example(&schema, &json!({"path": ".", "from": "HEAD~1"})).is_ok());
+
+    // Missing required properties are allowed in teaching examples
+    assert!(validate_example(&schema, &json!({"from": "HEAD~1"})).is_ok());
+}
+
+#[test]
+fn test_invalid_values_are_reported() {
+    let schema = args_schema("git_tag_list");
+
+    let violations = validate_example(&schema, &json!({"path": ".", "limit": "ten"})).unwrap_err();
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].starts_with("$.limit: expected"), "{:?}", violations);
+
+    let violations = validate_example(&schema, &json!({"path": ".", "sort": "newest"})).unwrap_err();
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].starts_with("$.sort:"), "{:?}", violations);
+
+    assert!(validate_example(&schema, &json!({"path": ".", "sort": "-version"})).is_ok());
+}
+
+#[test]
+fn test_prompt_examples_match_schemas() {
+    let drift = check_all_prompts();
+    let drifting: BTreeSet<&str> = drift.iter().map(|d| d.tool.as_str()).collect();
+
+    let unexpected: Vec<String> = drift
+        .iter()
+        .filter(|d| !KNOWN_DRIFT.contains(&d.tool.as_str()))
+        .map(ToString::to_string)
+        .collect();
+    assert!(unexpected.is_empty(), "prompt examples drifted from Args schemas:\n{}", unexpected.join("\n"));
+
+    let fixed: Vec<&str> = KNOWN_DRIFT.iter().copied().filter(|tool| !drifting.contains(tool)).collect();
+    assert!(fixed.is_empty(), "no longer drifting, remove from KNOWN_DRIFT: {:?}", fixed);
+}
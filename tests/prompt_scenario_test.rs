@@ -0,0 +1,194 @@
+//! Tests for typed prompt scenarios and prompt argument completion
+
+use kodegen_mcp_schema::git::diff::{DiffPrompts, GitDiffPromptArgs, GitDiffScenario};
+use kodegen_mcp_schema::git::tag_list::GitTagListPromptArgs;
+use kodegen_mcp_schema::tool::{PromptProvider, PromptScenario};
+use kodegen_mcp_schema::*;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+#[test]
+fn test_scenario_names_round_trip() {
+    assert!(!GitDiffScenario::ALL.is_empty());
+    for &scenario in GitDiffScenario::ALL {
+        assert_eq!(GitDiffScenario::from_name(scenario.name()), Some(scenario));
+        assert_eq!(scenario.to_string(), scenario.name());
+        assert_eq!(serde_json::to_value(scenario).unwrap(), json!(scenario.name()));
+    }
+    assert_eq!(GitDiffScenario::from_name("no_such_scenario"), None);
+}
+
+#[test]
+fn test_prompt_argument_lists_every_scenario() {
+    let argument = GitDiffScenario::prompt_argument();
+    assert_eq!(argument.name, "scenario");
+    assert_eq!(argument.required, Some(false));
+
+    let description = argument.description.unwrap();
+    for &scenario in GitDiffScenario::ALL {
+        assert!(description.contains(scenario.name()), "{} missing from {:?}", scenario, description);
+    }
+    assert_eq!(DiffPrompts::prompt_arguments(), vec![GitDiffScenario::prompt_argument()]);
+}
+
+#[test]
+fn test_unknown_scenario_is_rejected() {
+    let args: GitDiffPromptArgs = serde_json::from_value(json!({})).unwrap();
+    assert!(args.scenario.is_none());
+
+    let args: GitDiffPromptArgs = serde_json::from_value(json!({"scenario": "commits"})).unwrap();
+    assert_eq!(args.scenario, Some(GitDiffScenario::Commits));
+
+    assert!(serde_json::from_value::<GitDiffPromptArgs>(json!({"scenario": "comits"})).is_err());
+    assert!(serde_json::from_value::<GitTagListPromptArgs>(json!({"scenario": "typo"})).is_err());
+}
+
+#[test]
+fn test_complete_prompt_argument() {
+    let all: Vec<String> = GitDiffScenario::ALL.iter().map(|s| s.name().to_string()).collect();
+    assert_eq!(DiffPrompts::complete_prompt_argument("scenario", ""), all);
+    assert_eq!(DiffPrompts::complete_prompt_argument("scenario", "CO"), vec!["commits".to_string()]);
+    assert!(DiffPrompts::complete_prompt_argument("scenario", "zzz").is_empty());
+
+    // Unknown arguments have nothing to complete
+    assert!(DiffPrompts::complete_prompt_argument("unknown", "").is_empty());
+}
+
+/// Deserialize `{field: name}` as `T`, describing the failure
+fn parse_scenario<T: DeserializeOwned>(field: &str, name: &str) -> Result<(), String> {
+    serde_json::from_value::<T>(json!({ field: name }))
+        .map(drop)
+        .map_err(|e| format!("{} {:?}: {}", std::any::type_name::<T>(), name, e))
+}
+
+/// Check every name in `[..]` parses as the scenario argument (`field:` if not `scenario`) of the type
+macro_rules! assert_scenarios_parse {
+    ($($ty:ty => $($field:ident:)? [$($name:literal),+ $(,)?]),+ $(,)?) => {{
+        let mut failures: Vec<String> = Vec::new();
+        $(
+            let field = [$(stringify!($field),)? "scenario"][0];
+            $(failures.extend(parse_scenario::<$ty>(field, $name).err());)+
+        )+
+        assert!(failures.is_empty(), "scenarios no longer accepted:\n{}", failures.join("\n"));
+    }};
+}
+
+/// Every scenario name the prompt argument descriptions advertised before the
+/// scenario enums existed still parses, including the ones served by the
+/// default prompt
+#[test]
+fn test_advertised_scenarios_still_parse() {
+    assert_scenarios_parse! {
+        browser::agent::BrowserAgentPromptArgs => ["basic", "autonomous", "monitoring", "management"],
+        browser::click::BrowserClickPromptArgs => ["selectors", "waiting"],
+        browser::extract_text::BrowserExtractTextPromptArgs => ["page_content", "specific_elements"],
+        browser::navigate::BrowserNavigatePromptArgs => ["basic"],
+        browser::research::BrowserResearchPromptArgs => ["basic", "deep_research", "technical_docs", "comparison", "monitoring"],
+        browser::scroll::BrowserScrollPromptArgs => ["basic"],
+        browser::type_text::BrowserTypeTextPromptArgs => ["basic"],
+        citescrape::scrape_url::ScrapeUrlPromptArgs => ["crawling", "search", "background"],
+        citescrape::web_search::WebSearchPromptArgs => ["basic", "research"],
+        claude_agent::agent::ClaudeAgentPromptArgs => ["basic", "specialized", "parallel", "research", "monitoring"],
+        config::config_set::SetConfigValuePromptArgs => ["basic", "value_types"],
+        database::execute_sql::DbExecuteSqlPromptArgs => ["safety", "patterns"],
+        database::list_tables::ListTablesPromptArgs => ["basic", "filtering"],
+        database::stored_procedures::GetStoredProceduresPromptArgs => ["basic", "signatures"],
+        database::table_indexes::GetTableIndexesPromptArgs => ["usage"],
+        database::table_schema::GetTableSchemaPromptArgs => ["basic"],
+        filesystem::create_directory::FsCreateDirectoryPromptArgs => ["basic", "nested"],
+        filesystem::delete_directory::FsDeleteDirectoryPromptArgs => ["basic", "safety"],
+        filesystem::edit_block::FsEditBlockPromptArgs => ["basic", "precision", "workflows"],
+        filesystem::list_directory::FsListDirectoryPromptArgs => ["basic", "hidden"],
+        filesystem::move_file::FsMoveFilePromptArgs => ["rename", "relocate"],
+        filesystem::read_file::FsReadFilePromptArgs => ["basic", "large_files"],
+        filesystem::search::FsSearchPromptArgs => ["basic", "patterns", "pattern_modes", "options", "background", "workflows"],
+        filesystem::write_file::FsWriteFilePromptArgs => ["basic", "append"],
+        git::add::GitAddPromptArgs => ["specific_files", "patterns"],
+        git::branch_create::GitBranchCreatePromptArgs => ["feature", "from_commit", "tracking"],
+        git::branch_delete::GitBranchDeletePromptArgs => ["local", "remote"],
+        git::branch_list::GitBranchListPromptArgs => ["basic", "remote"],
+        git::branch_rename::GitBranchRenamePromptArgs => ["basic", "current"],
+        git::checkout::GitCheckoutPromptArgs => ["switch_branch", "detached"],
+        git::cherry_pick::GitCherryPickPromptArgs => ["single", "multiple", "conflicts", "options"],
+        git::clone::GitClonePromptArgs => ["basic", "shallow", "branch"],
+        git::commit::GitCommitPromptArgs => ["basic", "amend"],
+        git::config_get::GitConfigGetPromptArgs => ["user", "repo", "scopes", "list"],
+        git::config_set::GitConfigSetPromptArgs => ["identity", "behavior", "aliases", "repo_specific"],
+        git::diff::GitDiffPromptArgs => ["working", "commits"],
+        git::fetch::GitFetchPromptArgs => ["basic", "prune"],
+        git::history::GitHistoryPromptArgs => ["basic", "filtering"],
+        git::init::GitInitPromptArgs => ["basic", "bare"],
+        git::log::GitLogPromptArgs => ["filtering", "code_archaeology"],
+        git::merge::GitMergePromptArgs => ["strategies", "conflicts", "basic"],
+        git::pull::GitPullPromptArgs => ["basic", "rebase", "conflicts"],
+        git::push::GitPushPromptArgs => ["basic", "upstream", "force"],
+        git::rebase::GitRebasePromptArgs => ["basic", "onto", "conflicts", "workflows"],
+        git::remote_add::GitRemoteAddPromptArgs => ["basic"],
+        git::remote_list::GitRemoteListPromptArgs => ["basic"],
+        git::remote_remove::GitRemoteRemovePromptArgs => ["basic", "cleanup"],
+        git::reset::GitResetPromptArgs => ["soft", "mixed"],
+        git::revert::GitRevertPromptArgs => ["single", "multiple", "merge", "conflicts"],
+        git::show::GitShowPromptArgs => ["commits", "tags", "files", "formatting"],
+        git::stash::GitStashSavePromptArgs => ["basic", "operations"],
+        git::stash_apply::GitStashApplyPromptArgs => ["basic", "specific", "conflicts", "workflows"],
+        git::stash_list::GitStashListPromptArgs => ["basic", "details", "management"],
+        git::stash_pop::GitStashPopPromptArgs => ["basic", "specific", "conflicts", "workflows"],
+        git::status::GitStatusPromptArgs => ["basic", "interpreting"],
+        git::tag::GitTagPromptArgs => ["annotated"],
+        git::tag_create::GitTagCreatePromptArgs => ["annotated", "lightweight", "versioning", "workflows"],
+        git::tag_list::GitTagListPromptArgs => ["basic", "filtering", "sorting", "workflows"],
+        git::worktree_add::GitWorktreeAddPromptArgs => ["basic", "branch"],
+        git::worktree_lock::GitWorktreeLockPromptArgs => ["basic", "prevent"],
+        git::worktree_remove::GitWorktreeRemovePromptArgs => ["basic", "force"],
+        git::worktree_unlock::GitWorktreeUnlockPromptArgs => ["basic", "cleanup"],
+        github::accept_repo_invitation::GithubAcceptRepoInvitationPromptArgs => ["basic", "workflows"],
+        github::add_issue_comment::AddIssueCommentPromptArgs => ["issues", "prs"],
+        github::code_scanning_alerts::CodeScanningAlertsPromptArgs => ["basic", "filtering"],
+        github::create_branch::CreateBranchPromptArgs => ["basic", "from_ref"],
+        github::create_issue::CreateIssuePromptArgs => ["bug_report", "metadata", "templates"],
+        github::create_pull_request::CreatePullRequestPromptArgs => ["basic", "options", "workflows"],
+        github::create_pull_request_review::CreatePullRequestReviewPromptArgs => ["basic", "inline_comments", "workflows"],
+        github::create_release::GithubCreateReleasePromptArgs => ["basic", "notes", "options", "workflows"],
+        github::create_repository::CreateRepositoryPromptArgs => ["basic", "options", "organization"],
+        github::delete_branch::DeleteBranchPromptArgs => ["basic", "safety"],
+        github::delete_file::GithubDeleteFilePromptArgs => ["basic", "safety", "workflows"],
+        github::dependabot_alerts::GithubDependabotAlertsPromptArgs => ["basic", "filtering", "remediation", "workflows"],
+        github::fork_repository::GitHubForkRepositoryPromptArgs => ["basic", "organization"],
+        github::get_file_contents::GetFileContentsPromptArgs => ["files", "branches"],
+        github::get_issue::GetIssuePromptArgs => ["basic", "metadata"],
+        github::get_issue_comments::GetIssueCommentsPromptArgs => ["basic", "pagination"],
+        github::get_pr::GithubGetPrPromptArgs => ["basic", "status", "changes", "workflows"],
+        github::get_pull_request_files::GetPullRequestFilesPromptArgs => ["basic", "analysis"],
+        github::get_pull_request_reviews::GetPullRequestReviewsPromptArgs => ["basic", "review_analysis"],
+        github::get_pull_request_status::GetPullRequestStatusPromptArgs => ["basic", "merge_decision"],
+        github::list_branches::ListBranchesPromptArgs => ["basic", "pagination"],
+        github::list_commits::ListCommitsPromptArgs => ["basic", "filtering"],
+        github::list_issues::GithubListIssuesPromptArgs => ["basic", "filtering"],
+        github::list_pull_requests::ListPullRequestsPromptArgs => ["basic", "filtering"],
+        github::list_releases::ListReleasesPromptArgs => ["basic", "details", "workflows"],
+        github::list_repos::GithubListReposPromptArgs => ["user", "organization", "sorting", "workflows"],
+        github::merge_pull_request::MergePullRequestPromptArgs => ["basic", "strategies", "workflows"],
+        github::pending_invitations::GithubPendingInvitationsPromptArgs => ["basic", "workflows", "filtering", "integration"],
+        github::push_file::GithubPushFilePromptArgs => ["basics", "create", "branches", "workflows"],
+        github::search_code::SearchCodePromptArgs => ["basic", "syntax", "workflows"],
+        github::search_issues::SearchIssuesPromptArgs => ["basic", "syntax", "patterns"],
+        github::search_repositories::SearchRepositoriesPromptArgs => ["basic", "syntax", "workflows"],
+        github::secret_scanning_alerts::SecretScanningAlertsPromptArgs => ["basic"],
+        github::update_file::GithubUpdateFilePromptArgs => ["basic", "workflow", "branches", "advanced", "comprehensive"],
+        github::update_issue::UpdateIssuePromptArgs => ["state", "metadata"],
+        github::update_pull_request::UpdatePullRequestPromptArgs => ["content", "workflows"],
+        introspection::get_events::IntrospectionGetEventsPromptArgs => ["basic", "filtering", "analysis"],
+        introspection::inspect_tool_calls::InspectToolCallsPromptArgs => scenario_type: ["filtering", "onboarding"],
+        introspection::list_tools::IntrospectionListToolsPromptArgs => ["basic", "filtering", "discovery"],
+        memory::memorize::MemorizePromptArgs => ["basic", "organization"],
+        memory::recall::MemoryRecallPromptArgs => ["basic", "semantic"],
+        process::process_list::ProcessListPromptArgs => ["basic", "filtering"],
+        prompt::prompt_add::AddPromptPromptArgs => ["basic", "templating"],
+        prompt::prompt_delete::DeletePromptPromptArgs => ["basic", "safety"],
+        prompt::prompt_edit::EditPromptPromptArgs => ["basic", "refinement", "versioning", "workflows"],
+        prompt::prompt_get::GetPromptPromptArgs => ["basic", "variables"],
+        reasoner::ReasonerPromptArgs => ["basic", "beam_search", "mcts", "branching", "strategies"],
+        sequential_thinking::SequentialThinkingPromptArgs => ["basic", "revision", "branching", "sessions"],
+        terminal::TerminalPromptArgs => ["basic", "parallel", "background", "monitoring"],
+    }
+}
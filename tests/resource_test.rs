@@ -0,0 +1,95 @@
+//! Tests for resource URIs, providers and resource links
+
+use kodegen_mcp_schema::browser::{BrowserScreenshotOutput, ScreenshotResources, ScreenshotUri};
+use kodegen_mcp_schema::citescrape::{CitescrapeResources, CitescrapeUri, FetchOutput};
+use kodegen_mcp_schema::tool::{
+    McpError, ResourceProvider, ResourceRouter, ResourceSource, ResourceUri, ToolExecutionContext, match_template,
+};
+use kodegen_mcp_schema::ToolResponse;
+use rmcp::model::{RawContent, ResourceContents};
+
+#[test]
+fn test_match_template() {
+    let vars = match_template("kodegen://crawl/{crawl_id}/page/{n}", "kodegen://crawl/7/page/3").unwrap();
+    assert_eq!(vars["crawl_id"], "7");
+    assert_eq!(vars["n"], "3");
+
+    assert!(match_template("kodegen://crawl/{crawl_id}", "kodegen://crawl/7/page/3").is_none());
+    assert!(match_template("kodegen://crawl/{crawl_id}", "kodegen://crawl/").is_none());
+    assert!(match_template("kodegen://crawl/{crawl_id}", "kodegen://fetch/7").is_none());
+}
+
+#[test]
+fn test_citescrape_uris_round_trip() {
+    let uris = [
+        CitescrapeUri::Crawl { crawl_id: 7 },
+        CitescrapeUri::Page { crawl_id: 7, page: 3 },
+        CitescrapeUri::Fetched { file: "example_com.md".to_string() },
+    ];
+    for uri in uris {
+        assert_eq!(CitescrapeUri::parse(&uri.to_uri()), Some(uri));
+    }
+
+    assert_eq!(CitescrapeUri::parse("kodegen://crawl/x"), None);
+    assert_eq!(CitescrapeUri::parse("kodegen://crawl/7/page/0"), None);
+    assert_eq!(CitescrapeUri::parse("kodegen://screenshot/a.png"), None);
+    assert_eq!(CitescrapeResources::templates().len(), 3);
+}
+
+#[test]
+fn test_outputs_link_to_resources() {
+    let fetch = FetchOutput {
+        path: "/tmp/kodegen/fetch/example_com.md".to_string(),
+        search_helper: String::new(),
+        url: "https://example.com".to_string(),
+        title: None,
+        content_length: 10,
+    };
+    let link = fetch.resource_uri().unwrap().link();
+    assert_eq!(link.uri, "kodegen://fetch/example_com.md");
+    assert_eq!(link.mime_type.as_deref(), Some("text/markdown"));
+
+    let screenshot = BrowserScreenshotOutput {
+        success: true,
+        path: Some("/tmp/shots/page.png".to_string()),
+        width: 800,
+        height: 600,
+        format: "png".to_string(),
+        base64: None,
+    };
+    let uri = screenshot.resource_uri().unwrap();
+    assert_eq!(uri, ScreenshotUri { file: "page.png".to_string() });
+    assert_eq!(uri.mime_type(), Some("image/png"));
+
+    // Links follow display and metadata in the result content
+    let result = ToolResponse::new("saved", screenshot)
+        .with_link(uri.link())
+        .into_call_tool_result()
+        .unwrap();
+    assert_eq!(result.content.len(), 3);
+    match &*result.content[2] {
+        RawContent::ResourceLink(link) => assert_eq!(link.uri, "kodegen://screenshot/page.png"),
+        _ => panic!("content[2] is not a resource link"),
+    }
+}
+
+struct Screenshots;
+
+impl ResourceSource for Screenshots {
+    type Provider = ScreenshotResources;
+
+    async fn read(&self, uri: ScreenshotUri, _ctx: ToolExecutionContext) -> Result<Vec<ResourceContents>, McpError> {
+        Err(McpError::resource_not_found(uri.file))
+    }
+}
+
+#[test]
+fn test_router_advertises_templates() {
+    let router = ResourceRouter::new().source(Screenshots);
+
+    let templates = router.list_resource_templates().resource_templates;
+    assert_eq!(templates.len(), 1);
+    assert_eq!(templates[0].uri_template, "kodegen://screenshot/{file}");
+    assert_eq!(router.capabilities().subscribe, Some(true));
+    assert!(!router.is_subscribed("kodegen://screenshot/page.png"));
+}
@@ -0,0 +1,134 @@
+//! Tests for the static schema bundle
+
+use kodegen_mcp_schema::process::{ProcessKillArgs, ProcessKillOutput, ProcessKillPrompts};
+use kodegen_mcp_schema::tool::{BUNDLE_FORMAT_VERSION, SchemaBundle, SchemaRegistry};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use serde_json::Value;
+
+/// Every `$ref` in `value`
+fn refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) => out.push(reference),
+                    _ => refs(child, out),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| refs(item, out)),
+        _ => {}
+    }
+}
+
+fn assert_refs_resolve(document: &Value) {
+    let mut found = Vec::new();
+    refs(document, &mut found);
+    for reference in found {
+        let pointer = reference.strip_prefix('#').expect("local reference");
+        assert!(document.pointer(pointer).is_some(), "dangling $ref {}", reference);
+    }
+}
+
+#[test]
+fn test_bundle_covers_every_registered_tool() {
+    let bundle = SchemaBundle::from_inventory();
+    let index = bundle.index();
+
+    assert_eq!(index.format_version, BUNDLE_FORMAT_VERSION);
+    assert_eq!(index.tools.len(), SchemaRegistry::len());
+    assert!(index.tools.windows(2).all(|pair| pair[0].name < pair[1].name));
+
+    let kill = index.tools.iter().find(|tool| tool.name == "process_kill").unwrap();
+    assert_eq!(kill.category, "process");
+    assert_eq!(kill.schema, "tools/process_kill.schema.json");
+    let annotations = kill.annotations.as_ref().unwrap();
+    assert_eq!(annotations.read_only_hint, Some(false));
+    assert_eq!(annotations.destructive_hint, Some(true));
+    assert_eq!(annotations.idempotent_hint, Some(true));
+    assert_eq!(annotations.open_world_hint, Some(false));
+
+    let status = index.tools.iter().find(|tool| tool.name == "git_status").unwrap();
+    assert_eq!(status.annotations.as_ref().unwrap().read_only_hint, Some(true));
+    let push = index.tools.iter().find(|tool| tool.name == "git_push").unwrap();
+    assert_eq!(push.annotations.as_ref().unwrap().open_world_hint, Some(true));
+
+    assert!(index.tools.iter().all(|tool| tool.annotations.is_some()));
+}
+
+#[test]
+fn test_tool_schemas_are_self_contained() {
+    let bundle = SchemaBundle::from_inventory();
+
+    for tool in &bundle.index().tools {
+        let schema = bundle.tool_schema(&tool.name).unwrap();
+        assert_eq!(schema["title"], tool.name.as_str());
+        assert!(schema.pointer("/$defs/input/properties").is_some() || schema.pointer("/$defs/input/type").is_some());
+        assert_refs_resolve(&schema);
+    }
+    assert!(bundle.tool_schema("no_such_tool").is_none());
+}
+
+#[test]
+fn test_openapi_has_one_operation_per_tool() {
+    let bundle = SchemaBundle::from_inventory();
+    let openapi = bundle.openapi();
+
+    assert_eq!(openapi["openapi"], "3.1.0");
+    assert_eq!(openapi["paths"].as_object().unwrap().len(), bundle.index().tools.len());
+    assert_eq!(openapi["paths"]["/tools/process_kill"]["post"]["operationId"], "process_kill");
+    assert_refs_resolve(&openapi);
+}
+
+struct KillTool;
+
+impl Tool for KillTool {
+    type Args = ProcessKillArgs;
+    type Prompts = ProcessKillPrompts;
+
+    fn name() -> &'static str {
+        "process_kill"
+    }
+
+    fn description() -> &'static str {
+        "Kill a process"
+    }
+
+    fn idempotent() -> bool {
+        false
+    }
+
+    async fn execute(
+        &self,
+        _args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        Err(McpError::Other(anyhow::anyhow!("not executed in these tests")))
+    }
+}
+
+#[test]
+fn test_annotate_and_write() {
+    let mut bundle = SchemaBundle::from_inventory();
+    bundle.annotate::<KillTool>();
+
+    let kill = bundle.index().tools.iter().find(|tool| tool.name == "process_kill").unwrap();
+    let annotations = kill.annotations.as_ref().unwrap();
+    assert_eq!(annotations.idempotent_hint, Some(false));
+    assert_eq!(annotations.destructive_hint, Some(true));
+
+    let dir = std::env::temp_dir().join(format!("kodegen_schema_bundle_{}", std::process::id()));
+    let files = bundle.write_to(&dir, true).unwrap();
+    assert_eq!(files.len(), bundle.index().tools.len() + 2);
+
+    let root = dir.join(&bundle.index().schema_version);
+    let index: Value = serde_json::from_str(&std::fs::read_to_string(root.join("index.json")).unwrap()).unwrap();
+    assert_eq!(index["tools"].as_array().unwrap().len(), bundle.index().tools.len());
+    let written = index["tools"].as_array().unwrap().iter().find(|tool| tool["name"] == "process_kill").unwrap();
+    assert_eq!(written["annotations"]["idempotentHint"], false);
+    assert_eq!(written["annotations"]["destructiveHint"], true);
+    assert!(root.join("tools/process_kill.schema.json").exists());
+    assert!(root.join("openapi.json").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,132 @@
+//! Tests for the schema compatibility check
+
+use kodegen_mcp_schema::tool::schema_compat::compare;
+use kodegen_mcp_schema::tool::{ChangeKind, Compatibility, SchemaBundle};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Write the current bundle to a fresh directory and return its version directory
+fn written_bundle(name: &str) -> PathBuf {
+    let out = std::env::temp_dir().join(format!("kodegen_schema_compat_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&out);
+    let bundle = SchemaBundle::from_inventory();
+    bundle.write_to(&out, false).expect("write bundle");
+    out.join(&bundle.index().schema_version)
+}
+
+/// Edit the `$defs` of one tool's schema file in place
+fn edit_tool(dir: &Path, tool: &str, edit: impl FnOnce(&mut serde_json::Map<String, Value>)) {
+    let path = dir.join(format!("tools/{}.schema.json", tool));
+    let mut document: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    edit(document["$defs"].as_object_mut().unwrap());
+    std::fs::write(&path, serde_json::to_string(&document).unwrap()).unwrap();
+}
+
+/// A bundle with no tools
+fn empty_bundle() -> SchemaBundle {
+    let out = std::env::temp_dir().join(format!("kodegen_schema_compat_empty_{}", std::process::id()));
+    std::fs::create_dir_all(&out).unwrap();
+    let index = serde_json::json!({ "format_version": 1, "schema_version": "0.0.0", "tools": [] });
+    std::fs::write(out.join("index.json"), index.to_string()).unwrap();
+    let bundle = SchemaBundle::read_from(&out).unwrap();
+    let _ = std::fs::remove_dir_all(&out);
+    bundle
+}
+
+#[test]
+fn test_inventory_is_compatible_with_itself() {
+    let bundle = SchemaBundle::from_inventory();
+    let report = compare(&bundle, &bundle);
+    assert!(report.changes.is_empty());
+    report.assert_compatible();
+}
+
+#[test]
+fn test_read_bundle_matches_inventory() {
+    let dir = written_bundle("roundtrip");
+    let baseline = SchemaBundle::read_from(&dir).unwrap();
+
+    assert_eq!(baseline.index(), SchemaBundle::from_inventory().index());
+    let report = compare(&baseline, &SchemaBundle::from_inventory());
+    assert!(report.changes.is_empty(), "{}", report);
+
+    let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+}
+
+#[test]
+fn test_classifies_input_changes() {
+    let dir = written_bundle("input");
+    edit_tool(&dir, "terminal", |defs| {
+        let input = defs["input"].as_object_mut().unwrap();
+        input["properties"].as_object_mut().unwrap().remove("command");
+        input["properties"]["label"] = serde_json::json!({ "type": "string" });
+        input.insert("required".to_string(), serde_json::json!(["tail"]));
+
+        let actions = defs["input_TerminalAction"]["oneOf"].as_array_mut().unwrap();
+        actions.retain(|variant| variant["const"] != "KILL");
+        actions.push(serde_json::json!({ "const": "RESIZE", "type": "string" }));
+    });
+
+    let report = compare(&SchemaBundle::from_inventory(), &SchemaBundle::read_from(&dir).unwrap());
+    let find = |path: &str, kind: ChangeKind| {
+        report
+            .changes
+            .iter()
+            .find(|change| change.tool == "terminal" && change.path == path && change.kind == kind)
+            .unwrap_or_else(|| panic!("no {:?} at {} in\n{}", kind, path, report))
+    };
+
+    assert_eq!(find("input.command", ChangeKind::FieldRemoved).compatibility, Compatibility::Breaking);
+    assert_eq!(find("input.tail", ChangeKind::NewlyRequired).compatibility, Compatibility::Breaking);
+    assert_eq!(find("input.action", ChangeKind::EnumNarrowed).compatibility, Compatibility::Breaking);
+    assert_eq!(find("input.action", ChangeKind::EnumWidened).compatibility, Compatibility::Compatible);
+    assert_eq!(find("input.label", ChangeKind::FieldAdded).compatibility, Compatibility::Compatible);
+    assert!(!report.is_compatible());
+
+    let report = report.allow("terminal", "input");
+    assert!(report.is_compatible(), "{}", report);
+
+    let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+}
+
+#[test]
+fn test_output_changes_break_in_both_directions() {
+    let dir = written_bundle("output");
+    edit_tool(&dir, "terminal", |defs| {
+        let output = defs["output"].as_object_mut().unwrap();
+        output["properties"]["cwd"] = serde_json::json!({ "type": "integer" });
+        output["required"].as_array_mut().unwrap().retain(|name| name != "completed");
+        output["properties"]["exit_code"].as_object_mut().unwrap().remove("nullable");
+    });
+
+    let report = compare(&SchemaBundle::from_inventory(), &SchemaBundle::read_from(&dir).unwrap());
+    let breaks: Vec<(&str, ChangeKind)> =
+        report.unintended_breaks().map(|change| (change.path.as_str(), change.kind)).collect();
+
+    assert!(breaks.contains(&("output.cwd", ChangeKind::TypeChanged)), "{}", report);
+    assert!(breaks.contains(&("output.completed", ChangeKind::NoLongerRequired)), "{}", report);
+    assert!(breaks.contains(&("output.exit_code", ChangeKind::TypeChanged)), "{}", report);
+
+    let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "breaking schema change")]
+fn test_assert_compatible_fails_on_removed_tool() {
+    let dir = written_bundle("removed");
+    let baseline = SchemaBundle::read_from(&dir).unwrap();
+    let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+
+    // The baseline knows every tool; an empty bundle removes them all
+    let empty = empty_bundle();
+    compare(&baseline, &empty).assert_compatible();
+}
+
+/// Bundle of the current release, written with `kodegen-schema --out schemas`
+const BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/schemas/0.10.14");
+
+#[test]
+fn test_no_breaking_changes_since_baseline() {
+    let baseline = SchemaBundle::read_from(Path::new(BASELINE)).expect("read committed baseline bundle");
+    compare(&baseline, &SchemaBundle::from_inventory()).assert_compatible();
+}
@@ -0,0 +1,46 @@
+//! Integration tests for the precomputed tool schema registry
+
+use kodegen_config::TERMINAL;
+use kodegen_mcp_schema::{SchemaRegistry, ToolMetadata};
+use kodegen_mcp_schema::terminal::{TerminalInput, TerminalOutput};
+use rmcp::handler::server::tool::schema_for_type;
+use std::sync::Arc;
+
+#[test]
+fn test_registry_covers_every_registered_tool() {
+    let registered: Vec<&str> = inventory::iter::<ToolMetadata>
+        .into_iter()
+        .map(|m| m.name)
+        .collect();
+
+    assert!(!registered.is_empty());
+    for name in &registered {
+        assert!(
+            SchemaRegistry::get(name).is_some(),
+            "Tool '{}' missing from schema registry",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_registry_matches_rmcp_schema_dialect() {
+    let schemas = SchemaRegistry::get(TERMINAL).expect("terminal tool is registered");
+
+    assert_eq!(*schemas.input, schema_for_type::<TerminalInput>());
+    assert_eq!(*schemas.output, schema_for_type::<TerminalOutput>());
+}
+
+#[test]
+fn test_registry_lookups_share_schema() {
+    let first = SchemaRegistry::get(TERMINAL).expect("terminal tool is registered");
+    let second = SchemaRegistry::get(TERMINAL).expect("terminal tool is registered");
+
+    assert!(Arc::ptr_eq(&first.input, &second.input));
+    assert!(Arc::ptr_eq(&first.output, &second.output));
+}
+
+#[test]
+fn test_registry_unknown_tool() {
+    assert!(SchemaRegistry::get("nonexistent_tool").is_none());
+}
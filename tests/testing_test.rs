@@ -0,0 +1,485 @@
+//! Tests for the loopback test context
+
+#![cfg(feature = "testing")]
+
+use kodegen_mcp_schema::github::list_repos::{GitHubListReposOutput, GithubListReposPrompts, ListReposArgs};
+use kodegen_mcp_schema::process::{ProcessKillArgs, ProcessKillOutput, ProcessKillPrompts};
+use kodegen_mcp_schema::tool::testing::TestContext;
+use kodegen_mcp_schema::tool::{
+    ARGUMENT_COERCIONS_META_KEY, Coercion, CoercionKind, DEFAULT_PROGRESS_INTERVAL, ConfirmationFallback, ConfirmationPolicy, DestructiveAction, HistoryMiddleware, MiddlewareStack,
+    PROGRESS_STATS_META_KEY, ProgressStats, RouteConfig, TIMEOUT_META_KEY, ToolCallRecord, ToolHistorySink,
+};
+use kodegen_mcp_schema::{McpError, Tool, ToolExecutionContext, ToolResponse};
+use rmcp::model::{CallToolResult, CreateElicitationResult, ElicitationAction, Meta, RawContent};
+use serde_json::{Value, json};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Reports progress, then kills nothing
+struct KillTool;
+
+impl Tool for KillTool {
+    type Args = ProcessKillArgs;
+    type Prompts = ProcessKillPrompts;
+
+    fn name() -> &'static str {
+        "process_kill"
+    }
+
+    fn description() -> &'static str {
+        "Kill a process"
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        if args.pid == 0 {
+            return Err(McpError::invalid_argument("pid", "must not be 0"));
+        }
+        ctx.stream("looking up process\n").await?;
+        ctx.update(1.0, 2.0, "sending SIGKILL").await?;
+
+        Ok(ToolResponse::new(
+            format!("killed {}", args.pid),
+            ProcessKillOutput {
+                success: true,
+                pid: args.pid,
+                message: "killed".to_string(),
+            },
+        ))
+    }
+}
+
+fn payload(result: &CallToolResult) -> Value {
+    match &*result.content[1] {
+        RawContent::Text(t) => serde_json::from_str(&t.text).unwrap(),
+        _ => panic!("content[1] is not text"),
+    }
+}
+
+fn confirming() -> RouteConfig {
+    RouteConfig {
+        confirmation: Some(ConfirmationPolicy::default()),
+        ..RouteConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_context_reports_configured_environment() {
+    let test = TestContext::builder()
+        .pwd("/work/app")
+        .git_root("/work")
+        .connection_id("conn-1")
+        .build()
+        .await;
+    let ctx = test.context();
+
+    assert_eq!(ctx.pwd(), Some(Path::new("/work/app")));
+    assert_eq!(ctx.git_root(), Some(Path::new("/work")));
+    assert_eq!(ctx.connection_id(), Some("conn-1"));
+    assert!(ctx.client_protocol_version().is_some());
+
+    let default = TestContext::new().await.context();
+    assert_eq!(default.pwd(), None);
+}
+
+#[tokio::test]
+async fn test_execute_captures_notifications() {
+    let test = TestContext::new().await;
+
+    let response = KillTool.execute(ProcessKillArgs { pid: 7 }, test.context()).await.unwrap();
+    assert_eq!(response.metadata.pid, 7);
+
+    let notifications = test.notifications().await;
+    assert_eq!(notifications.len(), 2);
+    assert_eq!(notifications[1].progress, 1.0);
+    assert_eq!(notifications[1].total, Some(2.0));
+    assert_eq!(test.messages().await, vec!["looking up process\n", "sending SIGKILL"]);
+
+    test.clear().await;
+    assert!(test.notifications().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_cancellation() {
+    let test = TestContext::new().await;
+    let ctx = test.context();
+    assert!(!ctx.is_cancelled());
+
+    test.cancel();
+    assert!(ctx.is_cancelled());
+    assert!(test.context().is_cancelled());
+}
+
+#[tokio::test]
+async fn test_call_tool_returns_results_and_errors() {
+    let test = TestContext::new().await;
+
+    let result = test.call_tool(KillTool, json!({"pid": 42})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["pid"], 42);
+
+    let result = test.call_tool(KillTool, json!({"pid": 0})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["argument"], "pid");
+
+    // Arguments that do not match the Args schema are tool errors too
+    let result = test.call_tool(KillTool, json!({"pid": "x"})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["kind"], "invalid_arguments");
+    assert_eq!(payload(&result)["argument"], "pid");
+
+    let result = test.call_tool(KillTool, json!({})).await.unwrap();
+    assert_eq!(payload(&result)["argument"], "pid");
+    assert_eq!(payload(&result)["message"], "Invalid argument 'pid': missing required argument");
+}
+
+#[tokio::test]
+async fn test_confirmation_accepted() {
+    let test = TestContext::builder()
+        .elicitation(CreateElicitationResult {
+            action: ElicitationAction::Accept,
+            content: Some(json!({"confirm": true})),
+        })
+        .build()
+        .await;
+
+    let result = test.call_tool_with(Arc::new(KillTool), confirming(), json!({"pid": 42})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+
+    let elicitations = test.elicitations().await;
+    assert_eq!(elicitations.len(), 1);
+    assert!(elicitations[0].message.contains("Kill process 42"));
+}
+
+#[tokio::test]
+async fn test_confirmation_declined() {
+    let test = TestContext::builder()
+        .elicitation(CreateElicitationResult {
+            action: ElicitationAction::Decline,
+            content: None,
+        })
+        .build()
+        .await;
+
+    let result = test.call_tool_with(Arc::new(KillTool), confirming(), json!({"pid": 42})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["kind"], "confirmation_declined");
+
+    // The tool never ran
+    assert!(test.notifications().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_confirmation_cancelled_is_not_declined() {
+    let test = TestContext::builder()
+        .elicitation(CreateElicitationResult {
+            action: ElicitationAction::Accept,
+            content: Some(json!({"confirm": true})),
+        })
+        .build()
+        .await;
+    test.cancel();
+
+    let action = DestructiveAction::new("Kill process 42", "42");
+    let result = ConfirmationPolicy::default().confirm("process_kill", &action, &test.context()).await;
+    assert!(matches!(result, Err(McpError::Cancelled { partial_output: None, .. })));
+}
+
+#[tokio::test]
+async fn test_confirmation_accept_without_confirm_is_declined() {
+    for content in [None, Some(json!({})), Some(json!({"confirm": "yes"}))] {
+        let test = TestContext::builder()
+            .elicitation(CreateElicitationResult {
+                action: ElicitationAction::Accept,
+                content,
+            })
+            .build()
+            .await;
+
+        let result = test.call_tool_with(Arc::new(KillTool), confirming(), json!({"pid": 42})).await.unwrap();
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(payload(&result)["kind"], "confirmation_declined");
+    }
+}
+
+#[tokio::test]
+async fn test_confirmation_fallback_without_elicitation() {
+    let test = TestContext::new().await;
+
+    let result = test.call_tool_with(Arc::new(KillTool), confirming(), json!({"pid": 42})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(test.elicitations().await.is_empty());
+
+    let allow = RouteConfig {
+        confirmation: Some(ConfirmationPolicy::default().fallback(ConfirmationFallback::Allow)),
+        ..RouteConfig::default()
+    };
+    let result = test.call_tool_with(Arc::new(KillTool), allow, json!({"pid": 42})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+}
+
+/// Streams a line, then hangs without checking for cancellation
+struct HangingTool;
+
+impl Tool for HangingTool {
+    type Args = ProcessKillArgs;
+    type Prompts = ProcessKillPrompts;
+
+    fn name() -> &'static str {
+        "process_kill"
+    }
+
+    fn description() -> &'static str {
+        "Hangs"
+    }
+
+    fn default_timeout() -> Option<Duration> {
+        Some(Duration::from_millis(50))
+    }
+
+    async fn execute(
+        &self,
+        _args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        ctx.stream("partial line\n").await?;
+        std::future::pending().await
+    }
+}
+
+#[tokio::test]
+async fn test_default_timeout_stops_hung_tool() {
+    let test = TestContext::new().await;
+
+    let result = test.call_tool(HangingTool, json!({"pid": 1})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    let data = payload(&result);
+    assert_eq!(data["kind"], "timed_out");
+    assert_eq!(data["timeout_ms"], 50);
+    assert_eq!(data["partial_output"], "partial line\n");
+    assert_eq!(data["retryable"], true);
+}
+
+#[tokio::test]
+async fn test_meta_overrides_timeout() {
+    let mut meta = Meta::new();
+    meta.0.insert(TIMEOUT_META_KEY.to_string(), json!(10));
+    let test = TestContext::builder().meta(meta).build().await;
+
+    let result = test.call_tool(HangingTool, json!({"pid": 1})).await.unwrap();
+    assert_eq!(payload(&result)["timeout_ms"], 10);
+
+    let mut meta = Meta::new();
+    meta.0.insert(TIMEOUT_META_KEY.to_string(), json!("soon"));
+    let test = TestContext::builder().meta(meta).build().await;
+
+    let result = test.call_tool(HangingTool, json!({"pid": 1})).await.unwrap();
+    assert_eq!(payload(&result)["kind"], "invalid_arguments");
+    assert_eq!(payload(&result)["argument"], "_meta.timeoutMs");
+}
+
+#[tokio::test]
+async fn test_cancellation_stops_tool_ignoring_token() {
+    let mut meta = Meta::new();
+    meta.0.insert(TIMEOUT_META_KEY.to_string(), json!(60_000));
+    let test = Arc::new(TestContext::builder().meta(meta).build().await);
+
+    let call = tokio::spawn({
+        let test = test.clone();
+        async move { test.call_tool(HangingTool, json!({"pid": 1})).await }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    test.cancel();
+
+    let result = tokio::time::timeout(Duration::from_secs(5), call).await.unwrap().unwrap().unwrap();
+    let data = payload(&result);
+    assert_eq!(data["kind"], "cancelled");
+    assert_eq!(data["partial_output"], "partial line\n");
+}
+
+/// Streams many small chunks and progress updates in a tight loop
+struct ChattyTool;
+
+impl Tool for ChattyTool {
+    type Args = ProcessKillArgs;
+    type Prompts = ProcessKillPrompts;
+
+    fn name() -> &'static str {
+        "process_kill"
+    }
+
+    fn description() -> &'static str {
+        "Chatty"
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<ProcessKillOutput>, McpError> {
+        for i in 0..args.pid {
+            ctx.stream(format!("{}\n", i)).await?;
+            ctx.progress(f64::from(i + 1), f64::from(args.pid)).await?;
+        }
+
+        Ok(ToolResponse::new(
+            "done",
+            ProcessKillOutput {
+                success: true,
+                pid: args.pid,
+                message: "done".to_string(),
+            },
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_progress_is_coalesced_and_flushed() {
+    let test = TestContext::new().await;
+    let config = RouteConfig {
+        progress_interval: Some(DEFAULT_PROGRESS_INTERVAL),
+        ..RouteConfig::default()
+    };
+
+    let result = test.call_tool_with(Arc::new(ChattyTool), config, json!({"pid": 1000})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+
+    let notifications = test.notifications().await;
+    assert!(notifications.len() < 100, "{} notifications sent", notifications.len());
+
+    // No text is lost and the final progress is delivered
+    let text: String = notifications
+        .iter()
+        .filter(|n| n.total.is_none())
+        .filter_map(|n| n.message.clone())
+        .collect();
+    let expected: String = (0..1000).map(|i| format!("{}\n", i)).collect();
+    assert_eq!(text, expected);
+    assert_eq!(notifications.last().unwrap().progress, 1000.0);
+
+    let stats: ProgressStats =
+        serde_json::from_value(result.meta.unwrap().0[PROGRESS_STATS_META_KEY].clone()).unwrap();
+    assert_eq!(stats.sent, notifications.len() as u64);
+    assert_eq!(stats.sent + stats.merged + stats.dropped, 2000);
+}
+
+#[tokio::test]
+async fn test_default_route_sends_every_update() {
+    let test = TestContext::new().await;
+
+    let result = test.call_tool(ChattyTool, json!({"pid": 20})).await.unwrap();
+    assert_eq!(test.notifications().await.len(), 40);
+    assert!(result.meta.is_none());
+}
+
+#[tokio::test]
+async fn test_coercing_route_fixes_and_reports_arguments() {
+    let test = TestContext::new().await;
+    let config = RouteConfig {
+        coerce_arguments: true,
+        ..RouteConfig::default()
+    };
+
+    let result = test.call_tool_with(Arc::new(KillTool), config, json!({"pid": "42"})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["pid"], 42);
+
+    let coercions: Vec<Coercion> =
+        serde_json::from_value(result.meta.unwrap().0[ARGUMENT_COERCIONS_META_KEY].clone()).unwrap();
+    assert_eq!(coercions.len(), 1);
+    assert_eq!(coercions[0].path, "pid");
+    assert_eq!(coercions[0].kind, CoercionKind::StringToNumber);
+}
+
+#[tokio::test]
+async fn test_strict_route_rejects_mismatched_arguments() {
+    let test = TestContext::new().await;
+    let result = test.call_tool(KillTool, json!({"pid": "42"})).await.unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["kind"], "invalid_arguments");
+}
+
+/// Lists no repositories
+struct ListReposTool;
+
+impl Tool for ListReposTool {
+    type Args = ListReposArgs;
+    type Prompts = GithubListReposPrompts;
+
+    fn name() -> &'static str {
+        "github_list_repos"
+    }
+
+    fn description() -> &'static str {
+        "List repositories"
+    }
+
+    async fn execute(
+        &self,
+        args: Self::Args,
+        _ctx: ToolExecutionContext,
+    ) -> Result<ToolResponse<GitHubListReposOutput>, McpError> {
+        let owner = args.username.or(args.org);
+        Ok(ToolResponse::new(
+            "no repositories",
+            GitHubListReposOutput {
+                success: true,
+                owner,
+                count: 0,
+                repos: Vec::new(),
+            },
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_handler_validates_arguments_before_execution() {
+    let test = TestContext::new().await;
+
+    let result = test.call_tool(ListReposTool, json!({"org": "github"})).await.unwrap();
+    assert_ne!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["owner"], "github");
+
+    let result = test
+        .call_tool(ListReposTool, json!({"username": "octocat", "org": "github"}))
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(payload(&result)["argument"], "username");
+}
+
+/// Collects history records in memory
+#[derive(Default)]
+struct MemorySink(parking_lot::Mutex<Vec<ToolCallRecord>>);
+
+impl ToolHistorySink for MemorySink {
+    fn record(&self, _connection_id: Option<&str>, record: ToolCallRecord) {
+        self.0.lock().push(record);
+    }
+}
+
+#[tokio::test]
+async fn test_validation_failures_pass_through_middleware() {
+    let test = TestContext::new().await;
+    let sink = Arc::new(MemorySink::default());
+    let config = RouteConfig {
+        middleware: MiddlewareStack::new(vec![Arc::new(HistoryMiddleware::new(sink.clone()))]),
+        ..RouteConfig::default()
+    };
+
+    let result = test
+        .call_tool_with(Arc::new(ListReposTool), config, json!({"username": "octocat", "org": "github"}))
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+
+    let records = sink.0.lock();
+    assert_eq!(records.len(), 1);
+    assert!(!records[0].success);
+    assert!(records[0].output_json.contains("username"));
+}
@@ -0,0 +1,168 @@
+//! Integration tests for the built-in tool call history store
+
+use kodegen_mcp_schema::introspection::InspectToolCallsArgs;
+use kodegen_mcp_schema::{ToolCallRecord, ToolHistory};
+
+fn record(tool_name: &str, timestamp: &str, duration_ms: u64, success: bool) -> ToolCallRecord {
+    ToolCallRecord {
+        timestamp: timestamp.to_string(),
+        tool_name: tool_name.to_string(),
+        args_json: "{}".to_string(),
+        output_json: "{}".to_string(),
+        duration_ms: Some(duration_ms),
+        success,
+    }
+}
+
+fn query(offset: i64, max_results: usize, tool_name: Option<&str>, since: Option<&str>) -> InspectToolCallsArgs {
+    InspectToolCallsArgs {
+        max_results,
+        offset,
+        tool_name: tool_name.map(str::to_string),
+        since: since.map(str::to_string),
+    }
+}
+
+fn populated() -> ToolHistory {
+    let history = ToolHistory::new(3);
+    history.record(None, record("fs_read_file", "2025-01-01T00:00:00Z", 10, true));
+    history.record(None, record("git_status", "2025-01-01T00:01:00Z", 20, true));
+    history.record(None, record("fs_read_file", "2025-01-01T00:02:00Z", 30, false));
+    history.record(None, record("fs_read_file", "2025-01-01T00:03:00Z", 40, true));
+    history
+}
+
+#[test]
+fn test_ring_buffer_is_bounded() {
+    let output = populated()
+        .inspect_tool_calls(None, &query(0, 50, None, None))
+        .expect("query succeeds");
+
+    assert_eq!(output.total_entries_in_memory, 3);
+    assert_eq!(output.count, 3);
+    assert_eq!(output.calls[0].timestamp, "2025-01-01T00:01:00Z");
+}
+
+#[test]
+fn test_filters_and_pagination() {
+    let history = populated();
+
+    let by_tool = history
+        .inspect_tool_calls(None, &query(0, 50, Some("fs_read_file"), None))
+        .expect("query succeeds");
+    assert_eq!(by_tool.count, 2);
+    assert_eq!(by_tool.filter_tool_name.as_deref(), Some("fs_read_file"));
+
+    let since = history
+        .inspect_tool_calls(None, &query(0, 50, None, Some("2025-01-01T00:02:00Z")))
+        .expect("query succeeds");
+    assert_eq!(since.count, 2);
+
+    let page = history
+        .inspect_tool_calls(None, &query(1, 1, None, None))
+        .expect("query succeeds");
+    assert_eq!(page.count, 1);
+    assert_eq!(page.calls[0].timestamp, "2025-01-01T00:02:00Z");
+
+    let tail = history
+        .inspect_tool_calls(None, &query(-1, 50, None, None))
+        .expect("query succeeds");
+    assert_eq!(tail.count, 1);
+    assert_eq!(tail.calls[0].timestamp, "2025-01-01T00:03:00Z");
+
+    // A negative offset beyond the buffer returns the newest max_results calls
+    let capped = history
+        .inspect_tool_calls(None, &query(-5000, 2, None, None))
+        .expect("query succeeds");
+    assert_eq!(capped.count, 2);
+    assert_eq!(capped.max_results, 2);
+    assert_eq!(capped.calls[0].timestamp, "2025-01-01T00:02:00Z");
+    assert_eq!(capped.calls[1].timestamp, "2025-01-01T00:03:00Z");
+
+    assert!(history
+        .inspect_tool_calls(None, &query(0, 50, None, Some("yesterday")))
+        .is_err());
+}
+
+#[test]
+fn test_usage_stats_cover_evicted_calls() {
+    let stats = populated().usage_stats(None);
+
+    assert_eq!(stats.total_calls, 4);
+    assert_eq!(stats.tools_used, 2);
+    assert_eq!(stats.successful_calls, 3);
+    assert_eq!(stats.failed_calls, 1);
+    assert_eq!(stats.success_rate, 75.0);
+
+    let read_file = stats
+        .tool_usage
+        .iter()
+        .find(|t| t.tool_name == "fs_read_file")
+        .expect("fs_read_file stats present");
+    assert_eq!(read_file.call_count, 3);
+    assert_eq!(read_file.total_duration_ms, 80);
+    assert_eq!(read_file.avg_duration_ms, 26);
+}
+
+#[test]
+fn test_connections_are_isolated() {
+    let history = ToolHistory::new(10);
+    history.record(Some("conn-a"), record("git_status", "2025-01-01T00:00:00Z", 5, true));
+
+    assert_eq!(history.usage_stats(Some("conn-a")).total_calls, 1);
+    assert_eq!(history.usage_stats(Some("conn-b")).total_calls, 0);
+}
+
+#[test]
+fn test_connections_are_closed() {
+    let history = ToolHistory::new(10).with_max_connections(2);
+    history.record(Some("conn-a"), record("git_status", "2025-01-01T00:00:00Z", 5, true));
+    history.record(Some("conn-b"), record("git_status", "2025-01-01T00:00:00Z", 5, true));
+    history.close_connection(Some("conn-b"));
+    assert_eq!(history.usage_stats(Some("conn-b")).total_calls, 0);
+
+    // The least recently used connection makes room for a new one
+    history.record(Some("conn-b"), record("git_status", "2025-01-01T00:00:00Z", 5, true));
+    history.record(Some("conn-a"), record("git_log", "2025-01-01T00:01:00Z", 5, true));
+    history.record(Some("conn-c"), record("git_status", "2025-01-01T00:02:00Z", 5, true));
+    assert_eq!(history.usage_stats(Some("conn-a")).total_calls, 2);
+    assert_eq!(history.usage_stats(Some("conn-b")).total_calls, 0);
+    assert_eq!(history.usage_stats(Some("conn-c")).total_calls, 1);
+}
+
+#[test]
+fn test_persistence_reloads_records() {
+    let dir = std::env::temp_dir().join(format!("kodegen-history-{}", uuid::Uuid::new_v4()));
+
+    {
+        let history = ToolHistory::persistent_in(10, &dir).expect("create history dir");
+        history.record(Some("conn/1"), record("git_status", "2025-01-01T00:00:00Z", 5, true));
+        history.record(Some("conn/1"), record("git_log", "2025-01-01T00:01:00Z", 7, false));
+        // Sanitizes to the same file stem but is persisted separately
+        history.record(Some("conn_1"), record("git_status", "2025-01-01T00:02:00Z", 5, true));
+    }
+
+    let reloaded = ToolHistory::persistent_in(10, &dir).expect("open history dir");
+    let stats = reloaded.usage_stats(Some("conn/1"));
+    assert_eq!(stats.total_calls, 2);
+    assert_eq!(stats.failed_calls, 1);
+    assert_eq!(reloaded.usage_stats(Some("conn_1")).total_calls, 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_queries_for_unknown_connections_create_nothing() {
+    let dir = std::env::temp_dir().join(format!("kodegen-history-{}", uuid::Uuid::new_v4()));
+    let history = ToolHistory::persistent_in(10, &dir).expect("create history dir");
+
+    assert_eq!(history.usage_stats(Some("unknown")).total_calls, 0);
+    let calls = history
+        .inspect_tool_calls(Some("unknown"), &query(0, 50, None, None))
+        .expect("query succeeds");
+    assert_eq!(calls.count, 0);
+    assert_eq!(calls.total_entries_in_memory, 0);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,88 @@
+//! Tests for TypeScript and Python type generation
+
+use kodegen_mcp_schema::tool::type_gen::{self, PythonStyle};
+use kodegen_mcp_schema::tool::SchemaBundle;
+use std::collections::HashSet;
+
+/// Names declared by `export interface X` / `export type X`
+fn ts_declarations(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("export interface ").or_else(|| line.strip_prefix("export type ")))
+        .filter_map(|rest| rest.split([' ', '<']).next())
+        .collect()
+}
+
+#[test]
+fn test_typescript_declares_every_tool_once() {
+    let bundle = SchemaBundle::from_inventory();
+    let source = type_gen::typescript(&bundle);
+    let declared = ts_declarations(&source);
+
+    let unique: HashSet<&str> = declared.iter().copied().collect();
+    assert_eq!(unique.len(), declared.len(), "duplicate TypeScript declarations");
+
+    assert!(source.contains("export interface TerminalOutput {"));
+    assert!(source.contains("export interface FsSearchOutput {"));
+    assert!(source.contains("export interface GitStatusOutput {"));
+    for tool in &bundle.index().tools {
+        assert!(
+            source.contains(&format!("  | {{ tool: \"{}\"; output: ", tool.name)),
+            "{} missing from AnyToolOutput",
+            tool.name
+        );
+    }
+}
+
+#[test]
+fn test_typescript_references_resolve() {
+    let bundle = SchemaBundle::from_inventory();
+    let source = type_gen::typescript(&bundle);
+    let declared: HashSet<&str> = ts_declarations(&source).into_iter().collect();
+    let builtins = ["Array", "Record", "string", "number", "boolean", "null", "unknown", "true", "false", "keyof", "never"];
+
+    // Every capitalized identifier on the right-hand side of a member or alias
+    for line in source.lines().filter(|line| !line.trim_start().starts_with("/**") && !line.trim_start().starts_with('*')) {
+        let Some((_, rhs)) = line.split_once(": ").or_else(|| line.split_once(" = ")) else {
+            continue;
+        };
+        for word in rhs.split(|c: char| !c.is_ascii_alphanumeric() && c != '_') {
+            if word.starts_with(|c: char| c.is_ascii_uppercase()) && !builtins.contains(&word) && !rhs.contains(&format!("\"{}", word)) {
+                assert!(declared.contains(word), "undeclared type {} in `{}`", word, line);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_python_handles_keyword_fields() {
+    let bundle = SchemaBundle::from_inventory();
+
+    // `from` is a keyword, so the TypedDict needs the functional syntax
+    let typed_dict = type_gen::python(&bundle, PythonStyle::TypedDict);
+    assert!(typed_dict.contains("GitDiffArgs = TypedDict(\n    \"GitDiffArgs\",\n"));
+    assert!(typed_dict.contains("class TerminalOutput(TypedDict):"));
+    assert!(typed_dict.contains("class TerminalToolOutput(TypedDict):\n    tool: Literal[\"terminal\"]\n    output: TerminalOutput\n"));
+
+    // ...and the pydantic model an alias
+    let pydantic = type_gen::python(&bundle, PythonStyle::Pydantic);
+    assert!(pydantic.contains("    from_: str = Field(alias=\"from\")"));
+    assert!(pydantic.contains("class TerminalOutput(BaseModel):"));
+    assert!(pydantic.contains("AnyToolOutput = Annotated[Union[\n"));
+    assert!(pydantic.contains("Field(discriminator=\"tool\")]"));
+}
+
+#[test]
+fn test_python_aliases_follow_their_dependencies() {
+    let bundle = SchemaBundle::from_inventory();
+    let source = type_gen::python(&bundle, PythonStyle::TypedDict);
+
+    let position = |name: &str| {
+        source
+            .find(&format!("\nclass {}(", name))
+            .or_else(|| source.find(&format!("\n{} = ", name)))
+            .unwrap_or_else(|| panic!("{} not declared", name))
+    };
+    // SqlValue is a union alias over its tagged variants
+    assert!(position("SqlValueInt") < position("SqlValue"));
+}
@@ -0,0 +1,109 @@
+//! Tests for cross-field argument validation
+
+use kodegen_mcp_schema::claude_agent::ClaudeAgentArgs;
+use kodegen_mcp_schema::filesystem::search::FsSearchArgs;
+use kodegen_mcp_schema::git::GitConfigGetArgs;
+use kodegen_mcp_schema::github::list_repos::ListReposArgs;
+use kodegen_mcp_schema::terminal::{TerminalInput, TerminalPrompts};
+use kodegen_mcp_schema::{McpError, ToolArgs, ValidateArgs};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Deserialize `arguments` as `T` and validate them, returning the failing argument and message
+fn validate<T: ToolArgs + DeserializeOwned>(arguments: Value) -> Option<(String, String)> {
+    let args: T = serde_json::from_value(arguments).expect("arguments match the schema");
+    match args.validate() {
+        Ok(()) => None,
+        Err(McpError::InvalidArgument { argument, message }) => Some((argument, message)),
+        Err(other) => panic!("expected InvalidArgument, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_required_if_names_field_and_condition() {
+    let (argument, message) = validate::<TerminalInput>(json!({"action": "EXEC"})).unwrap();
+    assert_eq!(argument, "command");
+    assert_eq!(message, "required when action is EXEC");
+
+    // Blank strings do not count as provided
+    let (argument, _) = validate::<TerminalInput>(json!({"action": "EXEC", "command": "  "})).unwrap();
+    assert_eq!(argument, "command");
+
+    assert!(validate::<TerminalInput>(json!({"action": "EXEC", "command": "ls"})).is_none());
+    assert!(validate::<TerminalInput>(json!({"action": "READ"})).is_none());
+}
+
+#[test]
+fn test_required_if_matches_any_listed_variant() {
+    let (argument, message) = validate::<ClaudeAgentArgs>(json!({"action": "SEND", "agent": 1})).unwrap();
+    assert_eq!(argument, "prompt");
+    assert_eq!(message, "required when action is SEND");
+
+    // SEARCH is the default action
+    let (argument, _) = validate::<FsSearchArgs>(json!({"pattern": "TODO"})).unwrap();
+    assert_eq!(argument, "path");
+
+    assert!(validate::<ClaudeAgentArgs>(json!({"action": "KILL"})).is_none());
+    assert!(validate::<FsSearchArgs>(json!({"action": "LIST"})).is_none());
+}
+
+#[test]
+fn test_exclusive_with() {
+    let (argument, message) = validate::<ListReposArgs>(json!({"username": "octocat", "org": "github"})).unwrap();
+    assert_eq!(argument, "username");
+    assert_eq!(message, "cannot be combined with `org`");
+    assert!(validate::<ListReposArgs>(json!({"org": "github"})).is_none());
+
+    // `list: false` is the same as leaving it out
+    let (argument, _) = validate::<GitConfigGetArgs>(json!({"path": ".", "key": "user.name", "list": true})).unwrap();
+    assert_eq!(argument, "key");
+    assert!(validate::<GitConfigGetArgs>(json!({"path": ".", "key": "user.name", "list": false})).is_none());
+}
+
+#[test]
+fn test_range() {
+    let (argument, message) = validate::<TerminalInput>(json!({"action": "READ", "tail": 5000})).unwrap();
+    assert_eq!(argument, "tail");
+    assert_eq!(message, "must be at most 2000, found 5000");
+
+    let (argument, message) = validate::<ListReposArgs>(json!({"per_page": 0})).unwrap();
+    assert_eq!(argument, "per_page");
+    assert_eq!(message, "must be between 1 and 100, found 0");
+
+    assert!(validate::<ListReposArgs>(json!({"per_page": 100})).is_none());
+    assert!(validate::<ListReposArgs>(json!({})).is_none());
+}
+
+/// Args defined outside the schema crate, with no `validate` override
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, ValidateArgs)]
+struct DownstreamArgs {
+    #[range(min = 1, max = 10)]
+    count: u32,
+    #[exclusive_with(count_all)]
+    name: Option<String>,
+    #[serde(default)]
+    count_all: bool,
+}
+
+impl ToolArgs for DownstreamArgs {
+    type Output = serde_json::Value;
+    type Prompts = TerminalPrompts;
+
+    const NAME: &'static str = "downstream_tool";
+    const CATEGORY: &'static kodegen_config::Category = kodegen_config::CATEGORY_TERMINAL;
+    const DESCRIPTION: &'static str = "Downstream tool";
+}
+
+#[test]
+fn test_derive_runs_without_validate_override() {
+    let (argument, message) = validate::<DownstreamArgs>(json!({"count": 11})).unwrap();
+    assert_eq!(argument, "count");
+    assert_eq!(message, "must be between 1 and 10, found 11");
+
+    let (argument, _) = validate::<DownstreamArgs>(json!({"count": 1, "name": "x", "count_all": true})).unwrap();
+    assert_eq!(argument, "name");
+
+    assert!(validate::<DownstreamArgs>(json!({"count": 10, "name": "x"})).is_none());
+}